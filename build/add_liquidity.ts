@@ -0,0 +1 @@
+export type AddLiquidity = {"name":"add_liquidity","inputs":[{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"}],"outputs":[{"content":[{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"}],"type":"tuple"}]}
\ No newline at end of file
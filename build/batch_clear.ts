@@ -0,0 +1 @@
+export type BatchClear = {"name":"batch_clear","inputs":[{"content":[{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"type":"array"},{"content":[{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"}],"type":"array"},{"size_in_bits":8,"type":"u8"},{"type":"bool"},{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"outputs":[{"content":[{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"type":"bool"},{"type":"bool"},{"type":"bool"},{"type":"bool"}],"type":"array"},{"content":[{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"}],"type":"array"},{"content":[{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"}],"type":"array"}],"type":"tuple"}]}
\ No newline at end of file
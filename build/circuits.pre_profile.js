@@ -0,0 +1,7 @@
+var pre_profile = { 'code':
+{"source_file":"use arcis_imports::*;\n\n#[encrypted]\nmod circuits {\n    use arcis_imports::*;\n\n    // ========== Encrypted CFMM State ==========\n\n    /// Pool reserves, only ever held as `Enc<Mxe, CfmmState>`. For LMSR\n    /// markets the two fields hold the outstanding share quantities q_yes / q_no.\n    pub struct CfmmState {\n        yes_reserves: u64,\n        no_reserves: u64,\n    }\n\n    #[instruction]\n    pub fn init_cfmm_state(mxe: Mxe) -> Enc<Mxe, CfmmState> {\n        mxe.from_arcis(CfmmState {\n            yes_reserves: 0,\n            no_reserves: 0,\n        })\n    }\n\n    // ========== Encrypted User Positions ==========\n\n    /// A trader's holdings in one market, only ever held as\n    /// `Enc<Shared, PositionState>` so the trader alone can decrypt it\n    pub struct PositionState {\n        yes_shares: u64,\n        no_shares: u64,\n        cost_basis: u64,\n    }\n\n    #[instruction]\n    pub fn init_position(owner: Shared) -> Enc<Shared, PositionState> {\n        owner.from_arcis(PositionState {\n            yes_shares: 0,\n            no_shares: 0,\n            cost_basis: 0,\n        })\n    }\n\n    /// Credit a filled trade's shares and spend to the trader's position\n    fn apply_fill(\n        position: PositionState,\n        side: bool,\n        shares_out: u64,\n        user_amount: u64,\n        filled: bool,\n    ) -> PositionState {\n        let yes_add = if filled && side { shares_out } else { 0 };\n        let no_add = if filled && !side { shares_out } else { 0 };\n        let cost_add = if filled { user_amount } else { 0 };\n        PositionState {\n            yes_shares: position.yes_shares + yes_add,\n            no_shares: position.no_shares + no_add,\n            cost_basis: position.cost_basis + cost_add,\n        }\n    }\n\n    /// Token movements for a trade, revealed so the callback can settle it:\n    /// (side, shares to mint, collateral spent, collateral refunded).\n    /// Rejected trades mint nothing and refund the whole escrow.\n    fn settle(\n        filled: bool,\n        side: bool,\n        shares_out: u64,\n        user_amount: u64,\n        escrow: u64,\n    ) -> (bool, u64, u64, u64) {\n        let spent = if filled { user_amount } else { 0 };\n        (\n            filled && side,\n            if filled { shares_out } else { 0 },\n            spent,\n            escrow - spent,\n        )\n    }\n\n    // ========== Private Trade Circuit ==========\n\n    pub struct PrivateTradeInput {\n        user_amount: u64,\n        side: bool, // true for YES, false for NO\n        max_price: u64,\n    }\n\n    /// Fixed-point scale for prices (1.0 == PRICE_SCALE)\n    const PRICE_SCALE: u64 = 1_000_000;\n\n    /// Basis point denominator for the trading fee\n    const BPS_DENOMINATOR: u64 = 10_000;\n\n    pub struct TradeReceipt {\n        shares_out: u64,\n        effective_price: u64,\n        fee: u64,\n        filled: bool,\n    }\n\n    /// Trade circuit output: the trader's encrypted receipt, the new pool\n    /// state, the trader's new position, then the revealed settlement\n    /// (side, shares to mint, collateral spent, collateral refunded).\n    ///\n    /// The settlement has to be public because the program mints SPL\n    /// outcome tokens and moves collateral in the clear. Each fill is\n    /// therefore visible, and anyone replaying fills and liquidity events\n    /// can track the pool, so the encrypted reserves and positions hide the\n    /// trader's limit price and running totals, not what a trade did.\n    type TradeOutput = (\n        Enc<Shared, TradeReceipt>,\n        Enc<Mxe, CfmmState>,\n        Enc<Shared, PositionState>,\n        bool,\n        u64,\n        u64,\n        u64,\n    );\n\n    /// Plaintext reference: programs/private-markets/src/cpmm.rs\n    #[instruction]\n    pub fn private_trade(\n        input_ctxt: Enc<Shared, PrivateTradeInput>,\n        state_ctxt: Enc<Mxe, CfmmState>,\n        position_ctxt: Enc<Shared, PositionState>,\n        escrow: u64,\n        fee_bps: u64,\n    ) -> TradeOutput {\n        let input = input_ctxt.to_arcis();\n        let state = state_ctxt.to_arcis();\n        let yes_reserves = state.yes_reserves;\n        let no_reserves = state.no_reserves;\n\n        // The trader can never spend more than the collateral they escrowed\n        let user_amount = if input.user_amount > escrow { escrow } else { input.user_amount };\n        let fee = user_amount * fee_bps / BPS_DENOMINATOR;\n        let amount_in = user_amount - fee;\n\n        // Constant product (x * y = k): the collateral mints a complete set\n        // into both reserves, then the bought side is withdrawn until the\n        // product is restored.\n        let (bought_reserve, other_reserve) = if input.side {\n            (yes_reserves, no_reserves)\n        } else {\n            (no_reserves, yes_reserves)\n        };\n        let k = (bought_reserve as u128) * (other_reserve as u128);\n        let new_other = other_reserve + amount_in;\n        let new_other_denominator = if new_other > 0 { new_other } else { 1 };\n        let new_bought = (k / (new_other_denominator as u128)) as u64;\n        let shares_out = bought_reserve + amount_in - new_bought;\n\n        let price_denominator = if shares_out > 0 { shares_out } else { 1 };\n        let effective_price = ((user_amount as u128) * (PRICE_SCALE as u128)\n            / (price_denominator as u128)) as u64;\n\n        // An empty pool has no price to trade against, and the trader's\n        // max_price is their slippage limit\n        let filled = k > 0 && shares_out > 0 && effective_price <= input.max_price;\n\n        // Rejected trades leave the pool untouched\n        let (new_yes_reserves, new_no_reserves) = if !filled {\n            (yes_reserves, no_reserves)\n        } else if input.side {\n            (new_bought, new_other)\n        } else {\n            (new_other, new_bought)\n        };\n\n        let receipt = TradeReceipt {\n            shares_out: if filled { shares_out } else { 0 },\n            effective_price,\n            fee: if filled { fee } else { 0 },\n            filled,\n        };\n\n        let new_state = CfmmState {\n            yes_reserves: new_yes_reserves,\n            no_reserves: new_no_reserves,\n        };\n\n        let new_position = apply_fill(\n            position_ctxt.to_arcis(),\n            input.side,\n            shares_out,\n            user_amount,\n            filled,\n        );\n        let settlement = settle(filled, input.side, shares_out, user_amount, escrow);\n\n        // Trader gets the encrypted receipt and position; the reserves stay\n        // encrypted to the MXE. Only the settlement needed to move tokens is\n        // revealed.\n        (\n            input_ctxt.owner.from_arcis(receipt),\n            state_ctxt.owner.from_arcis(new_state),\n            position_ctxt.owner.from_arcis(new_position),\n            settlement.0.reveal(),\n            settlement.1.reveal(),\n            settlement.2.reveal(),\n            settlement.3.reveal(),\n        )\n    }\n\n    // ========== LMSR Trade Circuit ==========\n    // Plaintext reference: programs/private-markets/src/lmsr.rs\n\n    /// Fixed-point scale for the exp/ln internals (1.0 == FIXED_ONE)\n    const FIXED_ONE: u128 = 1_000_000_000_000;\n\n    /// Largest exponent argument exp_fixed takes (e^15), keeps products\n    /// within u128\n    const MAX_EXP_ARG: u128 = 15 * FIXED_ONE;\n\n    /// ln(2) in FIXED_ONE fixed point\n    const LN_2: u128 = 693_147_180_560;\n\n    /// e^x for 0 <= x <= MAX_EXP_ARG in FIXED_ONE fixed point: Taylor series\n    /// on x / 32, then squared five times. Callers keep x in range.\n    fn exp_fixed(x: u128) -> u128 {\n        let one = FIXED_ONE;\n        let r = x / 32;\n\n        let mut term = one;\n        let mut sum = one;\n        for i in 1..9u128 {\n            term = term * r / (one * i);\n            sum += term;\n        }\n        for _ in 0..5 {\n            sum = sum * sum / one;\n        }\n        sum\n    }\n\n    /// ln(y) for y >= 1 in FIXED_ONE fixed point: halve into [1, 2), then\n    /// the atanh series ln(y) = 2 * (z + z^3/3 + ...) with z = (y - 1) / (y + 1)\n    fn ln_fixed(y: u128) -> u128 {\n        let one = FIXED_ONE;\n        let mut y = if y < one { one } else { y };\n        let mut halvings = 0u128;\n        for _ in 0..64 {\n            let halve = y >= 2 * one;\n            y = if halve { y / 2 } else { y };\n            halvings = if halve { halvings + 1 } else { halvings };\n        }\n\n        let z = (y - one) * one / (y + one);\n        let z2 = z * z / one;\n        let mut term = z;\n        let mut sum = z;\n        for k in [3u128, 5, 7, 9, 11] {\n            term = term * z2 / one;\n            sum += term / k;\n        }\n        halvings * LN_2 + 2 * sum\n    }\n\n    /// Shares of one outcome bought for `amount_in` collateral under\n    /// C(q) = b * ln(e^(q_bought / b) + e^(q_other / b)):\n    /// shares = b * ln(e^(x / b) * (1 + e^d) - e^d), d = (q_other - q_bought) / b.\n    /// Zero, so the trade is rejected, when x / b is past MAX_EXP_ARG.\n    fn lmsr_shares_out(q_bought: u64, q_other: u64, b: u64, amount_in: u64) -> u64 {\n        let one = FIXED_ONE;\n        let b = b as u128;\n\n        let x = (amount_in as u128) * one / b;\n        let in_range = x <= MAX_EXP_ARG;\n        let e_x = exp_fixed(if in_range { x } else { 0 });\n\n        // Past MAX_EXP_ARG the trailing outcome costs under e^-15. Holding e^d\n        // at e^15 when buying it, or taking e^-d as 0 when buying the leader,\n        // both sit below the exact curve, so the pool never overpays.\n        let other_ahead = q_other >= q_bought;\n        let d_abs = if other_ahead {\n            ((q_other - q_bought) as u128) * one / b\n        } else {\n            ((q_bought - q_other) as u128) * one / b\n        };\n        let d_saturated = d_abs > MAX_EXP_ARG;\n        let e_d_abs = exp_fixed(if d_saturated { MAX_EXP_ARG } else { d_abs });\n        let e_d = if other_ahead {\n            e_d_abs\n        } else if d_saturated {\n            0\n        } else {\n            one * one / e_d_abs\n        };\n\n        let inner = e_x * (one + e_d) / one - e_d;\n        let shares = (b * ln_fixed(inner) / one) as u64;\n        if in_range { shares } else { 0 }\n    }\n\n    #[instruction]\n    pub fn lmsr_trade(\n        input_ctxt: Enc<Shared, PrivateTradeInput>,\n        state_ctxt: Enc<Mxe, CfmmState>,\n        position_ctxt: Enc<Shared, PositionState>,\n        escrow: u64,\n        liquidity_b: u64,\n        fee_bps: u64,\n    ) -> TradeOutput {\n        let input = input_ctxt.to_arcis();\n        let state = state_ctxt.to_arcis();\n        let q_yes = state.yes_reserves;\n        let q_no = state.no_reserves;\n\n        let user_amount = if input.user_amount > escrow { escrow } else { input.user_amount };\n        let fee = user_amount * fee_bps / BPS_DENOMINATOR;\n        let amount_in = user_amount - fee;\n\n        let (q_bought, q_other) = if input.side { (q_yes, q_no) } else { (q_no, q_yes) };\n        let shares_out = lmsr_shares_out(q_bought, q_other, liquidity_b, amount_in);\n\n        let price_denominator = if shares_out > 0 { shares_out } else { 1 };\n        let effective_price = ((user_amount as u128) * (PRICE_SCALE as u128)\n            / (price_denominator as u128)) as u64;\n\n        let filled = shares_out > 0 && effective_price <= input.max_price;\n\n        // Outstanding share quantities only move for filled trades\n        let (new_q_yes, new_q_no) = if !filled {\n            (q_yes, q_no)\n        } else if input.side {\n            (q_yes + shares_out, q_no)\n        } else {\n            (q_yes, q_no + shares_out)\n        };\n\n        let receipt = TradeReceipt {\n            shares_out: if filled { shares_out } else { 0 },\n            effective_price,\n            fee: if filled { fee } else { 0 },\n            filled,\n        };\n\n        let new_state = CfmmState {\n            yes_reserves: new_q_yes,\n            no_reserves: new_q_no,\n        };\n\n        let new_position = apply_fill(\n            position_ctxt.to_arcis(),\n            input.side,\n            shares_out,\n            user_amount,\n            filled,\n        );\n        let settlement = settle(filled, input.side, shares_out, user_amount, escrow);\n\n        (\n            input_ctxt.owner.from_arcis(receipt),\n            state_ctxt.owner.from_arcis(new_state),\n            position_ctxt.owner.from_arcis(new_position),\n            settlement.0.reveal(),\n            settlement.1.reveal(),\n            settlement.2.reveal(),\n            settlement.3.reveal(),\n        )\n    }\n\n    // ========== Batch Clear Circuit ==========\n\n    pub struct BatchOrder {\n        amount: u64,\n        side: bool,\n        limit_price: u64,\n    }\n\n    /// Granularity of the publicly revealed price (1% of PRICE_SCALE)\n    const PRICE_TICK: u64 = 10_000;\n\n    /// YES price implied by the reserves, rounded down to PRICE_TICK\n    fn coarse_price(yes_reserves: u64, no_reserves: u64) -> u64 {\n        let total = (yes_reserves as u128) + (no_reserves as u128);\n        let denominator = if total > 0 { total } else { 1 };\n        let price = if total > 0 {\n            ((no_reserves as u128) * (PRICE_SCALE as u128) / denominator) as u64\n        } else {\n            PRICE_SCALE / 2\n        };\n        price / PRICE_TICK * PRICE_TICK\n    }\n\n    /// Orders per batch; must match MAX_BATCH_ORDERS in the program\n    const MAX_BATCH_ORDERS: usize = 4;\n\n    /// A trader's result from one batch, encrypted to the trader\n    pub struct OrderFill {\n        shares: u64,\n        spent: u64,\n    }\n\n    /// Largest value a u64 output can carry\n    const U64_MAX: u128 = 18_446_744_073_709_551_615;\n\n    /// `value` as a u64, saturating at u64::MAX\n    fn saturate_u64(value: u128) -> u64 {\n        (if value > U64_MAX { U64_MAX } else { value }) as u64\n    }\n\n    /// Collateral from `orders` willing to trade at YES price `yes_price`,\n    /// split into (YES side, NO side). A NO limit of p is a YES limit of 1 - p.\n    /// Summed in u128 so a full batch of large escrows cannot overflow.\n    fn eligible_collateral(\n        orders: &[BatchOrder; MAX_BATCH_ORDERS],\n        amounts: &[u64; MAX_BATCH_ORDERS],\n        yes_price: u64,\n    ) -> (u128, u128) {\n        let mut yes_collateral = 0u128;\n        let mut no_collateral = 0u128;\n        for i in 0..MAX_BATCH_ORDERS {\n            let order = &orders[i];\n            let yes_ok = order.side && order.limit_price >= yes_price;\n            let no_ok = !order.side && order.limit_price >= PRICE_SCALE - yes_price;\n            yes_collateral += if yes_ok { amounts[i] as u128 } else { 0 };\n            no_collateral += if no_ok { amounts[i] as u128 } else { 0 };\n        }\n        (yes_collateral, no_collateral)\n    }\n\n    /// Shares bought for `collateral` at `price` (PRICE_SCALE fixed point)\n    fn shares_at(collateral: u128, price: u64) -> u128 {\n        let denominator = if price > 0 { price } else { 1 };\n        collateral * (PRICE_SCALE as u128) / (denominator as u128)\n    }\n\n    /// `n / d` rounded up, for `d > 0`. Spelled out because the circuit\n    /// build of `u128::div_ceil` does not match Rust's.\n    #[allow(clippy::manual_div_ceil)]\n    fn div_ceil(n: u128, d: u128) -> u128 {\n        (n + d - 1) / d\n    }\n\n    /// Batch clear output: the new pool state, the public coarse price, the\n    /// uniform clearing price, each slot's fill encrypted to its trader, then\n    /// each slot's revealed settlement (side, shares, refund)\n    type BatchClearOutput = (\n        Enc<Mxe, CfmmState>,\n        u64,\n        u64,\n        Enc<Shared, OrderFill>,\n        Enc<Shared, OrderFill>,\n        Enc<Shared, OrderFill>,\n        Enc<Shared, OrderFill>,\n        [bool; MAX_BATCH_ORDERS],\n        [u64; MAX_BATCH_ORDERS],\n        [u64; MAX_BATCH_ORDERS],\n    );\n\n    #[instruction]\n    pub fn batch_clear(\n        order_ctxts: [Enc<Shared, BatchOrder>; MAX_BATCH_ORDERS],\n        escrows: [u64; MAX_BATCH_ORDERS],\n        order_count: u8,\n        route_to_pool: bool,\n        state_ctxt: Enc<Mxe, CfmmState>,\n    ) -> BatchClearOutput {\n        let [order_0, order_1, order_2, order_3] = order_ctxts;\n        let orders = [\n            order_0.to_arcis(),\n            order_1.to_arcis(),\n            order_2.to_arcis(),\n            order_3.to_arcis(),\n        ];\n        let state = state_ctxt.to_arcis();\n\n        // Each order spends at most its escrow; unused slots spend nothing\n        let mut active = [false; MAX_BATCH_ORDERS];\n        let mut amounts = [0u64; MAX_BATCH_ORDERS];\n        for i in 0..MAX_BATCH_ORDERS {\n            active[i] = (i as u8) < order_count;\n            let amount = if orders[i].amount > escrows[i] { escrows[i] } else { orders[i].amount };\n            amounts[i] = if active[i] { amount } else { 0 };\n        }\n\n        // Uniform price: the order limit (in YES terms) that matches the most\n        // YES against NO shares. A YES share at p and a NO share at 1 - p\n        // together are one complete set, fully backed by their collateral.\n        let mut best_price = 0u64;\n        let mut best_matched = 0u128;\n        for j in 0..MAX_BATCH_ORDERS {\n            let order = &orders[j];\n            let no_limit = if order.limit_price > PRICE_SCALE { PRICE_SCALE } else { order.limit_price };\n            let candidate = if order.side { order.limit_price } else { PRICE_SCALE - no_limit };\n            let candidate = if candidate > PRICE_SCALE { PRICE_SCALE } else { candidate };\n            let valid = amounts[j] > 0 && candidate > 0 && candidate < PRICE_SCALE;\n\n            let (yes_collateral, no_collateral) = eligible_collateral(&orders, &amounts, candidate);\n            let yes_shares = shares_at(yes_collateral, candidate);\n            let no_shares = shares_at(no_collateral, PRICE_SCALE - candidate);\n            let matched = if yes_shares < no_shares { yes_shares } else { no_shares };\n\n            let better = valid && matched > best_matched;\n            best_price = if better { candidate } else { best_price };\n            best_matched = if better { matched } else { best_matched };\n        }\n\n        // Without a cross, orders trade against the pool at its current price\n        let pool_total = (state.yes_reserves as u128) + (state.no_reserves as u128);\n        let pool_denominator = if pool_total > 0 { pool_total } else { 1 };\n        let pool_price = if pool_total > 0 {\n            ((state.no_reserves as u128) * (PRICE_SCALE as u128) / pool_denominator) as u64\n        } else {\n            PRICE_SCALE / 2\n        };\n        let clearing_price = if best_matched > 0 { best_price } else { pool_price };\n        let no_price = PRICE_SCALE - clearing_price;\n\n        let (yes_collateral, no_collateral) = eligible_collateral(&orders, &amounts, clearing_price);\n        let yes_shares = shares_at(yes_collateral, clearing_price);\n        let no_shares = shares_at(no_collateral, no_price);\n        let long_is_yes = yes_shares > no_shares;\n        let matched = if long_is_yes { no_shares } else { yes_shares };\n\n        // The side with more demand is only matched up to the other side;\n        // its residual collateral is offered to the pool\n        let (long_collateral, long_price) = if long_is_yes {\n            (yes_collateral, clearing_price)\n        } else {\n            (no_collateral, no_price)\n        };\n        // Rounded up so the matched shares are fully paid for\n        let matched_collateral = div_ceil(matched * (long_price as u128), PRICE_SCALE as u128);\n        let residual = long_collateral - matched_collateral;\n\n        let (bought_reserve, other_reserve) = if long_is_yes {\n            (state.yes_reserves as u128, state.no_reserves as u128)\n        } else {\n            (state.no_reserves as u128, state.yes_reserves as u128)\n        };\n        let k = bought_reserve * other_reserve;\n        let new_other = other_reserve + residual;\n        let new_other_denominator = if new_other > 0 { new_other } else { 1 };\n        let new_bought = k / new_other_denominator;\n        let pool_shares = bought_reserve + residual - new_bought;\n\n        // Only route to the pool if its average price is within the uniform\n        // price and its reserves still fit once the residual is added\n        let routed = route_to_pool\n            && residual > 0\n            && pool_shares > 0\n            && new_other <= U64_MAX\n            && pool_shares * (long_price as u128) >= residual * (PRICE_SCALE as u128);\n\n        let long_shares = if routed { matched + pool_shares } else { matched };\n        let long_spent = if routed { long_collateral } else { matched_collateral };\n\n        let (new_yes_reserves, new_no_reserves) = if !routed {\n            (state.yes_reserves, state.no_reserves)\n        } else if long_is_yes {\n            (new_bought as u64, new_other as u64)\n        } else {\n            (new_other as u64, new_bought as u64)\n        };\n\n        // Short side fills in full at the uniform price; the long side is\n        // rationed pro rata by collateral, with shares rounded down and spend\n        // rounded up so every order pays at least the price of its shares\n        let long_denominator = if long_collateral > 0 { long_collateral } else { 1 };\n        let mut sides = [false; MAX_BATCH_ORDERS];\n        let mut shares = [0u64; MAX_BATCH_ORDERS];\n        let mut spent = [0u64; MAX_BATCH_ORDERS];\n        let mut refunds = [0u64; MAX_BATCH_ORDERS];\n        for i in 0..MAX_BATCH_ORDERS {\n            let order = &orders[i];\n            let eligible = if order.side {\n                order.limit_price >= clearing_price\n            } else {\n                order.limit_price >= no_price\n            };\n            let amount = if eligible { amounts[i] } else { 0 };\n            let on_long_side = order.side == long_is_yes;\n\n            let long_fill_shares = (amount as u128) * long_shares / long_denominator;\n            let long_fill_spent = div_ceil((amount as u128) * long_spent, long_denominator) as u64;\n            let short_price = if order.side { clearing_price } else { no_price };\n            let short_fill_shares = shares_at(amount as u128, short_price);\n\n            let order_shares = saturate_u64(if on_long_side { long_fill_shares } else { short_fill_shares });\n            let order_spent = if on_long_side { long_fill_spent } else { amount };\n\n            // Whatever an unused slot holds, it settles to nothing\n            sides[i] = active[i] && order.side && order_shares > 0;\n            shares[i] = if active[i] { order_shares } else { 0 };\n            spent[i] = if active[i] { order_spent } else { 0 };\n            refunds[i] = if active[i] { escrows[i] - order_spent } else { 0 };\n        }\n\n        let new_state = CfmmState {\n            yes_reserves: new_yes_reserves,\n            no_reserves: new_no_reserves,\n        };\n        let public_price = coarse_price(new_state.yes_reserves, new_state.no_reserves);\n\n        // The uniform price and each order's settlement are public once the\n        // batch clears; each trader also gets their fill encrypted to them\n        (\n            state_ctxt.owner.from_arcis(new_state),\n            public_price.reveal(),\n            clearing_price.reveal(),\n            order_0.owner.from_arcis(OrderFill { shares: shares[0], spent: spent[0] }),\n            order_1.owner.from_arcis(OrderFill { shares: shares[1], spent: spent[1] }),\n            order_2.owner.from_arcis(OrderFill { shares: shares[2], spent: spent[2] }),\n            order_3.owner.from_arcis(OrderFill { shares: shares[3], spent: spent[3] }),\n            sides.reveal(),\n            shares.reveal(),\n            refunds.reveal(),\n        )\n    }\n\n    // ========== Liquidity Circuits ==========\n\n    /// Deposit `amount` complete sets into the pool. Shares are priced against\n    /// the pool weight max(yes, no) plus the LP fees already accrued, so part\n    /// of the deposit (`fee_buy_in`) buys into those fees. The pool absorbs\n    /// the rest in proportion to its reserves and the leftover outcome tokens\n    /// go back to the provider. Those are minted publicly, so a deposit\n    /// reveals the pool ratio at that moment. Only a deposit into a pool with\n    /// neither shares nor reserves seeds it at one share per complete set.\n    #[instruction]\n    pub fn add_liquidity(\n        state_ctxt: Enc<Mxe, CfmmState>,\n        amount: u64,\n        total_shares: u64,\n        lp_fees_accrued: u64,\n    ) -> (Enc<Mxe, CfmmState>, u64, u64, u64, u64) {\n        let state = state_ctxt.to_arcis();\n\n        let pool_weight = if state.yes_reserves > state.no_reserves {\n            state.yes_reserves\n        } else {\n            state.no_reserves\n        };\n        let empty_pool = pool_weight == 0;\n        let seeding = total_shares == 0 && empty_pool;\n        // Shares with no reserves behind them, or reserves no shares claim,\n        // mean the books are out of step. Pricing against them would dilute\n        // the existing providers or hand the depositor ownerless reserves, so\n        // the deposit comes back as a complete set of outcome tokens instead.\n        let mismatched = (total_shares == 0 && !empty_pool) || (total_shares > 0 && empty_pool);\n        let weight = if empty_pool { 1 } else { pool_weight as u128 };\n        let value = weight + (lp_fees_accrued as u128);\n\n        let shares = if seeding {\n            amount\n        } else if mismatched {\n            0\n        } else {\n            ((amount as u128) * (total_shares as u128) / value) as u64\n        };\n        let deposited = if seeding {\n            amount\n        } else if mismatched {\n            0\n        } else {\n            ((amount as u128) * weight / value) as u64\n        };\n        let yes_added = if seeding {\n            amount\n        } else {\n            ((deposited as u128) * (state.yes_reserves as u128) / weight) as u64\n        };\n        let no_added = if seeding {\n            amount\n        } else {\n            ((deposited as u128) * (state.no_reserves as u128) / weight) as u64\n        };\n        let fee_buy_in = if mismatched { 0 } else { amount - deposited };\n        let yes_returned = if mismatched { amount } else { deposited - yes_added };\n        let no_returned = if mismatched { amount } else { deposited - no_added };\n\n        let new_state = CfmmState {\n            yes_reserves: state.yes_reserves + yes_added,\n            no_reserves: state.no_reserves + no_added,\n        };\n\n        (\n            state_ctxt.owner.from_arcis(new_state),\n            shares.reveal(),\n            fee_buy_in.reveal(),\n            yes_returned.reveal(),\n            no_returned.reveal(),\n        )\n    }\n\n    /// Withdraw `shares / total_shares` of both reserves. Shares beyond\n    /// `total_shares` are clamped so the pool can never be overdrawn.\n    #[instruction]\n    pub fn remove_liquidity(\n        state_ctxt: Enc<Mxe, CfmmState>,\n        shares: u64,\n        total_shares: u64,\n    ) -> (Enc<Mxe, CfmmState>, u64, u64) {\n        let state = state_ctxt.to_arcis();\n\n        let shares = if shares > total_shares { total_shares } else { shares };\n        let total = if total_shares > 0 { total_shares as u128 } else { 1 };\n        let yes_out = ((state.yes_reserves as u128) * (shares as u128) / total) as u64;\n        let no_out = ((state.no_reserves as u128) * (shares as u128) / total) as u64;\n\n        let new_state = CfmmState {\n            yes_reserves: state.yes_reserves - yes_out,\n            no_reserves: state.no_reserves - no_out,\n        };\n\n        (\n            state_ctxt.owner.from_arcis(new_state),\n            yes_out.reveal(),\n            no_out.reveal(),\n        )\n    }\n\n    // ========== Resolve Market Circuit ==========\n\n    /// Resolver slots per market; must match MAX_RESOLVERS in the program\n    const MAX_RESOLVERS: usize = 10;\n\n    pub struct Attestation {\n        outcome: bool, // true for YES, false for NO\n    }\n\n    /// Final outcome codes; must match OUTCOME_NO / OUTCOME_YES /\n    /// OUTCOME_INVALID in the program\n    const OUTCOME_NO: u8 = 0;\n    const OUTCOME_YES: u8 = 1;\n    const OUTCOME_INVALID: u8 = 2;\n\n    pub struct ResolutionResult {\n        final_outcome: u8,\n        confidence: u64,\n    }\n\n    /// Stake-weighted majority over every resolver's encrypted vote. Unused\n    /// slots carry zero stake and so never count. A tie, including no stake\n    /// at all, resolves INVALID rather than favouring either side.\n    #[instruction]\n    pub fn resolve_market(\n        vote_ctxts: [Enc<Shared, Attestation>; MAX_RESOLVERS],\n        stakes: [u64; MAX_RESOLVERS],\n    ) -> (u8, u64) {\n        // Weighted voting: sum stake behind each outcome\n        let mut yes_weight = 0u64;\n        let mut no_weight = 0u64;\n        for (vote_ctxt, stake) in vote_ctxts.into_iter().zip(stakes) {\n            let vote = vote_ctxt.to_arcis();\n            yes_weight += if vote.outcome { stake } else { 0 };\n            no_weight += if vote.outcome { 0 } else { stake };\n        }\n\n        let final_outcome = if yes_weight > no_weight {\n            OUTCOME_YES\n        } else if no_weight > yes_weight {\n            OUTCOME_NO\n        } else {\n            OUTCOME_INVALID\n        };\n        let confidence = if yes_weight > no_weight { yes_weight } else { no_weight };\n\n        let result = ResolutionResult {\n            final_outcome,\n            confidence,\n        };\n\n        // Only the outcome and the stake behind it are revealed, never how\n        // any single resolver voted\n        (result.final_outcome.reveal(), result.confidence.reveal())\n    }\n}\n","code":[{"code":"0","region":{"Known":[{"line":18,"column":26},{"line":18,"column":27}]}},{"code":"0","region":{"Known":[{"line":19,"column":25},{"line":19,"column":26}]}},{"code":"CfmmState { yes_reserves : 0, no_reserves : 0, }","region":{"Known":[{"line":17,"column":23},{"line":20,"column":9}]}},{"code":"self.nonce += 1;","region":"Unknown"},{"code":"self","region":"Unknown"},{"code":"a","region":"Unknown"},{"code":"a","region":"Unknown"},{"code":"self.from_arcis_inner(a)","region":"Unknown"},{"code":"a","region":"Unknown"},{"code":"let phantom = [a; 0];","region":"Unknown"},{"code":"data","region":"Unknown"},{"code":"phantom","region":"Unknown"},{"code":"let data = EncData { data, phantom };","region":"Unknown"},{"code":"owner","region":"Unknown"},{"code":"data","region":"Unknown"},{"code":"Self { owner, data }","region":"Unknown"},{"code":"Enc :: new_inner(self, a, self.from_arcis_inner(a))","region":"Unknown"},{"code":"mxe.from_arcis(CfmmState { yes_reserves : 0, no_reserves : 0, })","region":{"Known":[{"line":17,"column":8},{"line":20,"column":10}]}},{"code":"0","region":{"Known":[{"line":36,"column":24},{"line":36,"column":25}]}},{"code":"0","region":{"Known":[{"line":37,"column":23},{"line":37,"column":24}]}},{"code":"0","region":{"Known":[{"line":38,"column":24},{"line":38,"column":25}]}},{"code":"PositionState { yes_shares : 0, no_shares : 0, cost_basis : 0, }","region":{"Known":[{"line":35,"column":25},{"line":39,"column":9}]}},{"code":"self.nonce += 1;","region":"Unknown"},{"code":"self","region":"Unknown"},{"code":"a","region":"Unknown"},{"code":"a","region":"Unknown"},{"code":"self.from_arcis_inner(a)","region":"Unknown"},{"code":"Enc :: new_inner(self, a, self.from_arcis_inner(a))","region":"Unknown"},{"code":"owner.from_arcis(PositionState\n{ yes_shares : 0, no_shares : 0, cost_basis : 0, })","region":{"Known":[{"line":35,"column":8},{"line":39,"column":10}]}},{"code":"self.owner","region":"Unknown"},{"code":"self.data","region":"Unknown"},{"code":"Self :: __arcis_inner(self.owner, self.data)","region":"Unknown"},{"code":"let input = input_ctxt.to_arcis();","region":{"Known":[{"line":128,"column":8},{"line":128,"column":42}]}},{"code":"let state = state_ctxt.to_arcis();","region":{"Known":[{"line":129,"column":8},{"line":129,"column":42}]}},{"code":"let yes_reserves = state.yes_reserves;","region":{"Known":[{"line":130,"column":8},{"line":130,"column":46}]}},{"code":"let no_reserves = state.no_reserves;","region":{"Known":[{"line":131,"column":8},{"line":131,"column":44}]}},{"code":"input.user_amount > escrow","region":{"Known":[{"line":134,"column":29},{"line":134,"column":55}]}},{"code":"input.user_amount","region":{"Known":[{"line":134,"column":74},{"line":134,"column":91}]}},{"code":"escrow","region":{"Known":[{"line":134,"column":58},{"line":134,"column":64}]}},{"code":"let user_amount = if input.user_amount > escrow { escrow } else\n{ input.user_amount };","region":{"Known":[{"line":134,"column":8},{"line":134,"column":94}]}},{"code":"let fee = user_amount * fee_bps / BPS_DENOMINATOR;","region":{"Known":[{"line":135,"column":8},{"line":135,"column":58}]}},{"code":"let amount_in = user_amount - fee;","region":{"Known":[{"line":136,"column":8},{"line":136,"column":42}]}},{"code":"input.side","region":{"Known":[{"line":141,"column":49},{"line":141,"column":59}]}},{"code":"no_reserves","region":{"Known":[{"line":144,"column":13},{"line":144,"column":24}]}},{"code":"yes_reserves","region":{"Known":[{"line":144,"column":26},{"line":144,"column":38}]}},{"code":"(no_reserves, yes_reserves)","region":{"Known":[{"line":144,"column":12},{"line":144,"column":39}]}},{"code":"yes_reserves","region":{"Known":[{"line":142,"column":13},{"line":142,"column":25}]}},{"code":"no_reserves","region":{"Known":[{"line":142,"column":27},{"line":142,"column":38}]}},{"code":"(yes_reserves, no_reserves)","region":{"Known":[{"line":142,"column":12},{"line":142,"column":39}]}},{"code":"let (bought_reserve, other_reserve) = if input.side\n{ (yes_reserves, no_reserves) } else { (no_reserves, yes_reserves) };","region":{"Known":[{"line":141,"column":8},{"line":145,"column":10}]}},{"code":"bought_reserve as u128","region":{"Known":[{"line":146,"column":17},{"line":146,"column":39}]}},{"code":"other_reserve as u128","region":{"Known":[{"line":146,"column":44},{"line":146,"column":65}]}},{"code":"let k = (bought_reserve as u128) * (other_reserve as u128);","region":{"Known":[{"line":146,"column":8},{"line":146,"column":67}]}},{"code":"let new_other = other_reserve + amount_in;","region":{"Known":[{"line":147,"column":8},{"line":147,"column":50}]}},{"code":"new_other > 0","region":{"Known":[{"line":148,"column":39},{"line":148,"column":52}]}},{"code":"1","region":{"Known":[{"line":148,"column":74},{"line":148,"column":75}]}},{"code":"new_other","region":{"Known":[{"line":148,"column":55},{"line":148,"column":64}]}},{"code":"let new_other_denominator = if new_other > 0 { new_other } else { 1 };","region":{"Known":[{"line":148,"column":8},{"line":148,"column":78}]}},{"code":"new_other_denominator as u128","region":{"Known":[{"line":149,"column":31},{"line":149,"column":60}]}},{"code":"k / (new_other_denominator as u128)","region":{"Known":[{"line":149,"column":26},{"line":149,"column":61}]}},{"code":"let new_bought = (k / (new_other_denominator as u128)) as u64;","region":{"Known":[{"line":149,"column":8},{"line":149,"column":70}]}},{"code":"let shares_out = bought_reserve + amount_in - new_bought;","region":{"Known":[{"line":150,"column":8},{"line":150,"column":65}]}},{"code":"shares_out > 0","region":{"Known":[{"line":152,"column":35},{"line":152,"column":49}]}},{"code":"1","region":{"Known":[{"line":152,"column":72},{"line":152,"column":73}]}},{"code":"shares_out","region":{"Known":[{"line":152,"column":52},{"line":152,"column":62}]}},{"code":"let price_denominator = if shares_out > 0 { shares_out } else { 1 };","region":{"Known":[{"line":152,"column":8},{"line":152,"column":76}]}},{"code":"user_amount as u128","region":{"Known":[{"line":153,"column":32},{"line":153,"column":51}]}},{"code":"PRICE_SCALE as u128","region":{"Known":[{"line":153,"column":56},{"line":153,"column":75}]}},{"code":"price_denominator as u128","region":{"Known":[{"line":154,"column":15},{"line":154,"column":40}]}},{"code":"(user_amount as u128) * (PRICE_SCALE as u128) / (price_denominator as u128)","region":{"Known":[{"line":153,"column":31},{"line":154,"column":41}]}},{"code":"let effective_price =\n((user_amount as u128) * (PRICE_SCALE as u128) / (price_denominator as u128))\nas u64;","region":{"Known":[{"line":153,"column":8},{"line":154,"column":50}]}},{"code":"let filled = k > 0 && shares_out > 0 && effective_price <= input.max_price;","region":{"Known":[{"line":158,"column":8},{"line":158,"column":83}]}},{"code":"! filled","region":{"Known":[{"line":161,"column":53},{"line":161,"column":60}]}},{"code":"input.side","region":{"Known":[{"line":163,"column":18},{"line":163,"column":28}]}},{"code":"new_other","region":{"Known":[{"line":166,"column":13},{"line":166,"column":22}]}},{"code":"new_bought","region":{"Known":[{"line":166,"column":24},{"line":166,"column":34}]}},{"code":"(new_other, new_bought)","region":{"Known":[{"line":166,"column":12},{"line":166,"column":35}]}},{"code":"new_bought","region":{"Known":[{"line":164,"column":13},{"line":164,"column":23}]}},{"code":"new_other","region":{"Known":[{"line":164,"column":25},{"line":164,"column":34}]}},{"code":"(new_bought, new_other)","region":{"Known":[{"line":164,"column":12},{"line":164,"column":35}]}},{"code":"yes_reserves","region":{"Known":[{"line":162,"column":13},{"line":162,"column":25}]}},{"code":"no_reserves","region":{"Known":[{"line":162,"column":27},{"line":162,"column":38}]}},{"code":"(yes_reserves, no_reserves)","region":{"Known":[{"line":162,"column":12},{"line":162,"column":39}]}},{"code":"let (new_yes_reserves, new_no_reserves) = if ! filled\n{ (yes_reserves, no_reserves) } else if input.side { (new_bought, new_other) }\nelse { (new_other, new_bought) };","region":{"Known":[{"line":161,"column":8},{"line":167,"column":10}]}},{"code":"filled","region":{"Known":[{"line":170,"column":27},{"line":170,"column":33}]}},{"code":"0","region":{"Known":[{"line":170,"column":56},{"line":170,"column":57}]}},{"code":"shares_out","region":{"Known":[{"line":170,"column":36},{"line":170,"column":46}]}},{"code":"if filled { shares_out } else { 0 }","region":{"Known":[{"line":170,"column":24},{"line":170,"column":59}]}},{"code":"effective_price","region":{"Known":[{"line":171,"column":12},{"line":171,"column":27}]}},{"code":"filled","region":{"Known":[{"line":172,"column":20},{"line":172,"column":26}]}},{"code":"0","region":{"Known":[{"line":172,"column":42},{"line":172,"column":43}]}},{"code":"fee","region":{"Known":[{"line":172,"column":29},{"line":172,"column":32}]}},{"code":"if filled { fee } else { 0 }","region":{"Known":[{"line":172,"column":17},{"line":172,"column":45}]}},{"code":"filled","region":{"Known":[{"line":173,"column":12},{"line":173,"column":18}]}},{"code":"let receipt = TradeReceipt\n{\n    shares_out : if filled { shares_out } else { 0 }, effective_price, fee :\n    if filled { fee } else { 0 }, filled,\n};","region":{"Known":[{"line":169,"column":8},{"line":174,"column":10}]}},{"code":"new_yes_reserves","region":{"Known":[{"line":177,"column":26},{"line":177,"column":42}]}},{"code":"new_no_reserves","region":{"Known":[{"line":178,"column":25},{"line":178,"column":40}]}},{"code":"let new_state = CfmmState\n{ yes_reserves : new_yes_reserves, no_reserves : new_no_reserves, };","region":{"Known":[{"line":176,"column":8},{"line":179,"column":10}]}},{"code":"position_ctxt.to_arcis()","region":{"Known":[{"line":182,"column":12},{"line":182,"column":36}]}},{"code":"input.side","region":{"Known":[{"line":183,"column":12},{"line":183,"column":22}]}},{"code":"shares_out","region":{"Known":[{"line":184,"column":12},{"line":184,"column":22}]}},{"code":"user_amount","region":{"Known":[{"line":185,"column":12},{"line":185,"column":23}]}},{"code":"filled","region":{"Known":[{"line":186,"column":12},{"line":186,"column":18}]}},{"code":"filled && side","region":{"Known":[{"line":50,"column":25},{"line":50,"column":39}]}},{"code":"0","region":{"Known":[{"line":50,"column":62},{"line":50,"column":63}]}},{"code":"shares_out","region":{"Known":[{"line":50,"column":42},{"line":50,"column":52}]}},{"code":"let yes_add = if filled && side { shares_out } else { 0 };","region":{"Known":[{"line":50,"column":8},{"line":50,"column":66}]}},{"code":"filled && ! side","region":{"Known":[{"line":51,"column":24},{"line":51,"column":39}]}},{"code":"0","region":{"Known":[{"line":51,"column":62},{"line":51,"column":63}]}},{"code":"shares_out","region":{"Known":[{"line":51,"column":42},{"line":51,"column":52}]}},{"code":"let no_add = if filled && ! side { shares_out } else { 0 };","region":{"Known":[{"line":51,"column":8},{"line":51,"column":66}]}},{"code":"filled","region":{"Known":[{"line":52,"column":26},{"line":52,"column":32}]}},{"code":"0","region":{"Known":[{"line":52,"column":56},{"line":52,"column":57}]}},{"code":"user_amount","region":{"Known":[{"line":52,"column":35},{"line":52,"column":46}]}},{"code":"let cost_add = if filled { user_amount } else { 0 };","region":{"Known":[{"line":52,"column":8},{"line":52,"column":60}]}},{"code":"position.yes_shares + yes_add","region":{"Known":[{"line":54,"column":24},{"line":54,"column":53}]}},{"code":"position.no_shares + no_add","region":{"Known":[{"line":55,"column":23},{"line":55,"column":50}]}},{"code":"position.cost_basis + cost_add","region":{"Known":[{"line":56,"column":24},{"line":56,"column":54}]}},{"code":"PositionState\n{\n    yes_shares : position.yes_shares + yes_add, no_shares : position.no_shares\n    + no_add, cost_basis : position.cost_basis + cost_add,\n}","region":{"Known":[{"line":53,"column":8},{"line":57,"column":9}]}},{"code":"let new_position =\napply_fill(position_ctxt.to_arcis(), input.side, shares_out, user_amount,\nfilled,);","region":{"Known":[{"line":181,"column":8},{"line":187,"column":10}]}},{"code":"filled","region":{"Known":[{"line":188,"column":32},{"line":188,"column":38}]}},{"code":"input.side","region":{"Known":[{"line":188,"column":40},{"line":188,"column":50}]}},{"code":"shares_out","region":{"Known":[{"line":188,"column":52},{"line":188,"column":62}]}},{"code":"user_amount","region":{"Known":[{"line":188,"column":64},{"line":188,"column":75}]}},{"code":"escrow","region":{"Known":[{"line":188,"column":77},{"line":188,"column":83}]}},{"code":"filled","region":{"Known":[{"line":70,"column":23},{"line":70,"column":29}]}},{"code":"0","region":{"Known":[{"line":70,"column":53},{"line":70,"column":54}]}},{"code":"user_amount","region":{"Known":[{"line":70,"column":32},{"line":70,"column":43}]}},{"code":"let spent = if filled { user_amount } else { 0 };","region":{"Known":[{"line":70,"column":8},{"line":70,"column":57}]}},{"code":"filled && side","region":{"Known":[{"line":72,"column":12},{"line":72,"column":26}]}},{"code":"filled","region":{"Known":[{"line":73,"column":15},{"line":73,"column":21}]}},{"code":"0","region":{"Known":[{"line":73,"column":44},{"line":73,"column":45}]}},{"code":"shares_out","region":{"Known":[{"line":73,"column":24},{"line":73,"column":34}]}},{"code":"if filled { shares_out } else { 0 }","region":{"Known":[{"line":73,"column":12},{"line":73,"column":47}]}},{"code":"spent","region":{"Known":[{"line":74,"column":12},{"line":74,"column":17}]}},{"code":"escrow - spent","region":{"Known":[{"line":75,"column":12},{"line":75,"column":26}]}},{"code":"(filled && side, if filled { shares_out } else { 0 }, spent, escrow - spent,)","region":{"Known":[{"line":71,"column":8},{"line":76,"column":9}]}},{"code":"let settlement = settle(filled, input.side, shares_out, user_amount, escrow);","region":{"Known":[{"line":188,"column":8},{"line":188,"column":85}]}},{"code":"receipt","region":{"Known":[{"line":194,"column":40},{"line":194,"column":47}]}},{"code":"input_ctxt.owner.from_arcis(receipt)","region":{"Known":[{"line":194,"column":12},{"line":194,"column":48}]}},{"code":"new_state","region":{"Known":[{"line":195,"column":40},{"line":195,"column":49}]}},{"code":"state_ctxt.owner.from_arcis(new_state)","region":{"Known":[{"line":195,"column":12},{"line":195,"column":50}]}},{"code":"new_position","region":{"Known":[{"line":196,"column":43},{"line":196,"column":55}]}},{"code":"position_ctxt.owner.from_arcis(new_position)","region":{"Known":[{"line":196,"column":12},{"line":196,"column":56}]}},{"code":"settlement.0.reveal()","region":{"Known":[{"line":197,"column":12},{"line":197,"column":33}]}},{"code":"settlement.1.reveal()","region":{"Known":[{"line":198,"column":12},{"line":198,"column":33}]}},{"code":"settlement.2.reveal()","region":{"Known":[{"line":199,"column":12},{"line":199,"column":33}]}},{"code":"settlement.3.reveal()","region":{"Known":[{"line":200,"column":12},{"line":200,"column":33}]}},{"code":"(input_ctxt.owner.from_arcis(receipt), state_ctxt.owner.from_arcis(new_state),\nposition_ctxt.owner.from_arcis(new_position), settlement.0.reveal(),\nsettlement.1.reveal(), settlement.2.reveal(), settlement.3.reveal(),)","region":{"Known":[{"line":193,"column":8},{"line":201,"column":9}]}},{"code":"let input = input_ctxt.to_arcis();","region":{"Known":[{"line":303,"column":8},{"line":303,"column":42}]}},{"code":"let state = state_ctxt.to_arcis();","region":{"Known":[{"line":304,"column":8},{"line":304,"column":42}]}},{"code":"let q_yes = state.yes_reserves;","region":{"Known":[{"line":305,"column":8},{"line":305,"column":39}]}},{"code":"let q_no = state.no_reserves;","region":{"Known":[{"line":306,"column":8},{"line":306,"column":37}]}},{"code":"input.user_amount > escrow","region":{"Known":[{"line":308,"column":29},{"line":308,"column":55}]}},{"code":"input.user_amount","region":{"Known":[{"line":308,"column":74},{"line":308,"column":91}]}},{"code":"escrow","region":{"Known":[{"line":308,"column":58},{"line":308,"column":64}]}},{"code":"let user_amount = if input.user_amount > escrow { escrow } else\n{ input.user_amount };","region":{"Known":[{"line":308,"column":8},{"line":308,"column":94}]}},{"code":"let fee = user_amount * fee_bps / BPS_DENOMINATOR;","region":{"Known":[{"line":309,"column":8},{"line":309,"column":58}]}},{"code":"let amount_in = user_amount - fee;","region":{"Known":[{"line":310,"column":8},{"line":310,"column":42}]}},{"code":"input.side","region":{"Known":[{"line":312,"column":37},{"line":312,"column":47}]}},{"code":"q_no","region":{"Known":[{"line":312,"column":74},{"line":312,"column":78}]}},{"code":"q_yes","region":{"Known":[{"line":312,"column":80},{"line":312,"column":85}]}},{"code":"(q_no, q_yes)","region":{"Known":[{"line":312,"column":73},{"line":312,"column":86}]}},{"code":"q_yes","region":{"Known":[{"line":312,"column":51},{"line":312,"column":56}]}},{"code":"q_no","region":{"Known":[{"line":312,"column":58},{"line":312,"column":62}]}},{"code":"(q_yes, q_no)","region":{"Known":[{"line":312,"column":50},{"line":312,"column":63}]}},{"code":"let (q_bought, q_other) = if input.side { (q_yes, q_no) } else\n{ (q_no, q_yes) };","region":{"Known":[{"line":312,"column":8},{"line":312,"column":89}]}},{"code":"q_bought","region":{"Known":[{"line":313,"column":41},{"line":313,"column":49}]}},{"code":"q_other","region":{"Known":[{"line":313,"column":51},{"line":313,"column":58}]}},{"code":"liquidity_b","region":{"Known":[{"line":313,"column":60},{"line":313,"column":71}]}},{"code":"amount_in","region":{"Known":[{"line":313,"column":73},{"line":313,"column":82}]}},{"code":"let one = FIXED_ONE;","region":{"Known":[{"line":263,"column":8},{"line":263,"column":28}]}},{"code":"let b = b as u128;","region":{"Known":[{"line":264,"column":8},{"line":264,"column":26}]}},{"code":"amount_in as u128","region":{"Known":[{"line":266,"column":17},{"line":266,"column":34}]}},{"code":"let x = (amount_in as u128) * one / b;","region":{"Known":[{"line":266,"column":8},{"line":266,"column":46}]}},{"code":"let in_range = x <= MAX_EXP_ARG;","region":{"Known":[{"line":267,"column":8},{"line":267,"column":40}]}},{"code":"in_range","region":{"Known":[{"line":268,"column":31},{"line":268,"column":39}]}},{"code":"0","region":{"Known":[{"line":268,"column":53},{"line":268,"column":54}]}},{"code":"x","region":{"Known":[{"line":268,"column":42},{"line":268,"column":43}]}},{"code":"if in_range { x } else { 0 }","region":{"Known":[{"line":268,"column":28},{"line":268,"column":56}]}},{"code":"let one = FIXED_ONE;","region":{"Known":[{"line":220,"column":8},{"line":220,"column":28}]}},{"code":"let r = x / 32;","region":{"Known":[{"line":221,"column":8},{"line":221,"column":23}]}},{"code":"let mut term = one;","region":{"Known":[{"line":223,"column":8},{"line":223,"column":27}]}},{"code":"let mut sum = one;","region":{"Known":[{"line":224,"column":8},{"line":224,"column":26}]}},{"code":"self.0","region":"Unknown"},{"code":"self.1 - 1","region":"Unknown"},{"code":"start","region":"Unknown"},{"code":"end","region":"Unknown"},{"code":"InclusiveRangeIterator { start, end }","region":"Unknown"},{"code":"InclusiveRangeIterator :: new(self.0, self.1 - 1)","region":"Unknown"},{"code":"self.end - self.start + 1","region":"Unknown"},{"code":"0","region":"Unknown"},{"code":"#[arcis_circuit = \"max\"] fn max_circ < T > (a : T, b : T) -> T {}","region":"Unknown"},{"code":"self","region":"Unknown"},{"code":"other","region":"Unknown"},{"code":"max_circ(self, other)","region":"Unknown"},{"code":"(self.end - self.start + 1).max(0)","region":"Unknown"},{"code":"let res = self.start;","region":"Unknown"},{"code":"self.start += 1;","region":"Unknown"},{"code":"res","region":"Unknown"},{"code":"one * i","region":{"Known":[{"line":226,"column":31},{"line":226,"column":38}]}},{"code":"term = term * r / (one * i);","region":{"Known":[{"line":226,"column":12},{"line":226,"column":40}]}},{"code":"sum += term;","region":{"Known":[{"line":227,"column":12},{"line":227,"column":24}]}},{"code":"for i in 1 .. 9u128 { term = term * r / (one * i); sum += term; }","region":{"Known":[{"line":225,"column":8},{"line":228,"column":9}]}},{"code":"sum = sum * sum / one;","region":{"Known":[{"line":230,"column":12},{"line":230,"column":34}]}},{"code":"for _ in 0 .. 5 { sum = sum * sum / one; }","region":{"Known":[{"line":229,"column":8},{"line":231,"column":9}]}},{"code":"sum","region":{"Known":[{"line":232,"column":8},{"line":232,"column":11}]}},{"code":"let e_x = exp_fixed(if in_range { x } else { 0 });","region":{"Known":[{"line":268,"column":8},{"line":268,"column":58}]}},{"code":"let other_ahead = q_other >= q_bought;","region":{"Known":[{"line":273,"column":8},{"line":273,"column":46}]}},{"code":"other_ahead","region":{"Known":[{"line":274,"column":23},{"line":274,"column":34}]}},{"code":"q_bought - q_other","region":{"Known":[{"line":277,"column":14},{"line":277,"column":32}]}},{"code":"(q_bought - q_other) as u128","region":{"Known":[{"line":277,"column":13},{"line":277,"column":41}]}},{"code":"((q_bought - q_other) as u128) * one / b","region":{"Known":[{"line":277,"column":12},{"line":277,"column":52}]}},{"code":"q_other - q_bought","region":{"Known":[{"line":275,"column":14},{"line":275,"column":32}]}},{"code":"(q_other - q_bought) as u128","region":{"Known":[{"line":275,"column":13},{"line":275,"column":41}]}},{"code":"((q_other - q_bought) as u128) * one / b","region":{"Known":[{"line":275,"column":12},{"line":275,"column":52}]}},{"code":"let d_abs = if other_ahead { ((q_other - q_bought) as u128) * one / b } else\n{ ((q_bought - q_other) as u128) * one / b };","region":{"Known":[{"line":274,"column":8},{"line":278,"column":10}]}},{"code":"let d_saturated = d_abs > MAX_EXP_ARG;","region":{"Known":[{"line":279,"column":8},{"line":279,"column":46}]}},{"code":"d_saturated","region":{"Known":[{"line":280,"column":35},{"line":280,"column":46}]}},{"code":"d_abs","region":{"Known":[{"line":280,"column":70},{"line":280,"column":75}]}},{"code":"MAX_EXP_ARG","region":{"Known":[{"line":280,"column":49},{"line":280,"column":60}]}},{"code":"if d_saturated { MAX_EXP_ARG } else { d_abs }","region":{"Known":[{"line":280,"column":32},{"line":280,"column":77}]}},{"code":"let e_d_abs = exp_fixed(if d_saturated { MAX_EXP_ARG } else { d_abs });","region":{"Known":[{"line":280,"column":8},{"line":280,"column":79}]}},{"code":"other_ahead","region":{"Known":[{"line":281,"column":21},{"line":281,"column":32}]}},{"code":"d_saturated","region":{"Known":[{"line":283,"column":18},{"line":283,"column":29}]}},{"code":"one * one / e_d_abs","region":{"Known":[{"line":286,"column":12},{"line":286,"column":31}]}},{"code":"0","region":{"Known":[{"line":284,"column":12},{"line":284,"column":13}]}},{"code":"e_d_abs","region":{"Known":[{"line":282,"column":12},{"line":282,"column":19}]}},{"code":"let e_d = if other_ahead { e_d_abs } else if d_saturated { 0 } else\n{ one * one / e_d_abs };","region":{"Known":[{"line":281,"column":8},{"line":287,"column":10}]}},{"code":"one + e_d","region":{"Known":[{"line":289,"column":27},{"line":289,"column":36}]}},{"code":"let inner = e_x * (one + e_d) / one - e_d;","region":{"Known":[{"line":289,"column":8},{"line":289,"column":50}]}},{"code":"inner","region":{"Known":[{"line":290,"column":35},{"line":290,"column":40}]}},{"code":"let one = FIXED_ONE;","region":{"Known":[{"line":238,"column":8},{"line":238,"column":28}]}},{"code":"y < one","region":{"Known":[{"line":239,"column":23},{"line":239,"column":30}]}},{"code":"y","region":{"Known":[{"line":239,"column":46},{"line":239,"column":47}]}},{"code":"one","region":{"Known":[{"line":239,"column":33},{"line":239,"column":36}]}},{"code":"let mut y = if y < one { one } else { y };","region":{"Known":[{"line":239,"column":8},{"line":239,"column":50}]}},{"code":"let mut halvings = 0u128;","region":{"Known":[{"line":240,"column":8},{"line":240,"column":33}]}},{"code":"let halve = y >= 2 * one;","region":{"Known":[{"line":242,"column":12},{"line":242,"column":37}]}},{"code":"halve","region":{"Known":[{"line":243,"column":19},{"line":243,"column":24}]}},{"code":"y","region":{"Known":[{"line":243,"column":42},{"line":243,"column":43}]}},{"code":"y / 2","region":{"Known":[{"line":243,"column":27},{"line":243,"column":32}]}},{"code":"y = if halve { y / 2 } else { y };","region":{"Known":[{"line":243,"column":12},{"line":243,"column":46}]}},{"code":"halve","region":{"Known":[{"line":244,"column":26},{"line":244,"column":31}]}},{"code":"halvings","region":{"Known":[{"line":244,"column":56},{"line":244,"column":64}]}},{"code":"halvings + 1","region":{"Known":[{"line":244,"column":34},{"line":244,"column":46}]}},{"code":"halvings = if halve { halvings + 1 } else { halvings };","region":{"Known":[{"line":244,"column":12},{"line":244,"column":67}]}},{"code":"for _ in 0 .. 64\n{\n    let halve = y >= 2 * one; y = if halve { y / 2 } else { y }; halvings = if\n    halve { halvings + 1 } else { halvings };\n}","region":{"Known":[{"line":241,"column":8},{"line":245,"column":9}]}},{"code":"y - one","region":{"Known":[{"line":247,"column":17},{"line":247,"column":24}]}},{"code":"y + one","region":{"Known":[{"line":247,"column":35},{"line":247,"column":42}]}},{"code":"let z = (y - one) * one / (y + one);","region":{"Known":[{"line":247,"column":8},{"line":247,"column":44}]}},{"code":"let z2 = z * z / one;","region":{"Known":[{"line":248,"column":8},{"line":248,"column":29}]}},{"code":"let mut term = z;","region":{"Known":[{"line":249,"column":8},{"line":249,"column":25}]}},{"code":"let mut sum = z;","region":{"Known":[{"line":250,"column":8},{"line":250,"column":24}]}},{"code":"3u128","region":{"Known":[{"line":251,"column":18},{"line":251,"column":23}]}},{"code":"5","region":{"Known":[{"line":251,"column":25},{"line":251,"column":26}]}},{"code":"7","region":{"Known":[{"line":251,"column":28},{"line":251,"column":29}]}},{"code":"9","region":{"Known":[{"line":251,"column":31},{"line":251,"column":32}]}},{"code":"11","region":{"Known":[{"line":251,"column":34},{"line":251,"column":36}]}},{"code":"self","region":"Unknown"},{"code":"arr","region":"Unknown"},{"code":"0","region":"Unknown"},{"code":"0","region":"Unknown"},{"code":"Self { arr, left_skip : 0, right_skip : 0 }","region":"Unknown"},{"code":"ArcisArrayIterator :: new(self)","region":"Unknown"},{"code":"self.arr.len() - self.left_skip - self.right_skip","region":"Unknown"},{"code":"self.left_skip","region":"Unknown"},{"code":"let res = self.arr [self.left_skip];","region":"Unknown"},{"code":"self.left_skip += 1;","region":"Unknown"},{"code":"res","region":"Unknown"},{"code":"term = term * z2 / one;","region":{"Known":[{"line":252,"column":12},{"line":252,"column":35}]}},{"code":"sum += term / k;","region":{"Known":[{"line":253,"column":12},{"line":253,"column":28}]}},{"code":"for k in [3u128, 5, 7, 9, 11] { term = term * z2 / one; sum += term / k; }","region":{"Known":[{"line":251,"column":8},{"line":254,"column":9}]}},{"code":"halvings * LN_2 + 2 * sum","region":{"Known":[{"line":255,"column":8},{"line":255,"column":33}]}},{"code":"b * ln_fixed(inner) / one","region":{"Known":[{"line":290,"column":22},{"line":290,"column":47}]}},{"code":"let shares = (b * ln_fixed(inner) / one) as u64;","region":{"Known":[{"line":290,"column":8},{"line":290,"column":56}]}},{"code":"in_range","region":{"Known":[{"line":291,"column":11},{"line":291,"column":19}]}},{"code":"0","region":{"Known":[{"line":291,"column":38},{"line":291,"column":39}]}},{"code":"shares","region":{"Known":[{"line":291,"column":22},{"line":291,"column":28}]}},{"code":"if in_range { shares } else { 0 }","region":{"Known":[{"line":291,"column":8},{"line":291,"column":41}]}},{"code":"let shares_out = lmsr_shares_out(q_bought, q_other, liquidity_b, amount_in);","region":{"Known":[{"line":313,"column":8},{"line":313,"column":84}]}},{"code":"shares_out > 0","region":{"Known":[{"line":315,"column":35},{"line":315,"column":49}]}},{"code":"1","region":{"Known":[{"line":315,"column":72},{"line":315,"column":73}]}},{"code":"shares_out","region":{"Known":[{"line":315,"column":52},{"line":315,"column":62}]}},{"code":"let price_denominator = if shares_out > 0 { shares_out } else { 1 };","region":{"Known":[{"line":315,"column":8},{"line":315,"column":76}]}},{"code":"user_amount as u128","region":{"Known":[{"line":316,"column":32},{"line":316,"column":51}]}},{"code":"PRICE_SCALE as u128","region":{"Known":[{"line":316,"column":56},{"line":316,"column":75}]}},{"code":"price_denominator as u128","region":{"Known":[{"line":317,"column":15},{"line":317,"column":40}]}},{"code":"(user_amount as u128) * (PRICE_SCALE as u128) / (price_denominator as u128)","region":{"Known":[{"line":316,"column":31},{"line":317,"column":41}]}},{"code":"let effective_price =\n((user_amount as u128) * (PRICE_SCALE as u128) / (price_denominator as u128))\nas u64;","region":{"Known":[{"line":316,"column":8},{"line":317,"column":50}]}},{"code":"let filled = shares_out > 0 && effective_price <= input.max_price;","region":{"Known":[{"line":319,"column":8},{"line":319,"column":74}]}},{"code":"! filled","region":{"Known":[{"line":322,"column":39},{"line":322,"column":46}]}},{"code":"input.side","region":{"Known":[{"line":324,"column":18},{"line":324,"column":28}]}},{"code":"q_yes","region":{"Known":[{"line":327,"column":13},{"line":327,"column":18}]}},{"code":"q_no + shares_out","region":{"Known":[{"line":327,"column":20},{"line":327,"column":37}]}},{"code":"(q_yes, q_no + shares_out)","region":{"Known":[{"line":327,"column":12},{"line":327,"column":38}]}},{"code":"q_yes + shares_out","region":{"Known":[{"line":325,"column":13},{"line":325,"column":31}]}},{"code":"q_no","region":{"Known":[{"line":325,"column":33},{"line":325,"column":37}]}},{"code":"(q_yes + shares_out, q_no)","region":{"Known":[{"line":325,"column":12},{"line":325,"column":38}]}},{"code":"q_yes","region":{"Known":[{"line":323,"column":13},{"line":323,"column":18}]}},{"code":"q_no","region":{"Known":[{"line":323,"column":20},{"line":323,"column":24}]}},{"code":"(q_yes, q_no)","region":{"Known":[{"line":323,"column":12},{"line":323,"column":25}]}},{"code":"let (new_q_yes, new_q_no) = if ! filled { (q_yes, q_no) } else if input.side\n{ (q_yes + shares_out, q_no) } else { (q_yes, q_no + shares_out) };","region":{"Known":[{"line":322,"column":8},{"line":328,"column":10}]}},{"code":"filled","region":{"Known":[{"line":331,"column":27},{"line":331,"column":33}]}},{"code":"0","region":{"Known":[{"line":331,"column":56},{"line":331,"column":57}]}},{"code":"shares_out","region":{"Known":[{"line":331,"column":36},{"line":331,"column":46}]}},{"code":"if filled { shares_out } else { 0 }","region":{"Known":[{"line":331,"column":24},{"line":331,"column":59}]}},{"code":"effective_price","region":{"Known":[{"line":332,"column":12},{"line":332,"column":27}]}},{"code":"filled","region":{"Known":[{"line":333,"column":20},{"line":333,"column":26}]}},{"code":"0","region":{"Known":[{"line":333,"column":42},{"line":333,"column":43}]}},{"code":"fee","region":{"Known":[{"line":333,"column":29},{"line":333,"column":32}]}},{"code":"if filled { fee } else { 0 }","region":{"Known":[{"line":333,"column":17},{"line":333,"column":45}]}},{"code":"filled","region":{"Known":[{"line":334,"column":12},{"line":334,"column":18}]}},{"code":"let receipt = TradeReceipt\n{\n    shares_out : if filled { shares_out } else { 0 }, effective_price, fee :\n    if filled { fee } else { 0 }, filled,\n};","region":{"Known":[{"line":330,"column":8},{"line":335,"column":10}]}},{"code":"new_q_yes","region":{"Known":[{"line":338,"column":26},{"line":338,"column":35}]}},{"code":"new_q_no","region":{"Known":[{"line":339,"column":25},{"line":339,"column":33}]}},{"code":"let new_state = CfmmState\n{ yes_reserves : new_q_yes, no_reserves : new_q_no, };","region":{"Known":[{"line":337,"column":8},{"line":340,"column":10}]}},{"code":"position_ctxt.to_arcis()","region":{"Known":[{"line":343,"column":12},{"line":343,"column":36}]}},{"code":"input.side","region":{"Known":[{"line":344,"column":12},{"line":344,"column":22}]}},{"code":"shares_out","region":{"Known":[{"line":345,"column":12},{"line":345,"column":22}]}},{"code":"user_amount","region":{"Known":[{"line":346,"column":12},{"line":346,"column":23}]}},{"code":"filled","region":{"Known":[{"line":347,"column":12},{"line":347,"column":18}]}},{"code":"let new_position =\napply_fill(position_ctxt.to_arcis(), input.side, shares_out, user_amount,\nfilled,);","region":{"Known":[{"line":342,"column":8},{"line":348,"column":10}]}},{"code":"filled","region":{"Known":[{"line":349,"column":32},{"line":349,"column":38}]}},{"code":"input.side","region":{"Known":[{"line":349,"column":40},{"line":349,"column":50}]}},{"code":"shares_out","region":{"Known":[{"line":349,"column":52},{"line":349,"column":62}]}},{"code":"user_amount","region":{"Known":[{"line":349,"column":64},{"line":349,"column":75}]}},{"code":"escrow","region":{"Known":[{"line":349,"column":77},{"line":349,"column":83}]}},{"code":"let settlement = settle(filled, input.side, shares_out, user_amount, escrow);","region":{"Known":[{"line":349,"column":8},{"line":349,"column":85}]}},{"code":"receipt","region":{"Known":[{"line":352,"column":40},{"line":352,"column":47}]}},{"code":"input_ctxt.owner.from_arcis(receipt)","region":{"Known":[{"line":352,"column":12},{"line":352,"column":48}]}},{"code":"new_state","region":{"Known":[{"line":353,"column":40},{"line":353,"column":49}]}},{"code":"state_ctxt.owner.from_arcis(new_state)","region":{"Known":[{"line":353,"column":12},{"line":353,"column":50}]}},{"code":"new_position","region":{"Known":[{"line":354,"column":43},{"line":354,"column":55}]}},{"code":"position_ctxt.owner.from_arcis(new_position)","region":{"Known":[{"line":354,"column":12},{"line":354,"column":56}]}},{"code":"settlement.0.reveal()","region":{"Known":[{"line":355,"column":12},{"line":355,"column":33}]}},{"code":"settlement.1.reveal()","region":{"Known":[{"line":356,"column":12},{"line":356,"column":33}]}},{"code":"settlement.2.reveal()","region":{"Known":[{"line":357,"column":12},{"line":357,"column":33}]}},{"code":"settlement.3.reveal()","region":{"Known":[{"line":358,"column":12},{"line":358,"column":33}]}},{"code":"(input_ctxt.owner.from_arcis(receipt), state_ctxt.owner.from_arcis(new_state),\nposition_ctxt.owner.from_arcis(new_position), settlement.0.reveal(),\nsettlement.1.reveal(), settlement.2.reveal(), settlement.3.reveal(),)","region":{"Known":[{"line":351,"column":8},{"line":359,"column":9}]}},{"code":"let [order_0, order_1, order_2, order_3] = order_ctxts;","region":{"Known":[{"line":459,"column":8},{"line":459,"column":63}]}},{"code":"order_0.to_arcis()","region":{"Known":[{"line":461,"column":12},{"line":461,"column":30}]}},{"code":"order_1.to_arcis()","region":{"Known":[{"line":462,"column":12},{"line":462,"column":30}]}},{"code":"order_2.to_arcis()","region":{"Known":[{"line":463,"column":12},{"line":463,"column":30}]}},{"code":"order_3.to_arcis()","region":{"Known":[{"line":464,"column":12},{"line":464,"column":30}]}},{"code":"let orders =\n[order_0.to_arcis(), order_1.to_arcis(), order_2.to_arcis(),\norder_3.to_arcis(),];","region":{"Known":[{"line":460,"column":8},{"line":465,"column":10}]}},{"code":"let state = state_ctxt.to_arcis();","region":{"Known":[{"line":466,"column":8},{"line":466,"column":42}]}},{"code":"false","region":{"Known":[{"line":469,"column":26},{"line":469,"column":31}]}},{"code":"let mut active = [false; MAX_BATCH_ORDERS];","region":{"Known":[{"line":469,"column":8},{"line":469,"column":51}]}},{"code":"0u64","region":{"Known":[{"line":470,"column":27},{"line":470,"column":31}]}},{"code":"let mut amounts = [0u64; MAX_BATCH_ORDERS];","region":{"Known":[{"line":470,"column":8},{"line":470,"column":51}]}},{"code":"i","region":{"Known":[{"line":472,"column":19},{"line":472,"column":20}]}},{"code":"i as u8","region":{"Known":[{"line":472,"column":25},{"line":472,"column":32}]}},{"code":"active [i] = (i as u8) < order_count;","region":{"Known":[{"line":472,"column":12},{"line":472,"column":48}]}},{"code":"i","region":{"Known":[{"line":473,"column":35},{"line":473,"column":36}]}},{"code":"i","region":{"Known":[{"line":473,"column":55},{"line":473,"column":56}]}},{"code":"orders [i].amount > escrows [i]","region":{"Known":[{"line":473,"column":28},{"line":473,"column":57}]}},{"code":"i","region":{"Known":[{"line":473,"column":87},{"line":473,"column":88}]}},{"code":"orders [i].amount","region":{"Known":[{"line":473,"column":80},{"line":473,"column":96}]}},{"code":"i","region":{"Known":[{"line":473,"column":68},{"line":473,"column":69}]}},{"code":"escrows [i]","region":{"Known":[{"line":473,"column":60},{"line":473,"column":70}]}},{"code":"let amount = if orders [i].amount > escrows [i] { escrows [i] } else\n{ orders [i].amount };","region":{"Known":[{"line":473,"column":12},{"line":473,"column":99}]}},{"code":"i","region":{"Known":[{"line":474,"column":20},{"line":474,"column":21}]}},{"code":"i","region":{"Known":[{"line":474,"column":35},{"line":474,"column":36}]}},{"code":"active [i]","region":{"Known":[{"line":474,"column":28},{"line":474,"column":37}]}},{"code":"0","region":{"Known":[{"line":474,"column":56},{"line":474,"column":57}]}},{"code":"amount","region":{"Known":[{"line":474,"column":40},{"line":474,"column":46}]}},{"code":"amounts [i] = if active [i] { amount } else { 0 };","region":{"Known":[{"line":474,"column":12},{"line":474,"column":60}]}},{"code":"for i in 0 .. MAX_BATCH_ORDERS\n{\n    active [i] = (i as u8) < order_count; let amount = if orders [i].amount >\n    escrows [i] { escrows [i] } else { orders [i].amount }; amounts [i] = if\n    active [i] { amount } else { 0 };\n}","region":{"Known":[{"line":471,"column":8},{"line":475,"column":9}]}},{"code":"let mut best_price = 0u64;","region":{"Known":[{"line":480,"column":8},{"line":480,"column":34}]}},{"code":"let mut best_matched = 0u128;","region":{"Known":[{"line":481,"column":8},{"line":481,"column":37}]}},{"code":"j","region":{"Known":[{"line":483,"column":32},{"line":483,"column":33}]}},{"code":"let order = & orders [j];","region":{"Known":[{"line":483,"column":12},{"line":483,"column":35}]}},{"code":"order.limit_price > PRICE_SCALE","region":{"Known":[{"line":484,"column":30},{"line":484,"column":61}]}},{"code":"order.limit_price","region":{"Known":[{"line":484,"column":85},{"line":484,"column":102}]}},{"code":"PRICE_SCALE","region":{"Known":[{"line":484,"column":64},{"line":484,"column":75}]}},{"code":"let no_limit = if order.limit_price > PRICE_SCALE { PRICE_SCALE } else\n{ order.limit_price };","region":{"Known":[{"line":484,"column":12},{"line":484,"column":105}]}},{"code":"order.side","region":{"Known":[{"line":485,"column":31},{"line":485,"column":41}]}},{"code":"PRICE_SCALE - no_limit","region":{"Known":[{"line":485,"column":71},{"line":485,"column":93}]}},{"code":"order.limit_price","region":{"Known":[{"line":485,"column":44},{"line":485,"column":61}]}},{"code":"let candidate = if order.side { order.limit_price } else\n{ PRICE_SCALE - no_limit };","region":{"Known":[{"line":485,"column":12},{"line":485,"column":96}]}},{"code":"candidate > PRICE_SCALE","region":{"Known":[{"line":486,"column":31},{"line":486,"column":54}]}},{"code":"candidate","region":{"Known":[{"line":486,"column":78},{"line":486,"column":87}]}},{"code":"PRICE_SCALE","region":{"Known":[{"line":486,"column":57},{"line":486,"column":68}]}},{"code":"let candidate = if candidate > PRICE_SCALE { PRICE_SCALE } else { candidate };","region":{"Known":[{"line":486,"column":12},{"line":486,"column":90}]}},{"code":"j","region":{"Known":[{"line":487,"column":32},{"line":487,"column":33}]}},{"code":"let valid = amounts [j] > 0 && candidate > 0 && candidate < PRICE_SCALE;","region":{"Known":[{"line":487,"column":12},{"line":487,"column":83}]}},{"code":"& orders","region":{"Known":[{"line":489,"column":70},{"line":489,"column":77}]}},{"code":"& amounts","region":{"Known":[{"line":489,"column":79},{"line":489,"column":87}]}},{"code":"candidate","region":{"Known":[{"line":489,"column":89},{"line":489,"column":98}]}},{"code":"let mut yes_collateral = 0u128;","region":{"Known":[{"line":410,"column":8},{"line":410,"column":39}]}},{"code":"let mut no_collateral = 0u128;","region":{"Known":[{"line":411,"column":8},{"line":411,"column":38}]}},{"code":"i","region":{"Known":[{"line":413,"column":32},{"line":413,"column":33}]}},{"code":"let order = & orders [i];","region":{"Known":[{"line":413,"column":12},{"line":413,"column":35}]}},{"code":"let yes_ok = order.side && order.limit_price >= yes_price;","region":{"Known":[{"line":414,"column":12},{"line":414,"column":70}]}},{"code":"let no_ok = ! order.side && order.limit_price >= PRICE_SCALE - yes_price;","region":{"Known":[{"line":415,"column":12},{"line":415,"column":84}]}},{"code":"yes_ok","region":{"Known":[{"line":416,"column":33},{"line":416,"column":39}]}},{"code":"0","region":{"Known":[{"line":416,"column":70},{"line":416,"column":71}]}},{"code":"i","region":{"Known":[{"line":416,"column":50},{"line":416,"column":51}]}},{"code":"amounts [i] as u128","region":{"Known":[{"line":416,"column":42},{"line":416,"column":60}]}},{"code":"yes_collateral += if yes_ok { amounts [i] as u128 } else { 0 };","region":{"Known":[{"line":416,"column":12},{"line":416,"column":74}]}},{"code":"no_ok","region":{"Known":[{"line":417,"column":32},{"line":417,"column":37}]}},{"code":"0","region":{"Known":[{"line":417,"column":68},{"line":417,"column":69}]}},{"code":"i","region":{"Known":[{"line":417,"column":48},{"line":417,"column":49}]}},{"code":"amounts [i] as u128","region":{"Known":[{"line":417,"column":40},{"line":417,"column":58}]}},{"code":"no_collateral += if no_ok { amounts [i] as u128 } else { 0 };","region":{"Known":[{"line":417,"column":12},{"line":417,"column":72}]}},{"code":"for i in 0 .. MAX_BATCH_ORDERS\n{\n    let order = & orders [i]; let yes_ok = order.side && order.limit_price >=\n    yes_price; let no_ok = ! order.side && order.limit_price >= PRICE_SCALE -\n    yes_price; yes_collateral += if yes_ok { amounts [i] as u128 } else { 0 };\n    no_collateral += if no_ok { amounts [i] as u128 } else { 0 };\n}","region":{"Known":[{"line":412,"column":8},{"line":418,"column":9}]}},{"code":"yes_collateral","region":{"Known":[{"line":419,"column":9},{"line":419,"column":23}]}},{"code":"no_collateral","region":{"Known":[{"line":419,"column":25},{"line":419,"column":38}]}},{"code":"(yes_collateral, no_collateral)","region":{"Known":[{"line":419,"column":8},{"line":419,"column":39}]}},{"code":"let (yes_collateral, no_collateral) =\neligible_collateral(& orders, & amounts, candidate);","region":{"Known":[{"line":489,"column":12},{"line":489,"column":100}]}},{"code":"yes_collateral","region":{"Known":[{"line":490,"column":39},{"line":490,"column":53}]}},{"code":"candidate","region":{"Known":[{"line":490,"column":55},{"line":490,"column":64}]}},{"code":"price > 0","region":{"Known":[{"line":424,"column":29},{"line":424,"column":38}]}},{"code":"1","region":{"Known":[{"line":424,"column":56},{"line":424,"column":57}]}},{"code":"price","region":{"Known":[{"line":424,"column":41},{"line":424,"column":46}]}},{"code":"let denominator = if price > 0 { price } else { 1 };","region":{"Known":[{"line":424,"column":8},{"line":424,"column":60}]}},{"code":"PRICE_SCALE as u128","region":{"Known":[{"line":425,"column":22},{"line":425,"column":41}]}},{"code":"denominator as u128","region":{"Known":[{"line":425,"column":46},{"line":425,"column":65}]}},{"code":"collateral * (PRICE_SCALE as u128) / (denominator as u128)","region":{"Known":[{"line":425,"column":8},{"line":425,"column":66}]}},{"code":"let yes_shares = shares_at(yes_collateral, candidate);","region":{"Known":[{"line":490,"column":12},{"line":490,"column":66}]}},{"code":"no_collateral","region":{"Known":[{"line":491,"column":38},{"line":491,"column":51}]}},{"code":"PRICE_SCALE - candidate","region":{"Known":[{"line":491,"column":53},{"line":491,"column":76}]}},{"code":"let no_shares = shares_at(no_collateral, PRICE_SCALE - candidate);","region":{"Known":[{"line":491,"column":12},{"line":491,"column":78}]}},{"code":"yes_shares < no_shares","region":{"Known":[{"line":492,"column":29},{"line":492,"column":51}]}},{"code":"no_shares","region":{"Known":[{"line":492,"column":74},{"line":492,"column":83}]}},{"code":"yes_shares","region":{"Known":[{"line":492,"column":54},{"line":492,"column":64}]}},{"code":"let matched = if yes_shares < no_shares { yes_shares } else { no_shares };","region":{"Known":[{"line":492,"column":12},{"line":492,"column":86}]}},{"code":"let better = valid && matched > best_matched;","region":{"Known":[{"line":494,"column":12},{"line":494,"column":57}]}},{"code":"better","region":{"Known":[{"line":495,"column":28},{"line":495,"column":34}]}},{"code":"best_price","region":{"Known":[{"line":495,"column":56},{"line":495,"column":66}]}},{"code":"candidate","region":{"Known":[{"line":495,"column":37},{"line":495,"column":46}]}},{"code":"best_price = if better { candidate } else { best_price };","region":{"Known":[{"line":495,"column":12},{"line":495,"column":69}]}},{"code":"better","region":{"Known":[{"line":496,"column":30},{"line":496,"column":36}]}},{"code":"best_matched","region":{"Known":[{"line":496,"column":56},{"line":496,"column":68}]}},{"code":"matched","region":{"Known":[{"line":496,"column":39},{"line":496,"column":46}]}},{"code":"best_matched = if better { matched } else { best_matched };","region":{"Known":[{"line":496,"column":12},{"line":496,"column":71}]}},{"code":"for j in 0 .. MAX_BATCH_ORDERS\n{\n    let order = & orders [j]; let no_limit = if order.limit_price >\n    PRICE_SCALE { PRICE_SCALE } else { order.limit_price }; let candidate = if\n    order.side { order.limit_price } else { PRICE_SCALE - no_limit }; let\n    candidate = if candidate > PRICE_SCALE { PRICE_SCALE } else { candidate };\n    let valid = amounts [j] > 0 && candidate > 0 && candidate < PRICE_SCALE;\n    let (yes_collateral, no_collateral) =\n    eligible_collateral(& orders, & amounts, candidate); let yes_shares =\n    shares_at(yes_collateral, candidate); let no_shares =\n    shares_at(no_collateral, PRICE_SCALE - candidate); let matched = if\n    yes_shares < no_shares { yes_shares } else { no_shares }; let better =\n    valid && matched > best_matched; best_price = if better { candidate } else\n    { best_price }; best_matched = if better { matched } else\n    { best_matched };\n}","region":{"Known":[{"line":482,"column":8},{"line":497,"column":9}]}},{"code":"state.yes_reserves as u128","region":{"Known":[{"line":500,"column":26},{"line":500,"column":52}]}},{"code":"state.no_reserves as u128","region":{"Known":[{"line":500,"column":57},{"line":500,"column":82}]}},{"code":"let pool_total = (state.yes_reserves as u128) + (state.no_reserves as u128);","region":{"Known":[{"line":500,"column":8},{"line":500,"column":84}]}},{"code":"pool_total > 0","region":{"Known":[{"line":501,"column":34},{"line":501,"column":48}]}},{"code":"1","region":{"Known":[{"line":501,"column":71},{"line":501,"column":72}]}},{"code":"pool_total","region":{"Known":[{"line":501,"column":51},{"line":501,"column":61}]}},{"code":"let pool_denominator = if pool_total > 0 { pool_total } else { 1 };","region":{"Known":[{"line":501,"column":8},{"line":501,"column":75}]}},{"code":"pool_total > 0","region":{"Known":[{"line":502,"column":28},{"line":502,"column":42}]}},{"code":"PRICE_SCALE / 2","region":{"Known":[{"line":505,"column":12},{"line":505,"column":27}]}},{"code":"state.no_reserves as u128","region":{"Known":[{"line":503,"column":14},{"line":503,"column":39}]}},{"code":"PRICE_SCALE as u128","region":{"Known":[{"line":503,"column":44},{"line":503,"column":63}]}},{"code":"(state.no_reserves as u128) * (PRICE_SCALE as u128) / pool_denominator","region":{"Known":[{"line":503,"column":13},{"line":503,"column":83}]}},{"code":"((state.no_reserves as u128) * (PRICE_SCALE as u128) / pool_denominator) as\nu64","region":{"Known":[{"line":503,"column":12},{"line":503,"column":91}]}},{"code":"let pool_price = if pool_total > 0\n{\n    ((state.no_reserves as u128) * (PRICE_SCALE as u128) / pool_denominator)\n    as u64\n} else { PRICE_SCALE / 2 };","region":{"Known":[{"line":502,"column":8},{"line":506,"column":10}]}},{"code":"best_matched > 0","region":{"Known":[{"line":507,"column":32},{"line":507,"column":48}]}},{"code":"pool_price","region":{"Known":[{"line":507,"column":71},{"line":507,"column":81}]}},{"code":"best_price","region":{"Known":[{"line":507,"column":51},{"line":507,"column":61}]}},{"code":"let clearing_price = if best_matched > 0 { best_price } else { pool_price };","region":{"Known":[{"line":507,"column":8},{"line":507,"column":84}]}},{"code":"let no_price = PRICE_SCALE - clearing_price;","region":{"Known":[{"line":508,"column":8},{"line":508,"column":52}]}},{"code":"& orders","region":{"Known":[{"line":510,"column":66},{"line":510,"column":73}]}},{"code":"& amounts","region":{"Known":[{"line":510,"column":75},{"line":510,"column":83}]}},{"code":"clearing_price","region":{"Known":[{"line":510,"column":85},{"line":510,"column":99}]}},{"code":"let (yes_collateral, no_collateral) =\neligible_collateral(& orders, & amounts, clearing_price);","region":{"Known":[{"line":510,"column":8},{"line":510,"column":101}]}},{"code":"yes_collateral","region":{"Known":[{"line":511,"column":35},{"line":511,"column":49}]}},{"code":"clearing_price","region":{"Known":[{"line":511,"column":51},{"line":511,"column":65}]}},{"code":"let yes_shares = shares_at(yes_collateral, clearing_price);","region":{"Known":[{"line":511,"column":8},{"line":511,"column":67}]}},{"code":"no_collateral","region":{"Known":[{"line":512,"column":34},{"line":512,"column":47}]}},{"code":"no_price","region":{"Known":[{"line":512,"column":49},{"line":512,"column":57}]}},{"code":"let no_shares = shares_at(no_collateral, no_price);","region":{"Known":[{"line":512,"column":8},{"line":512,"column":59}]}},{"code":"let long_is_yes = yes_shares > no_shares;","region":{"Known":[{"line":513,"column":8},{"line":513,"column":49}]}},{"code":"long_is_yes","region":{"Known":[{"line":514,"column":25},{"line":514,"column":36}]}},{"code":"yes_shares","region":{"Known":[{"line":514,"column":58},{"line":514,"column":68}]}},{"code":"no_shares","region":{"Known":[{"line":514,"column":39},{"line":514,"column":48}]}},{"code":"let matched = if long_is_yes { no_shares } else { yes_shares };","region":{"Known":[{"line":514,"column":8},{"line":514,"column":71}]}},{"code":"long_is_yes","region":{"Known":[{"line":518,"column":47},{"line":518,"column":58}]}},{"code":"no_collateral","region":{"Known":[{"line":521,"column":13},{"line":521,"column":26}]}},{"code":"no_price","region":{"Known":[{"line":521,"column":28},{"line":521,"column":36}]}},{"code":"(no_collateral, no_price)","region":{"Known":[{"line":521,"column":12},{"line":521,"column":37}]}},{"code":"yes_collateral","region":{"Known":[{"line":519,"column":13},{"line":519,"column":27}]}},{"code":"clearing_price","region":{"Known":[{"line":519,"column":29},{"line":519,"column":43}]}},{"code":"(yes_collateral, clearing_price)","region":{"Known":[{"line":519,"column":12},{"line":519,"column":44}]}},{"code":"let (long_collateral, long_price) = if long_is_yes\n{ (yes_collateral, clearing_price) } else { (no_collateral, no_price) };","region":{"Known":[{"line":518,"column":8},{"line":522,"column":10}]}},{"code":"long_price as u128","region":{"Known":[{"line":524,"column":53},{"line":524,"column":71}]}},{"code":"matched * (long_price as u128)","region":{"Known":[{"line":524,"column":42},{"line":524,"column":72}]}},{"code":"PRICE_SCALE as u128","region":{"Known":[{"line":524,"column":74},{"line":524,"column":93}]}},{"code":"n + d - 1","region":{"Known":[{"line":432,"column":9},{"line":432,"column":18}]}},{"code":"(n + d - 1) / d","region":{"Known":[{"line":432,"column":8},{"line":432,"column":23}]}},{"code":"let matched_collateral =\ndiv_ceil(matched * (long_price as u128), PRICE_SCALE as u128);","region":{"Known":[{"line":524,"column":8},{"line":524,"column":95}]}},{"code":"let residual = long_collateral - matched_collateral;","region":{"Known":[{"line":525,"column":8},{"line":525,"column":60}]}},{"code":"long_is_yes","region":{"Known":[{"line":527,"column":49},{"line":527,"column":60}]}},{"code":"state.no_reserves as u128","region":{"Known":[{"line":530,"column":13},{"line":530,"column":38}]}},{"code":"state.yes_reserves as u128","region":{"Known":[{"line":530,"column":40},{"line":530,"column":66}]}},{"code":"(state.no_reserves as u128, state.yes_reserves as u128)","region":{"Known":[{"line":530,"column":12},{"line":530,"column":67}]}},{"code":"state.yes_reserves as u128","region":{"Known":[{"line":528,"column":13},{"line":528,"column":39}]}},{"code":"state.no_reserves as u128","region":{"Known":[{"line":528,"column":41},{"line":528,"column":66}]}},{"code":"(state.yes_reserves as u128, state.no_reserves as u128)","region":{"Known":[{"line":528,"column":12},{"line":528,"column":67}]}},{"code":"let (bought_reserve, other_reserve) = if long_is_yes\n{ (state.yes_reserves as u128, state.no_reserves as u128) } else\n{ (state.no_reserves as u128, state.yes_reserves as u128) };","region":{"Known":[{"line":527,"column":8},{"line":531,"column":10}]}},{"code":"let k = bought_reserve * other_reserve;","region":{"Known":[{"line":532,"column":8},{"line":532,"column":47}]}},{"code":"let new_other = other_reserve + residual;","region":{"Known":[{"line":533,"column":8},{"line":533,"column":49}]}},{"code":"new_other > 0","region":{"Known":[{"line":534,"column":39},{"line":534,"column":52}]}},{"code":"1","region":{"Known":[{"line":534,"column":74},{"line":534,"column":75}]}},{"code":"new_other","region":{"Known":[{"line":534,"column":55},{"line":534,"column":64}]}},{"code":"let new_other_denominator = if new_other > 0 { new_other } else { 1 };","region":{"Known":[{"line":534,"column":8},{"line":534,"column":78}]}},{"code":"let new_bought = k / new_other_denominator;","region":{"Known":[{"line":535,"column":8},{"line":535,"column":51}]}},{"code":"let pool_shares = bought_reserve + residual - new_bought;","region":{"Known":[{"line":536,"column":8},{"line":536,"column":65}]}},{"code":"long_price as u128","region":{"Known":[{"line":544,"column":30},{"line":544,"column":48}]}},{"code":"PRICE_SCALE as u128","region":{"Known":[{"line":544,"column":65},{"line":544,"column":84}]}},{"code":"let routed = route_to_pool && residual > 0 && pool_shares > 0 && new_other <=\nU64_MAX && pool_shares * (long_price as u128) >= residual *\n(PRICE_SCALE as u128);","region":{"Known":[{"line":540,"column":8},{"line":544,"column":86}]}},{"code":"routed","region":{"Known":[{"line":546,"column":29},{"line":546,"column":35}]}},{"code":"matched","region":{"Known":[{"line":546,"column":69},{"line":546,"column":76}]}},{"code":"matched + pool_shares","region":{"Known":[{"line":546,"column":38},{"line":546,"column":59}]}},{"code":"let long_shares = if routed { matched + pool_shares } else { matched };","region":{"Known":[{"line":546,"column":8},{"line":546,"column":79}]}},{"code":"routed","region":{"Known":[{"line":547,"column":28},{"line":547,"column":34}]}},{"code":"matched_collateral","region":{"Known":[{"line":547,"column":62},{"line":547,"column":80}]}},{"code":"long_collateral","region":{"Known":[{"line":547,"column":37},{"line":547,"column":52}]}},{"code":"let long_spent = if routed { long_collateral } else { matched_collateral };","region":{"Known":[{"line":547,"column":8},{"line":547,"column":83}]}},{"code":"! routed","region":{"Known":[{"line":549,"column":53},{"line":549,"column":60}]}},{"code":"long_is_yes","region":{"Known":[{"line":551,"column":18},{"line":551,"column":29}]}},{"code":"new_other as u64","region":{"Known":[{"line":554,"column":13},{"line":554,"column":29}]}},{"code":"new_bought as u64","region":{"Known":[{"line":554,"column":31},{"line":554,"column":48}]}},{"code":"(new_other as u64, new_bought as u64)","region":{"Known":[{"line":554,"column":12},{"line":554,"column":49}]}},{"code":"new_bought as u64","region":{"Known":[{"line":552,"column":13},{"line":552,"column":30}]}},{"code":"new_other as u64","region":{"Known":[{"line":552,"column":32},{"line":552,"column":48}]}},{"code":"(new_bought as u64, new_other as u64)","region":{"Known":[{"line":552,"column":12},{"line":552,"column":49}]}},{"code":"state.yes_reserves","region":{"Known":[{"line":550,"column":13},{"line":550,"column":31}]}},{"code":"state.no_reserves","region":{"Known":[{"line":550,"column":33},{"line":550,"column":50}]}},{"code":"(state.yes_reserves, state.no_reserves)","region":{"Known":[{"line":550,"column":12},{"line":550,"column":51}]}},{"code":"let (new_yes_reserves, new_no_reserves) = if ! routed\n{ (state.yes_reserves, state.no_reserves) } else if long_is_yes\n{ (new_bought as u64, new_other as u64) } else\n{ (new_other as u64, new_bought as u64) };","region":{"Known":[{"line":549,"column":8},{"line":555,"column":10}]}},{"code":"long_collateral > 0","region":{"Known":[{"line":560,"column":34},{"line":560,"column":53}]}},{"code":"1","region":{"Known":[{"line":560,"column":81},{"line":560,"column":82}]}},{"code":"long_collateral","region":{"Known":[{"line":560,"column":56},{"line":560,"column":71}]}},{"code":"let long_denominator = if long_collateral > 0 { long_collateral } else { 1 };","region":{"Known":[{"line":560,"column":8},{"line":560,"column":85}]}},{"code":"false","region":{"Known":[{"line":561,"column":25},{"line":561,"column":30}]}},{"code":"let mut sides = [false; MAX_BATCH_ORDERS];","region":{"Known":[{"line":561,"column":8},{"line":561,"column":50}]}},{"code":"0u64","region":{"Known":[{"line":562,"column":26},{"line":562,"column":30}]}},{"code":"let mut shares = [0u64; MAX_BATCH_ORDERS];","region":{"Known":[{"line":562,"column":8},{"line":562,"column":50}]}},{"code":"0u64","region":{"Known":[{"line":563,"column":25},{"line":563,"column":29}]}},{"code":"let mut spent = [0u64; MAX_BATCH_ORDERS];","region":{"Known":[{"line":563,"column":8},{"line":563,"column":49}]}},{"code":"0u64","region":{"Known":[{"line":564,"column":27},{"line":564,"column":31}]}},{"code":"let mut refunds = [0u64; MAX_BATCH_ORDERS];","region":{"Known":[{"line":564,"column":8},{"line":564,"column":51}]}},{"code":"i","region":{"Known":[{"line":566,"column":32},{"line":566,"column":33}]}},{"code":"let order = & orders [i];","region":{"Known":[{"line":566,"column":12},{"line":566,"column":35}]}},{"code":"order.side","region":{"Known":[{"line":567,"column":30},{"line":567,"column":40}]}},{"code":"order.limit_price >= no_price","region":{"Known":[{"line":570,"column":16},{"line":570,"column":45}]}},{"code":"order.limit_price >= clearing_price","region":{"Known":[{"line":568,"column":16},{"line":568,"column":51}]}},{"code":"let eligible = if order.side { order.limit_price >= clearing_price } else\n{ order.limit_price >= no_price };","region":{"Known":[{"line":567,"column":12},{"line":571,"column":14}]}},{"code":"eligible","region":{"Known":[{"line":572,"column":28},{"line":572,"column":36}]}},{"code":"0","region":{"Known":[{"line":572,"column":59},{"line":572,"column":60}]}},{"code":"i","region":{"Known":[{"line":572,"column":47},{"line":572,"column":48}]}},{"code":"amounts [i]","region":{"Known":[{"line":572,"column":39},{"line":572,"column":49}]}},{"code":"let amount = if eligible { amounts [i] } else { 0 };","region":{"Known":[{"line":572,"column":12},{"line":572,"column":63}]}},{"code":"let on_long_side = order.side == long_is_yes;","region":{"Known":[{"line":573,"column":12},{"line":573,"column":57}]}},{"code":"amount as u128","region":{"Known":[{"line":575,"column":36},{"line":575,"column":50}]}},{"code":"let long_fill_shares = (amount as u128) * long_shares / long_denominator;","region":{"Known":[{"line":575,"column":12},{"line":575,"column":85}]}},{"code":"amount as u128","region":{"Known":[{"line":576,"column":44},{"line":576,"column":58}]}},{"code":"(amount as u128) * long_spent","region":{"Known":[{"line":576,"column":43},{"line":576,"column":72}]}},{"code":"long_denominator","region":{"Known":[{"line":576,"column":74},{"line":576,"column":90}]}},{"code":"let long_fill_spent =\ndiv_ceil((amount as u128) * long_spent, long_denominator) as u64;","region":{"Known":[{"line":576,"column":12},{"line":576,"column":99}]}},{"code":"order.side","region":{"Known":[{"line":577,"column":33},{"line":577,"column":43}]}},{"code":"no_price","region":{"Known":[{"line":577,"column":70},{"line":577,"column":78}]}},{"code":"clearing_price","region":{"Known":[{"line":577,"column":46},{"line":577,"column":60}]}},{"code":"let short_price = if order.side { clearing_price } else { no_price };","region":{"Known":[{"line":577,"column":12},{"line":577,"column":81}]}},{"code":"amount as u128","region":{"Known":[{"line":578,"column":46},{"line":578,"column":60}]}},{"code":"short_price","region":{"Known":[{"line":578,"column":62},{"line":578,"column":73}]}},{"code":"let short_fill_shares = shares_at(amount as u128, short_price);","region":{"Known":[{"line":578,"column":12},{"line":578,"column":75}]}},{"code":"on_long_side","region":{"Known":[{"line":580,"column":47},{"line":580,"column":59}]}},{"code":"short_fill_shares","region":{"Known":[{"line":580,"column":88},{"line":580,"column":105}]}},{"code":"long_fill_shares","region":{"Known":[{"line":580,"column":62},{"line":580,"column":78}]}},{"code":"if on_long_side { long_fill_shares } else { short_fill_shares }","region":{"Known":[{"line":580,"column":44},{"line":580,"column":107}]}},{"code":"value > U64_MAX","region":{"Known":[{"line":399,"column":12},{"line":399,"column":27}]}},{"code":"value","region":{"Known":[{"line":399,"column":47},{"line":399,"column":52}]}},{"code":"U64_MAX","region":{"Known":[{"line":399,"column":30},{"line":399,"column":37}]}},{"code":"if value > U64_MAX { U64_MAX } else { value }","region":{"Known":[{"line":399,"column":9},{"line":399,"column":54}]}},{"code":"(if value > U64_MAX { U64_MAX } else { value }) as u64","region":{"Known":[{"line":399,"column":8},{"line":399,"column":62}]}},{"code":"let order_shares =\nsaturate_u64(if on_long_side { long_fill_shares } else { short_fill_shares });","region":{"Known":[{"line":580,"column":12},{"line":580,"column":109}]}},{"code":"on_long_side","region":{"Known":[{"line":581,"column":33},{"line":581,"column":45}]}},{"code":"amount","region":{"Known":[{"line":581,"column":73},{"line":581,"column":79}]}},{"code":"long_fill_spent","region":{"Known":[{"line":581,"column":48},{"line":581,"column":63}]}},{"code":"let order_spent = if on_long_side { long_fill_spent } else { amount };","region":{"Known":[{"line":581,"column":12},{"line":581,"column":82}]}},{"code":"i","region":{"Known":[{"line":584,"column":18},{"line":584,"column":19}]}},{"code":"i","region":{"Known":[{"line":584,"column":30},{"line":584,"column":31}]}},{"code":"sides [i] = active [i] && order.side && order_shares > 0;","region":{"Known":[{"line":584,"column":12},{"line":584,"column":67}]}},{"code":"i","region":{"Known":[{"line":585,"column":19},{"line":585,"column":20}]}},{"code":"i","region":{"Known":[{"line":585,"column":34},{"line":585,"column":35}]}},{"code":"active [i]","region":{"Known":[{"line":585,"column":27},{"line":585,"column":36}]}},{"code":"0","region":{"Known":[{"line":585,"column":61},{"line":585,"column":62}]}},{"code":"order_shares","region":{"Known":[{"line":585,"column":39},{"line":585,"column":51}]}},{"code":"shares [i] = if active [i] { order_shares } else { 0 };","region":{"Known":[{"line":585,"column":12},{"line":585,"column":65}]}},{"code":"i","region":{"Known":[{"line":586,"column":18},{"line":586,"column":19}]}},{"code":"i","region":{"Known":[{"line":586,"column":33},{"line":586,"column":34}]}},{"code":"active [i]","region":{"Known":[{"line":586,"column":26},{"line":586,"column":35}]}},{"code":"0","region":{"Known":[{"line":586,"column":59},{"line":586,"column":60}]}},{"code":"order_spent","region":{"Known":[{"line":586,"column":38},{"line":586,"column":49}]}},{"code":"spent [i] = if active [i] { order_spent } else { 0 };","region":{"Known":[{"line":586,"column":12},{"line":586,"column":63}]}},{"code":"i","region":{"Known":[{"line":587,"column":20},{"line":587,"column":21}]}},{"code":"i","region":{"Known":[{"line":587,"column":35},{"line":587,"column":36}]}},{"code":"active [i]","region":{"Known":[{"line":587,"column":28},{"line":587,"column":37}]}},{"code":"0","region":{"Known":[{"line":587,"column":74},{"line":587,"column":75}]}},{"code":"i","region":{"Known":[{"line":587,"column":48},{"line":587,"column":49}]}},{"code":"escrows [i] - order_spent","region":{"Known":[{"line":587,"column":40},{"line":587,"column":64}]}},{"code":"refunds [i] = if active [i] { escrows [i] - order_spent } else { 0 };","region":{"Known":[{"line":587,"column":12},{"line":587,"column":78}]}},{"code":"for i in 0 .. MAX_BATCH_ORDERS\n{\n    let order = & orders [i]; let eligible = if order.side\n    { order.limit_price >= clearing_price } else\n    { order.limit_price >= no_price }; let amount = if eligible\n    { amounts [i] } else { 0 }; let on_long_side = order.side == long_is_yes;\n    let long_fill_shares = (amount as u128) * long_shares / long_denominator;\n    let long_fill_spent =\n    div_ceil((amount as u128) * long_spent, long_denominator) as u64; let\n    short_price = if order.side { clearing_price } else { no_price }; let\n    short_fill_shares = shares_at(amount as u128, short_price); let\n    order_shares =\n    saturate_u64(if on_long_side { long_fill_shares } else\n    { short_fill_shares }); let order_spent = if on_long_side\n    { long_fill_spent } else { amount }; sides [i] = active [i] && order.side\n    && order_shares > 0; shares [i] = if active [i] { order_shares } else\n    { 0 }; spent [i] = if active [i] { order_spent } else { 0 }; refunds [i] =\n    if active [i] { escrows [i] - order_spent } else { 0 };\n}","region":{"Known":[{"line":565,"column":8},{"line":588,"column":9}]}},{"code":"new_yes_reserves","region":{"Known":[{"line":591,"column":26},{"line":591,"column":42}]}},{"code":"new_no_reserves","region":{"Known":[{"line":592,"column":25},{"line":592,"column":40}]}},{"code":"let new_state = CfmmState\n{ yes_reserves : new_yes_reserves, no_reserves : new_no_reserves, };","region":{"Known":[{"line":590,"column":8},{"line":593,"column":10}]}},{"code":"new_state.yes_reserves","region":{"Known":[{"line":594,"column":40},{"line":594,"column":62}]}},{"code":"new_state.no_reserves","region":{"Known":[{"line":594,"column":64},{"line":594,"column":85}]}},{"code":"yes_reserves as u128","region":{"Known":[{"line":375,"column":21},{"line":375,"column":41}]}},{"code":"no_reserves as u128","region":{"Known":[{"line":375,"column":46},{"line":375,"column":65}]}},{"code":"let total = (yes_reserves as u128) + (no_reserves as u128);","region":{"Known":[{"line":375,"column":8},{"line":375,"column":67}]}},{"code":"total > 0","region":{"Known":[{"line":376,"column":29},{"line":376,"column":38}]}},{"code":"1","region":{"Known":[{"line":376,"column":56},{"line":376,"column":57}]}},{"code":"total","region":{"Known":[{"line":376,"column":41},{"line":376,"column":46}]}},{"code":"let denominator = if total > 0 { total } else { 1 };","region":{"Known":[{"line":376,"column":8},{"line":376,"column":60}]}},{"code":"total > 0","region":{"Known":[{"line":377,"column":23},{"line":377,"column":32}]}},{"code":"PRICE_SCALE / 2","region":{"Known":[{"line":380,"column":12},{"line":380,"column":27}]}},{"code":"no_reserves as u128","region":{"Known":[{"line":378,"column":14},{"line":378,"column":33}]}},{"code":"PRICE_SCALE as u128","region":{"Known":[{"line":378,"column":38},{"line":378,"column":57}]}},{"code":"(no_reserves as u128) * (PRICE_SCALE as u128) / denominator","region":{"Known":[{"line":378,"column":13},{"line":378,"column":72}]}},{"code":"((no_reserves as u128) * (PRICE_SCALE as u128) / denominator) as u64","region":{"Known":[{"line":378,"column":12},{"line":378,"column":80}]}},{"code":"let price = if total > 0\n{ ((no_reserves as u128) * (PRICE_SCALE as u128) / denominator) as u64 } else\n{ PRICE_SCALE / 2 };","region":{"Known":[{"line":377,"column":8},{"line":381,"column":10}]}},{"code":"price / PRICE_TICK * PRICE_TICK","region":{"Known":[{"line":382,"column":8},{"line":382,"column":39}]}},{"code":"let public_price =\ncoarse_price(new_state.yes_reserves, new_state.no_reserves);","region":{"Known":[{"line":594,"column":8},{"line":594,"column":87}]}},{"code":"new_state","region":{"Known":[{"line":599,"column":40},{"line":599,"column":49}]}},{"code":"state_ctxt.owner.from_arcis(new_state)","region":{"Known":[{"line":599,"column":12},{"line":599,"column":50}]}},{"code":"public_price.reveal()","region":{"Known":[{"line":600,"column":12},{"line":600,"column":33}]}},{"code":"clearing_price.reveal()","region":{"Known":[{"line":601,"column":12},{"line":601,"column":35}]}},{"code":"0","region":{"Known":[{"line":602,"column":64},{"line":602,"column":65}]}},{"code":"shares [0]","region":{"Known":[{"line":602,"column":57},{"line":602,"column":66}]}},{"code":"0","region":{"Known":[{"line":602,"column":81},{"line":602,"column":82}]}},{"code":"spent [0]","region":{"Known":[{"line":602,"column":75},{"line":602,"column":83}]}},{"code":"OrderFill { shares : shares [0], spent : spent [0] }","region":{"Known":[{"line":602,"column":37},{"line":602,"column":85}]}},{"code":"order_0.owner.from_arcis(OrderFill { shares : shares [0], spent : spent [0] })","region":{"Known":[{"line":602,"column":12},{"line":602,"column":86}]}},{"code":"1","region":{"Known":[{"line":603,"column":64},{"line":603,"column":65}]}},{"code":"shares [1]","region":{"Known":[{"line":603,"column":57},{"line":603,"column":66}]}},{"code":"1","region":{"Known":[{"line":603,"column":81},{"line":603,"column":82}]}},{"code":"spent [1]","region":{"Known":[{"line":603,"column":75},{"line":603,"column":83}]}},{"code":"OrderFill { shares : shares [1], spent : spent [1] }","region":{"Known":[{"line":603,"column":37},{"line":603,"column":85}]}},{"code":"order_1.owner.from_arcis(OrderFill { shares : shares [1], spent : spent [1] })","region":{"Known":[{"line":603,"column":12},{"line":603,"column":86}]}},{"code":"2","region":{"Known":[{"line":604,"column":64},{"line":604,"column":65}]}},{"code":"shares [2]","region":{"Known":[{"line":604,"column":57},{"line":604,"column":66}]}},{"code":"2","region":{"Known":[{"line":604,"column":81},{"line":604,"column":82}]}},{"code":"spent [2]","region":{"Known":[{"line":604,"column":75},{"line":604,"column":83}]}},{"code":"OrderFill { shares : shares [2], spent : spent [2] }","region":{"Known":[{"line":604,"column":37},{"line":604,"column":85}]}},{"code":"order_2.owner.from_arcis(OrderFill { shares : shares [2], spent : spent [2] })","region":{"Known":[{"line":604,"column":12},{"line":604,"column":86}]}},{"code":"3","region":{"Known":[{"line":605,"column":64},{"line":605,"column":65}]}},{"code":"shares [3]","region":{"Known":[{"line":605,"column":57},{"line":605,"column":66}]}},{"code":"3","region":{"Known":[{"line":605,"column":81},{"line":605,"column":82}]}},{"code":"spent [3]","region":{"Known":[{"line":605,"column":75},{"line":605,"column":83}]}},{"code":"OrderFill { shares : shares [3], spent : spent [3] }","region":{"Known":[{"line":605,"column":37},{"line":605,"column":85}]}},{"code":"order_3.owner.from_arcis(OrderFill { shares : shares [3], spent : spent [3] })","region":{"Known":[{"line":605,"column":12},{"line":605,"column":86}]}},{"code":"sides.reveal()","region":{"Known":[{"line":606,"column":12},{"line":606,"column":26}]}},{"code":"shares.reveal()","region":{"Known":[{"line":607,"column":12},{"line":607,"column":27}]}},{"code":"refunds.reveal()","region":{"Known":[{"line":608,"column":12},{"line":608,"column":28}]}},{"code":"(state_ctxt.owner.from_arcis(new_state), public_price.reveal(),\nclearing_price.reveal(),\norder_0.owner.from_arcis(OrderFill\n{ shares : shares [0], spent : spent [0] }),\norder_1.owner.from_arcis(OrderFill\n{ shares : shares [1], spent : spent [1] }),\norder_2.owner.from_arcis(OrderFill\n{ shares : shares [2], spent : spent [2] }),\norder_3.owner.from_arcis(OrderFill\n{ shares : shares [3], spent : spent [3] }), sides.reveal(), shares.reveal(),\nrefunds.reveal(),)","region":{"Known":[{"line":598,"column":8},{"line":609,"column":9}]}},{"code":"let state = state_ctxt.to_arcis();","region":{"Known":[{"line":628,"column":8},{"line":628,"column":42}]}},{"code":"state.yes_reserves > state.no_reserves","region":{"Known":[{"line":630,"column":29},{"line":630,"column":67}]}},{"code":"state.no_reserves","region":{"Known":[{"line":633,"column":12},{"line":633,"column":29}]}},{"code":"state.yes_reserves","region":{"Known":[{"line":631,"column":12},{"line":631,"column":30}]}},{"code":"let pool_weight = if state.yes_reserves > state.no_reserves\n{ state.yes_reserves } else { state.no_reserves };","region":{"Known":[{"line":630,"column":8},{"line":634,"column":10}]}},{"code":"let empty_pool = pool_weight == 0;","region":{"Known":[{"line":635,"column":8},{"line":635,"column":42}]}},{"code":"let seeding = total_shares == 0 && empty_pool;","region":{"Known":[{"line":636,"column":8},{"line":636,"column":54}]}},{"code":"total_shares == 0 && ! empty_pool","region":{"Known":[{"line":641,"column":26},{"line":641,"column":58}]}},{"code":"total_shares > 0 && empty_pool","region":{"Known":[{"line":641,"column":64},{"line":641,"column":94}]}},{"code":"let mismatched = (total_shares == 0 && ! empty_pool) ||\n(total_shares > 0 && empty_pool);","region":{"Known":[{"line":641,"column":8},{"line":641,"column":96}]}},{"code":"empty_pool","region":{"Known":[{"line":642,"column":24},{"line":642,"column":34}]}},{"code":"pool_weight as u128","region":{"Known":[{"line":642,"column":48},{"line":642,"column":67}]}},{"code":"1","region":{"Known":[{"line":642,"column":37},{"line":642,"column":38}]}},{"code":"let weight = if empty_pool { 1 } else { pool_weight as u128 };","region":{"Known":[{"line":642,"column":8},{"line":642,"column":70}]}},{"code":"lp_fees_accrued as u128","region":{"Known":[{"line":643,"column":30},{"line":643,"column":53}]}},{"code":"let value = weight + (lp_fees_accrued as u128);","region":{"Known":[{"line":643,"column":8},{"line":643,"column":55}]}},{"code":"seeding","region":{"Known":[{"line":645,"column":24},{"line":645,"column":31}]}},{"code":"mismatched","region":{"Known":[{"line":647,"column":18},{"line":647,"column":28}]}},{"code":"amount as u128","region":{"Known":[{"line":650,"column":14},{"line":650,"column":28}]}},{"code":"total_shares as u128","region":{"Known":[{"line":650,"column":33},{"line":650,"column":53}]}},{"code":"(amount as u128) * (total_shares as u128) / value","region":{"Known":[{"line":650,"column":13},{"line":650,"column":62}]}},{"code":"((amount as u128) * (total_shares as u128) / value) as u64","region":{"Known":[{"line":650,"column":12},{"line":650,"column":70}]}},{"code":"0","region":{"Known":[{"line":648,"column":12},{"line":648,"column":13}]}},{"code":"amount","region":{"Known":[{"line":646,"column":12},{"line":646,"column":18}]}},{"code":"let shares = if seeding { amount } else if mismatched { 0 } else\n{ ((amount as u128) * (total_shares as u128) / value) as u64 };","region":{"Known":[{"line":645,"column":8},{"line":651,"column":10}]}},{"code":"seeding","region":{"Known":[{"line":652,"column":27},{"line":652,"column":34}]}},{"code":"mismatched","region":{"Known":[{"line":654,"column":18},{"line":654,"column":28}]}},{"code":"amount as u128","region":{"Known":[{"line":657,"column":14},{"line":657,"column":28}]}},{"code":"(amount as u128) * weight / value","region":{"Known":[{"line":657,"column":13},{"line":657,"column":46}]}},{"code":"((amount as u128) * weight / value) as u64","region":{"Known":[{"line":657,"column":12},{"line":657,"column":54}]}},{"code":"0","region":{"Known":[{"line":655,"column":12},{"line":655,"column":13}]}},{"code":"amount","region":{"Known":[{"line":653,"column":12},{"line":653,"column":18}]}},{"code":"let deposited = if seeding { amount } else if mismatched { 0 } else\n{ ((amount as u128) * weight / value) as u64 };","region":{"Known":[{"line":652,"column":8},{"line":658,"column":10}]}},{"code":"seeding","region":{"Known":[{"line":659,"column":27},{"line":659,"column":34}]}},{"code":"deposited as u128","region":{"Known":[{"line":662,"column":14},{"line":662,"column":31}]}},{"code":"state.yes_reserves as u128","region":{"Known":[{"line":662,"column":36},{"line":662,"column":62}]}},{"code":"(deposited as u128) * (state.yes_reserves as u128) / weight","region":{"Known":[{"line":662,"column":13},{"line":662,"column":72}]}},{"code":"((deposited as u128) * (state.yes_reserves as u128) / weight) as u64","region":{"Known":[{"line":662,"column":12},{"line":662,"column":80}]}},{"code":"amount","region":{"Known":[{"line":660,"column":12},{"line":660,"column":18}]}},{"code":"let yes_added = if seeding { amount } else\n{ ((deposited as u128) * (state.yes_reserves as u128) / weight) as u64 };","region":{"Known":[{"line":659,"column":8},{"line":663,"column":10}]}},{"code":"seeding","region":{"Known":[{"line":664,"column":26},{"line":664,"column":33}]}},{"code":"deposited as u128","region":{"Known":[{"line":667,"column":14},{"line":667,"column":31}]}},{"code":"state.no_reserves as u128","region":{"Known":[{"line":667,"column":36},{"line":667,"column":61}]}},{"code":"(deposited as u128) * (state.no_reserves as u128) / weight","region":{"Known":[{"line":667,"column":13},{"line":667,"column":71}]}},{"code":"((deposited as u128) * (state.no_reserves as u128) / weight) as u64","region":{"Known":[{"line":667,"column":12},{"line":667,"column":79}]}},{"code":"amount","region":{"Known":[{"line":665,"column":12},{"line":665,"column":18}]}},{"code":"let no_added = if seeding { amount } else\n{ ((deposited as u128) * (state.no_reserves as u128) / weight) as u64 };","region":{"Known":[{"line":664,"column":8},{"line":668,"column":10}]}},{"code":"mismatched","region":{"Known":[{"line":669,"column":28},{"line":669,"column":38}]}},{"code":"amount - deposited","region":{"Known":[{"line":669,"column":52},{"line":669,"column":70}]}},{"code":"0","region":{"Known":[{"line":669,"column":41},{"line":669,"column":42}]}},{"code":"let fee_buy_in = if mismatched { 0 } else { amount - deposited };","region":{"Known":[{"line":669,"column":8},{"line":669,"column":73}]}},{"code":"mismatched","region":{"Known":[{"line":670,"column":30},{"line":670,"column":40}]}},{"code":"deposited - yes_added","region":{"Known":[{"line":670,"column":59},{"line":670,"column":80}]}},{"code":"amount","region":{"Known":[{"line":670,"column":43},{"line":670,"column":49}]}},{"code":"let yes_returned = if mismatched { amount } else { deposited - yes_added };","region":{"Known":[{"line":670,"column":8},{"line":670,"column":83}]}},{"code":"mismatched","region":{"Known":[{"line":671,"column":29},{"line":671,"column":39}]}},{"code":"deposited - no_added","region":{"Known":[{"line":671,"column":58},{"line":671,"column":78}]}},{"code":"amount","region":{"Known":[{"line":671,"column":42},{"line":671,"column":48}]}},{"code":"let no_returned = if mismatched { amount } else { deposited - no_added };","region":{"Known":[{"line":671,"column":8},{"line":671,"column":81}]}},{"code":"state.yes_reserves + yes_added","region":{"Known":[{"line":674,"column":26},{"line":674,"column":56}]}},{"code":"state.no_reserves + no_added","region":{"Known":[{"line":675,"column":25},{"line":675,"column":53}]}},{"code":"let new_state = CfmmState\n{\n    yes_reserves : state.yes_reserves + yes_added, no_reserves :\n    state.no_reserves + no_added,\n};","region":{"Known":[{"line":673,"column":8},{"line":676,"column":10}]}},{"code":"new_state","region":{"Known":[{"line":679,"column":40},{"line":679,"column":49}]}},{"code":"state_ctxt.owner.from_arcis(new_state)","region":{"Known":[{"line":679,"column":12},{"line":679,"column":50}]}},{"code":"shares.reveal()","region":{"Known":[{"line":680,"column":12},{"line":680,"column":27}]}},{"code":"fee_buy_in.reveal()","region":{"Known":[{"line":681,"column":12},{"line":681,"column":31}]}},{"code":"yes_returned.reveal()","region":{"Known":[{"line":682,"column":12},{"line":682,"column":33}]}},{"code":"no_returned.reveal()","region":{"Known":[{"line":683,"column":12},{"line":683,"column":32}]}},{"code":"(state_ctxt.owner.from_arcis(new_state), shares.reveal(), fee_buy_in.reveal(),\nyes_returned.reveal(), no_returned.reveal(),)","region":{"Known":[{"line":678,"column":8},{"line":684,"column":9}]}},{"code":"let state = state_ctxt.to_arcis();","region":{"Known":[{"line":695,"column":8},{"line":695,"column":42}]}},{"code":"shares > total_shares","region":{"Known":[{"line":697,"column":24},{"line":697,"column":45}]}},{"code":"shares","region":{"Known":[{"line":697,"column":70},{"line":697,"column":76}]}},{"code":"total_shares","region":{"Known":[{"line":697,"column":48},{"line":697,"column":60}]}},{"code":"let shares = if shares > total_shares { total_shares } else { shares };","region":{"Known":[{"line":697,"column":8},{"line":697,"column":79}]}},{"code":"total_shares > 0","region":{"Known":[{"line":698,"column":23},{"line":698,"column":39}]}},{"code":"1","region":{"Known":[{"line":698,"column":72},{"line":698,"column":73}]}},{"code":"total_shares as u128","region":{"Known":[{"line":698,"column":42},{"line":698,"column":62}]}},{"code":"let total = if total_shares > 0 { total_shares as u128 } else { 1 };","region":{"Known":[{"line":698,"column":8},{"line":698,"column":76}]}},{"code":"state.yes_reserves as u128","region":{"Known":[{"line":699,"column":24},{"line":699,"column":50}]}},{"code":"shares as u128","region":{"Known":[{"line":699,"column":55},{"line":699,"column":69}]}},{"code":"(state.yes_reserves as u128) * (shares as u128) / total","region":{"Known":[{"line":699,"column":23},{"line":699,"column":78}]}},{"code":"let yes_out = ((state.yes_reserves as u128) * (shares as u128) / total) as\nu64;","region":{"Known":[{"line":699,"column":8},{"line":699,"column":87}]}},{"code":"state.no_reserves as u128","region":{"Known":[{"line":700,"column":23},{"line":700,"column":48}]}},{"code":"shares as u128","region":{"Known":[{"line":700,"column":53},{"line":700,"column":67}]}},{"code":"(state.no_reserves as u128) * (shares as u128) / total","region":{"Known":[{"line":700,"column":22},{"line":700,"column":76}]}},{"code":"let no_out = ((state.no_reserves as u128) * (shares as u128) / total) as u64;","region":{"Known":[{"line":700,"column":8},{"line":700,"column":85}]}},{"code":"state.yes_reserves - yes_out","region":{"Known":[{"line":703,"column":26},{"line":703,"column":54}]}},{"code":"state.no_reserves - no_out","region":{"Known":[{"line":704,"column":25},{"line":704,"column":51}]}},{"code":"let new_state = CfmmState\n{\n    yes_reserves : state.yes_reserves - yes_out, no_reserves :\n    state.no_reserves - no_out,\n};","region":{"Known":[{"line":702,"column":8},{"line":705,"column":10}]}},{"code":"new_state","region":{"Known":[{"line":708,"column":40},{"line":708,"column":49}]}},{"code":"state_ctxt.owner.from_arcis(new_state)","region":{"Known":[{"line":708,"column":12},{"line":708,"column":50}]}},{"code":"yes_out.reveal()","region":{"Known":[{"line":709,"column":12},{"line":709,"column":28}]}},{"code":"no_out.reveal()","region":{"Known":[{"line":710,"column":12},{"line":710,"column":27}]}},{"code":"(state_ctxt.owner.from_arcis(new_state), yes_out.reveal(), no_out.reveal(),)","region":{"Known":[{"line":707,"column":8},{"line":711,"column":9}]}},{"code":"let mut yes_weight = 0u64;","region":{"Known":[{"line":743,"column":8},{"line":743,"column":34}]}},{"code":"let mut no_weight = 0u64;","region":{"Known":[{"line":744,"column":8},{"line":744,"column":33}]}},{"code":"stakes","region":{"Known":[{"line":745,"column":61},{"line":745,"column":67}]}},{"code":"self","region":"Unknown"},{"code":"other.into_iter()","region":"Unknown"},{"code":"iter_l","region":"Unknown"},{"code":"iter_r","region":"Unknown"},{"code":"ArcisZipIterator { iter_l, iter_r }","region":"Unknown"},{"code":"ArcisZipIterator :: new(self, other.into_iter())","region":"Unknown"},{"code":"self.iter_r.len()","region":"Unknown"},{"code":"#[arcis_circuit = \"min\"] fn min_circ < T > (a : T, b : T) -> T {}","region":"Unknown"},{"code":"self","region":"Unknown"},{"code":"other","region":"Unknown"},{"code":"min_circ(self, other)","region":"Unknown"},{"code":"self.iter_l.len().min(self.iter_r.len())","region":"Unknown"},{"code":"self.iter_l.__next()","region":"Unknown"},{"code":"self.iter_r.__next()","region":"Unknown"},{"code":"(self.iter_l.__next(), self.iter_r.__next())","region":"Unknown"},{"code":"let vote = vote_ctxt.to_arcis();","region":{"Known":[{"line":746,"column":12},{"line":746,"column":44}]}},{"code":"vote.outcome","region":{"Known":[{"line":747,"column":29},{"line":747,"column":41}]}},{"code":"0","region":{"Known":[{"line":747,"column":59},{"line":747,"column":60}]}},{"code":"stake","region":{"Known":[{"line":747,"column":44},{"line":747,"column":49}]}},{"code":"yes_weight += if vote.outcome { stake } else { 0 };","region":{"Known":[{"line":747,"column":12},{"line":747,"column":63}]}},{"code":"vote.outcome","region":{"Known":[{"line":748,"column":28},{"line":748,"column":40}]}},{"code":"stake","region":{"Known":[{"line":748,"column":54},{"line":748,"column":59}]}},{"code":"0","region":{"Known":[{"line":748,"column":43},{"line":748,"column":44}]}},{"code":"no_weight += if vote.outcome { 0 } else { stake };","region":{"Known":[{"line":748,"column":12},{"line":748,"column":62}]}},{"code":"for (vote_ctxt, stake) in vote_ctxts.into_iter().zip(stakes)\n{\n    let vote = vote_ctxt.to_arcis(); yes_weight += if vote.outcome { stake }\n    else { 0 }; no_weight += if vote.outcome { 0 } else { stake };\n}","region":{"Known":[{"line":745,"column":8},{"line":749,"column":9}]}},{"code":"yes_weight > no_weight","region":{"Known":[{"line":751,"column":31},{"line":751,"column":53}]}},{"code":"no_weight > yes_weight","region":{"Known":[{"line":753,"column":18},{"line":753,"column":40}]}},{"code":"OUTCOME_INVALID","region":{"Known":[{"line":756,"column":12},{"line":756,"column":27}]}},{"code":"OUTCOME_NO","region":{"Known":[{"line":754,"column":12},{"line":754,"column":22}]}},{"code":"OUTCOME_YES","region":{"Known":[{"line":752,"column":12},{"line":752,"column":23}]}},{"code":"let final_outcome = if yes_weight > no_weight { OUTCOME_YES } else if\nno_weight > yes_weight { OUTCOME_NO } else { OUTCOME_INVALID };","region":{"Known":[{"line":751,"column":8},{"line":757,"column":10}]}},{"code":"yes_weight > no_weight","region":{"Known":[{"line":758,"column":28},{"line":758,"column":50}]}},{"code":"no_weight","region":{"Known":[{"line":758,"column":73},{"line":758,"column":82}]}},{"code":"yes_weight","region":{"Known":[{"line":758,"column":53},{"line":758,"column":63}]}},{"code":"let confidence = if yes_weight > no_weight { yes_weight } else { no_weight };","region":{"Known":[{"line":758,"column":8},{"line":758,"column":85}]}},{"code":"final_outcome","region":{"Known":[{"line":761,"column":12},{"line":761,"column":25}]}},{"code":"confidence","region":{"Known":[{"line":762,"column":12},{"line":762,"column":22}]}},{"code":"let result = ResolutionResult { final_outcome, confidence, };","region":{"Known":[{"line":760,"column":8},{"line":763,"column":10}]}},{"code":"result.final_outcome.reveal()","region":{"Known":[{"line":767,"column":9},{"line":767,"column":38}]}},{"code":"result.confidence.reveal()","region":{"Known":[{"line":767,"column":40},{"line":767,"column":66}]}},{"code":"(result.final_outcome.reveal(), result.confidence.reveal())","region":{"Known":[{"line":767,"column":8},{"line":767,"column":67}]}}]}
+, 'instructions':
+{"batch_clear":{"profilees":[{"code_idx":340,"before_size_id":0,"after_size_id":1},{"code_idx":29,"before_size_id":1,"after_size_id":1},{"code_idx":30,"before_size_id":1,"after_size_id":1},{"code_idx":31,"before_size_id":1,"after_size_id":2},{"code_idx":341,"before_size_id":1,"after_size_id":2},{"code_idx":29,"before_size_id":2,"after_size_id":2},{"code_idx":30,"before_size_id":2,"after_size_id":2},{"code_idx":31,"before_size_id":2,"after_size_id":3},{"code_idx":342,"before_size_id":2,"after_size_id":3},{"code_idx":29,"before_size_id":3,"after_size_id":3},{"code_idx":30,"before_size_id":3,"after_size_id":3},{"code_idx":31,"before_size_id":3,"after_size_id":4},{"code_idx":343,"before_size_id":3,"after_size_id":4},{"code_idx":29,"before_size_id":4,"after_size_id":4},{"code_idx":30,"before_size_id":4,"after_size_id":4},{"code_idx":31,"before_size_id":4,"after_size_id":5},{"code_idx":344,"before_size_id":4,"after_size_id":5},{"code_idx":345,"before_size_id":1,"after_size_id":5},{"code_idx":29,"before_size_id":5,"after_size_id":5},{"code_idx":30,"before_size_id":5,"after_size_id":5},{"code_idx":31,"before_size_id":5,"after_size_id":6},{"code_idx":346,"before_size_id":5,"after_size_id":6},{"code_idx":347,"before_size_id":6,"after_size_id":6},{"code_idx":348,"before_size_id":6,"after_size_id":6},{"code_idx":349,"before_size_id":6,"after_size_id":6},{"code_idx":350,"before_size_id":6,"after_size_id":6},{"code_idx":184,"before_size_id":6,"after_size_id":6},{"code_idx":185,"before_size_id":6,"after_size_id":7},{"code_idx":186,"before_size_id":7,"after_size_id":7},{"code_idx":187,"before_size_id":7,"after_size_id":7},{"code_idx":188,"before_size_id":7,"after_size_id":7},{"code_idx":189,"before_size_id":6,"after_size_id":7},{"code_idx":190,"before_size_id":7,"after_size_id":8},{"code_idx":191,"before_size_id":8,"after_size_id":8},{"code_idx":192,"before_size_id":8,"after_size_id":8},{"code_idx":193,"before_size_id":8,"after_size_id":8},{"code_idx":194,"before_size_id":8,"after_size_id":8},{"code_idx":195,"before_size_id":8,"after_size_id":9},{"code_idx":196,"before_size_id":7,"after_size_id":9},{"code_idx":197,"before_size_id":9,"after_size_id":9},{"code_idx":198,"before_size_id":9,"after_size_id":10},{"code_idx":199,"before_size_id":10,"after_size_id":10},{"code_idx":351,"before_size_id":10,"after_size_id":10},{"code_idx":352,"before_size_id":10,"after_size_id":10},{"code_idx":353,"before_size_id":10,"after_size_id":11},{"code_idx":354,"before_size_id":11,"after_size_id":11},{"code_idx":355,"before_size_id":11,"after_size_id":11},{"code_idx":356,"before_size_id":11,"after_size_id":12},{"code_idx":357,"before_size_id":12,"after_size_id":12},{"code_idx":358,"before_size_id":12,"after_size_id":12},{"code_idx":359,"before_size_id":12,"after_size_id":12},{"code_idx":360,"before_size_id":12,"after_size_id":12},{"code_idx":361,"before_size_id":11,"after_size_id":13},{"code_idx":362,"before_size_id":13,"after_size_id":13},{"code_idx":363,"before_size_id":13,"after_size_id":13},{"code_idx":364,"before_size_id":13,"after_size_id":13},{"code_idx":365,"before_size_id":13,"after_size_id":13},{"code_idx":366,"before_size_id":13,"after_size_id":13},{"code_idx":367,"before_size_id":13,"after_size_id":14},{"code_idx":197,"before_size_id":14,"after_size_id":14},{"code_idx":198,"before_size_id":14,"after_size_id":15},{"code_idx":199,"before_size_id":15,"after_size_id":15},{"code_idx":351,"before_size_id":15,"after_size_id":15},{"code_idx":352,"before_size_id":15,"after_size_id":15},{"code_idx":353,"before_size_id":15,"after_size_id":16},{"code_idx":354,"before_size_id":16,"after_size_id":16},{"code_idx":355,"before_size_id":16,"after_size_id":16},{"code_idx":356,"before_size_id":16,"after_size_id":17},{"code_idx":357,"before_size_id":17,"after_size_id":17},{"code_idx":358,"before_size_id":17,"after_size_id":17},{"code_idx":359,"before_size_id":17,"after_size_id":17},{"code_idx":360,"before_size_id":17,"after_size_id":17},{"code_idx":361,"before_size_id":16,"after_size_id":18},{"code_idx":362,"before_size_id":18,"after_size_id":18},{"code_idx":363,"before_size_id":18,"after_size_id":18},{"code_idx":364,"before_size_id":18,"after_size_id":18},{"code_idx":365,"before_size_id":18,"after_size_id":18},{"code_idx":366,"before_size_id":18,"after_size_id":18},{"code_idx":367,"before_size_id":18,"after_size_id":19},{"code_idx":197,"before_size_id":19,"after_size_id":19},{"code_idx":198,"before_size_id":19,"after_size_id":20},{"code_idx":199,"before_size_id":20,"after_size_id":20},{"code_idx":351,"before_size_id":20,"after_size_id":20},{"code_idx":352,"before_size_id":20,"after_size_id":20},{"code_idx":353,"before_size_id":20,"after_size_id":21},{"code_idx":354,"before_size_id":21,"after_size_id":21},{"code_idx":355,"before_size_id":21,"after_size_id":21},{"code_idx":356,"before_size_id":21,"after_size_id":22},{"code_idx":357,"before_size_id":22,"after_size_id":22},{"code_idx":358,"before_size_id":22,"after_size_id":22},{"code_idx":359,"before_size_id":22,"after_size_id":22},{"code_idx":360,"before_size_id":22,"after_size_id":22},{"code_idx":361,"before_size_id":21,"after_size_id":23},{"code_idx":362,"before_size_id":23,"after_size_id":23},{"code_idx":363,"before_size_id":23,"after_size_id":23},{"code_idx":364,"before_size_id":23,"after_size_id":23},{"code_idx":365,"before_size_id":23,"after_size_id":23},{"code_idx":366,"before_size_id":23,"after_size_id":23},{"code_idx":367,"before_size_id":23,"after_size_id":24},{"code_idx":197,"before_size_id":24,"after_size_id":24},{"code_idx":198,"before_size_id":24,"after_size_id":25},{"code_idx":199,"before_size_id":25,"after_size_id":25},{"code_idx":351,"before_size_id":25,"after_size_id":25},{"code_idx":352,"before_size_id":25,"after_size_id":25},{"code_idx":353,"before_size_id":25,"after_size_id":26},{"code_idx":354,"before_size_id":26,"after_size_id":26},{"code_idx":355,"before_size_id":26,"after_size_id":26},{"code_idx":356,"before_size_id":26,"after_size_id":27},{"code_idx":357,"before_size_id":27,"after_size_id":27},{"code_idx":358,"before_size_id":27,"after_size_id":27},{"code_idx":359,"before_size_id":27,"after_size_id":27},{"code_idx":360,"before_size_id":27,"after_size_id":27},{"code_idx":361,"before_size_id":26,"after_size_id":28},{"code_idx":362,"before_size_id":28,"after_size_id":28},{"code_idx":363,"before_size_id":28,"after_size_id":28},{"code_idx":364,"before_size_id":28,"after_size_id":28},{"code_idx":365,"before_size_id":28,"after_size_id":28},{"code_idx":366,"before_size_id":28,"after_size_id":28},{"code_idx":367,"before_size_id":28,"after_size_id":29},{"code_idx":368,"before_size_id":6,"after_size_id":29},{"code_idx":369,"before_size_id":29,"after_size_id":29},{"code_idx":370,"before_size_id":29,"after_size_id":29},{"code_idx":184,"before_size_id":29,"after_size_id":29},{"code_idx":185,"before_size_id":29,"after_size_id":29},{"code_idx":186,"before_size_id":29,"after_size_id":29},{"code_idx":187,"before_size_id":29,"after_size_id":29},{"code_idx":188,"before_size_id":29,"after_size_id":29},{"code_idx":189,"before_size_id":29,"after_size_id":29},{"code_idx":190,"before_size_id":29,"after_size_id":29},{"code_idx":191,"before_size_id":29,"after_size_id":29},{"code_idx":192,"before_size_id":29,"after_size_id":29},{"code_idx":193,"before_size_id":29,"after_size_id":29},{"code_idx":194,"before_size_id":29,"after_size_id":29},{"code_idx":195,"before_size_id":29,"after_size_id":29},{"code_idx":196,"before_size_id":29,"after_size_id":29},{"code_idx":197,"before_size_id":29,"after_size_id":29},{"code_idx":198,"before_size_id":29,"after_size_id":29},{"code_idx":199,"before_size_id":29,"after_size_id":29},{"code_idx":371,"before_size_id":29,"after_size_id":29},{"code_idx":372,"before_size_id":29,"after_size_id":29},{"code_idx":373,"before_size_id":29,"after_size_id":30},{"code_idx":374,"before_size_id":30,"after_size_id":30},{"code_idx":375,"before_size_id":30,"after_size_id":30},{"code_idx":376,"before_size_id":29,"after_size_id":31},{"code_idx":377,"before_size_id":31,"after_size_id":31},{"code_idx":378,"before_size_id":31,"after_size_id":32},{"code_idx":379,"before_size_id":32,"after_size_id":32},{"code_idx":380,"before_size_id":31,"after_size_id":33},{"code_idx":381,"before_size_id":33,"after_size_id":34},{"code_idx":382,"before_size_id":34,"after_size_id":34},{"code_idx":383,"before_size_id":34,"after_size_id":34},{"code_idx":384,"before_size_id":33,"after_size_id":35},{"code_idx":385,"before_size_id":35,"after_size_id":35},{"code_idx":386,"before_size_id":35,"after_size_id":36},{"code_idx":387,"before_size_id":36,"after_size_id":36},{"code_idx":388,"before_size_id":36,"after_size_id":36},{"code_idx":389,"before_size_id":36,"after_size_id":36},{"code_idx":390,"before_size_id":36,"after_size_id":36},{"code_idx":391,"before_size_id":36,"after_size_id":36},{"code_idx":184,"before_size_id":36,"after_size_id":36},{"code_idx":185,"before_size_id":36,"after_size_id":36},{"code_idx":186,"before_size_id":36,"after_size_id":36},{"code_idx":187,"before_size_id":36,"after_size_id":36},{"code_idx":188,"before_size_id":36,"after_size_id":36},{"code_idx":189,"before_size_id":36,"after_size_id":36},{"code_idx":190,"before_size_id":36,"after_size_id":36},{"code_idx":191,"before_size_id":36,"after_size_id":36},{"code_idx":192,"before_size_id":36,"after_size_id":36},{"code_idx":193,"before_size_id":36,"after_size_id":36},{"code_idx":194,"before_size_id":36,"after_size_id":36},{"code_idx":195,"before_size_id":36,"after_size_id":36},{"code_idx":196,"before_size_id":36,"after_size_id":36},{"code_idx":197,"before_size_id":36,"after_size_id":36},{"code_idx":198,"before_size_id":36,"after_size_id":36},{"code_idx":199,"before_size_id":36,"after_size_id":36},{"code_idx":392,"before_size_id":36,"after_size_id":36},{"code_idx":393,"before_size_id":36,"after_size_id":36},{"code_idx":394,"before_size_id":36,"after_size_id":37},{"code_idx":395,"before_size_id":37,"after_size_id":38},{"code_idx":396,"before_size_id":38,"after_size_id":38},{"code_idx":397,"before_size_id":38,"after_size_id":38},{"code_idx":398,"before_size_id":38,"after_size_id":38},{"code_idx":399,"before_size_id":38,"after_size_id":38},{"code_idx":400,"before_size_id":38,"after_size_id":39},{"code_idx":401,"before_size_id":39,"after_size_id":39},{"code_idx":402,"before_size_id":39,"after_size_id":39},{"code_idx":403,"before_size_id":39,"after_size_id":39},{"code_idx":404,"before_size_id":39,"after_size_id":39},{"code_idx":405,"before_size_id":39,"after_size_id":40},{"code_idx":197,"before_size_id":40,"after_size_id":40},{"code_idx":198,"before_size_id":40,"after_size_id":40},{"code_idx":199,"before_size_id":40,"after_size_id":40},{"code_idx":392,"before_size_id":40,"after_size_id":40},{"code_idx":393,"before_size_id":40,"after_size_id":40},{"code_idx":394,"before_size_id":40,"after_size_id":41},{"code_idx":395,"before_size_id":41,"after_size_id":42},{"code_idx":396,"before_size_id":42,"after_size_id":42},{"code_idx":397,"before_size_id":42,"after_size_id":42},{"code_idx":398,"before_size_id":42,"after_size_id":42},{"code_idx":399,"before_size_id":42,"after_size_id":42},{"code_idx":400,"before_size_id":42,"after_size_id":43},{"code_idx":401,"before_size_id":43,"after_size_id":43},{"code_idx":402,"before_size_id":43,"after_size_id":43},{"code_idx":403,"before_size_id":43,"after_size_id":43},{"code_idx":404,"before_size_id":43,"after_size_id":43},{"code_idx":405,"before_size_id":43,"after_size_id":44},{"code_idx":197,"before_size_id":44,"after_size_id":44},{"code_idx":198,"before_size_id":44,"after_size_id":44},{"code_idx":199,"before_size_id":44,"after_size_id":44},{"code_idx":392,"before_size_id":44,"after_size_id":44},{"code_idx":393,"before_size_id":44,"after_size_id":44},{"code_idx":394,"before_size_id":44,"after_size_id":45},{"code_idx":395,"before_size_id":45,"after_size_id":46},{"code_idx":396,"before_size_id":46,"after_size_id":46},{"code_idx":397,"before_size_id":46,"after_size_id":46},{"code_idx":398,"before_size_id":46,"after_size_id":46},{"code_idx":399,"before_size_id":46,"after_size_id":46},{"code_idx":400,"before_size_id":46,"after_size_id":47},{"code_idx":401,"before_size_id":47,"after_size_id":47},{"code_idx":402,"before_size_id":47,"after_size_id":47},{"code_idx":403,"before_size_id":47,"after_size_id":47},{"code_idx":404,"before_size_id":47,"after_size_id":47},{"code_idx":405,"before_size_id":47,"after_size_id":48},{"code_idx":197,"before_size_id":48,"after_size_id":48},{"code_idx":198,"before_size_id":48,"after_size_id":48},{"code_idx":199,"before_size_id":48,"after_size_id":48},{"code_idx":392,"before_size_id":48,"after_size_id":48},{"code_idx":393,"before_size_id":48,"after_size_id":48},{"code_idx":394,"before_size_id":48,"after_size_id":49},{"code_idx":395,"before_size_id":49,"after_size_id":50},{"code_idx":396,"before_size_id":50,"after_size_id":50},{"code_idx":397,"before_size_id":50,"after_size_id":50},{"code_idx":398,"before_size_id":50,"after_size_id":50},{"code_idx":399,"before_size_id":50,"after_size_id":50},{"code_idx":400,"before_size_id":50,"after_size_id":51},{"code_idx":401,"before_size_id":51,"after_size_id":51},{"code_idx":402,"before_size_id":51,"after_size_id":51},{"code_idx":403,"before_size_id":51,"after_size_id":51},{"code_idx":404,"before_size_id":51,"after_size_id":51},{"code_idx":405,"before_size_id":51,"after_size_id":52},{"code_idx":406,"before_size_id":36,"after_size_id":52},{"code_idx":407,"before_size_id":52,"after_size_id":52},{"code_idx":408,"before_size_id":52,"after_size_id":52},{"code_idx":409,"before_size_id":52,"after_size_id":52},{"code_idx":410,"before_size_id":36,"after_size_id":52},{"code_idx":411,"before_size_id":52,"after_size_id":52},{"code_idx":412,"before_size_id":52,"after_size_id":52},{"code_idx":413,"before_size_id":52,"after_size_id":52},{"code_idx":414,"before_size_id":52,"after_size_id":52},{"code_idx":415,"before_size_id":52,"after_size_id":52},{"code_idx":416,"before_size_id":52,"after_size_id":53},{"code_idx":417,"before_size_id":53,"after_size_id":53},{"code_idx":418,"before_size_id":54,"after_size_id":54},{"code_idx":419,"before_size_id":53,"after_size_id":55},{"code_idx":420,"before_size_id":52,"after_size_id":55},{"code_idx":421,"before_size_id":55,"after_size_id":55},{"code_idx":422,"before_size_id":55,"after_size_id":55},{"code_idx":413,"before_size_id":55,"after_size_id":56},{"code_idx":414,"before_size_id":56,"after_size_id":56},{"code_idx":415,"before_size_id":56,"after_size_id":56},{"code_idx":416,"before_size_id":55,"after_size_id":57},{"code_idx":417,"before_size_id":57,"after_size_id":57},{"code_idx":418,"before_size_id":58,"after_size_id":58},{"code_idx":419,"before_size_id":57,"after_size_id":59},{"code_idx":423,"before_size_id":55,"after_size_id":59},{"code_idx":424,"before_size_id":59,"after_size_id":60},{"code_idx":425,"before_size_id":60,"after_size_id":60},{"code_idx":426,"before_size_id":60,"after_size_id":60},{"code_idx":427,"before_size_id":59,"after_size_id":61},{"code_idx":428,"before_size_id":61,"after_size_id":62},{"code_idx":429,"before_size_id":62,"after_size_id":62},{"code_idx":430,"before_size_id":62,"after_size_id":62},{"code_idx":431,"before_size_id":62,"after_size_id":62},{"code_idx":432,"before_size_id":62,"after_size_id":63},{"code_idx":433,"before_size_id":63,"after_size_id":63},{"code_idx":434,"before_size_id":63,"after_size_id":63},{"code_idx":435,"before_size_id":63,"after_size_id":63},{"code_idx":436,"before_size_id":63,"after_size_id":64},{"code_idx":197,"before_size_id":64,"after_size_id":64},{"code_idx":198,"before_size_id":64,"after_size_id":64},{"code_idx":199,"before_size_id":64,"after_size_id":64},{"code_idx":371,"before_size_id":64,"after_size_id":64},{"code_idx":372,"before_size_id":64,"after_size_id":64},{"code_idx":373,"before_size_id":64,"after_size_id":65},{"code_idx":374,"before_size_id":65,"after_size_id":65},{"code_idx":375,"before_size_id":65,"after_size_id":65},{"code_idx":376,"before_size_id":64,"after_size_id":66},{"code_idx":377,"before_size_id":66,"after_size_id":66},{"code_idx":378,"before_size_id":66,"after_size_id":67},{"code_idx":379,"before_size_id":67,"after_size_id":67},{"code_idx":380,"before_size_id":66,"after_size_id":68},{"code_idx":381,"before_size_id":68,"after_size_id":69},{"code_idx":382,"before_size_id":69,"after_size_id":69},{"code_idx":383,"before_size_id":69,"after_size_id":69},{"code_idx":384,"before_size_id":68,"after_size_id":70},{"code_idx":385,"before_size_id":70,"after_size_id":70},{"code_idx":386,"before_size_id":70,"after_size_id":71},{"code_idx":387,"before_size_id":71,"after_size_id":71},{"code_idx":388,"before_size_id":71,"after_size_id":71},{"code_idx":389,"before_size_id":71,"after_size_id":71},{"code_idx":390,"before_size_id":71,"after_size_id":71},{"code_idx":391,"before_size_id":71,"after_size_id":71},{"code_idx":184,"before_size_id":71,"after_size_id":71},{"code_idx":185,"before_size_id":71,"after_size_id":71},{"code_idx":186,"before_size_id":71,"after_size_id":71},{"code_idx":187,"before_size_id":71,"after_size_id":71},{"code_idx":188,"before_size_id":71,"after_size_id":71},{"code_idx":189,"before_size_id":71,"after_size_id":71},{"code_idx":190,"before_size_id":71,"after_size_id":71},{"code_idx":191,"before_size_id":71,"after_size_id":71},{"code_idx":192,"before_size_id":71,"after_size_id":71},{"code_idx":193,"before_size_id":71,"after_size_id":71},{"code_idx":194,"before_size_id":71,"after_size_id":71},{"code_idx":195,"before_size_id":71,"after_size_id":71},{"code_idx":196,"before_size_id":71,"after_size_id":71},{"code_idx":197,"before_size_id":71,"after_size_id":71},{"code_idx":198,"before_size_id":71,"after_size_id":71},{"code_idx":199,"before_size_id":71,"after_size_id":71},{"code_idx":392,"before_size_id":71,"after_size_id":71},{"code_idx":393,"before_size_id":71,"after_size_id":71},{"code_idx":394,"before_size_id":71,"after_size_id":72},{"code_idx":395,"before_size_id":72,"after_size_id":73},{"code_idx":396,"before_size_id":73,"after_size_id":73},{"code_idx":397,"before_size_id":73,"after_size_id":73},{"code_idx":398,"before_size_id":73,"after_size_id":73},{"code_idx":399,"before_size_id":73,"after_size_id":73},{"code_idx":400,"before_size_id":73,"after_size_id":74},{"code_idx":401,"before_size_id":74,"after_size_id":74},{"code_idx":402,"before_size_id":74,"after_size_id":74},{"code_idx":403,"before_size_id":74,"after_size_id":74},{"code_idx":404,"before_size_id":74,"after_size_id":74},{"code_idx":405,"before_size_id":74,"after_size_id":75},{"code_idx":197,"before_size_id":75,"after_size_id":75},{"code_idx":198,"before_size_id":75,"after_size_id":75},{"code_idx":199,"before_size_id":75,"after_size_id":75},{"code_idx":392,"before_size_id":75,"after_size_id":75},{"code_idx":393,"before_size_id":75,"after_size_id":75},{"code_idx":394,"before_size_id":75,"after_size_id":76},{"code_idx":395,"before_size_id":76,"after_size_id":77},{"code_idx":396,"before_size_id":77,"after_size_id":77},{"code_idx":397,"before_size_id":77,"after_size_id":77},{"code_idx":398,"before_size_id":77,"after_size_id":77},{"code_idx":399,"before_size_id":77,"after_size_id":77},{"code_idx":400,"before_size_id":77,"after_size_id":78},{"code_idx":401,"before_size_id":78,"after_size_id":78},{"code_idx":402,"before_size_id":78,"after_size_id":78},{"code_idx":403,"before_size_id":78,"after_size_id":78},{"code_idx":404,"before_size_id":78,"after_size_id":78},{"code_idx":405,"before_size_id":78,"after_size_id":79},{"code_idx":197,"before_size_id":79,"after_size_id":79},{"code_idx":198,"before_size_id":79,"after_size_id":79},{"code_idx":199,"before_size_id":79,"after_size_id":79},{"code_idx":392,"before_size_id":79,"after_size_id":79},{"code_idx":393,"before_size_id":79,"after_size_id":79},{"code_idx":394,"before_size_id":79,"after_size_id":80},{"code_idx":395,"before_size_id":80,"after_size_id":81},{"code_idx":396,"before_size_id":81,"after_size_id":81},{"code_idx":397,"before_size_id":81,"after_size_id":81},{"code_idx":398,"before_size_id":81,"after_size_id":81},{"code_idx":399,"before_size_id":81,"after_size_id":81},{"code_idx":400,"before_size_id":81,"after_size_id":82},{"code_idx":401,"before_size_id":82,"after_size_id":82},{"code_idx":402,"before_size_id":82,"after_size_id":82},{"code_idx":403,"before_size_id":82,"after_size_id":82},{"code_idx":404,"before_size_id":82,"after_size_id":82},{"code_idx":405,"before_size_id":82,"after_size_id":83},{"code_idx":197,"before_size_id":83,"after_size_id":83},{"code_idx":198,"before_size_id":83,"after_size_id":83},{"code_idx":199,"before_size_id":83,"after_size_id":83},{"code_idx":392,"before_size_id":83,"after_size_id":83},{"code_idx":393,"before_size_id":83,"after_size_id":83},{"code_idx":394,"before_size_id":83,"after_size_id":84},{"code_idx":395,"before_size_id":84,"after_size_id":85},{"code_idx":396,"before_size_id":85,"after_size_id":85},{"code_idx":397,"before_size_id":85,"after_size_id":85},{"code_idx":398,"before_size_id":85,"after_size_id":85},{"code_idx":399,"before_size_id":85,"after_size_id":85},{"code_idx":400,"before_size_id":85,"after_size_id":86},{"code_idx":401,"before_size_id":86,"after_size_id":86},{"code_idx":402,"before_size_id":86,"after_size_id":86},{"code_idx":403,"before_size_id":86,"after_size_id":86},{"code_idx":404,"before_size_id":86,"after_size_id":86},{"code_idx":405,"before_size_id":86,"after_size_id":87},{"code_idx":406,"before_size_id":71,"after_size_id":87},{"code_idx":407,"before_size_id":87,"after_size_id":87},{"code_idx":408,"before_size_id":87,"after_size_id":87},{"code_idx":409,"before_size_id":87,"after_size_id":87},{"code_idx":410,"before_size_id":71,"after_size_id":87},{"code_idx":411,"before_size_id":87,"after_size_id":87},{"code_idx":412,"before_size_id":87,"after_size_id":87},{"code_idx":413,"before_size_id":87,"after_size_id":87},{"code_idx":414,"before_size_id":87,"after_size_id":87},{"code_idx":415,"before_size_id":87,"after_size_id":87},{"code_idx":416,"before_size_id":87,"after_size_id":88},{"code_idx":417,"before_size_id":88,"after_size_id":88},{"code_idx":418,"before_size_id":89,"after_size_id":89},{"code_idx":419,"before_size_id":88,"after_size_id":90},{"code_idx":420,"before_size_id":87,"after_size_id":90},{"code_idx":421,"before_size_id":90,"after_size_id":90},{"code_idx":422,"before_size_id":90,"after_size_id":90},{"code_idx":413,"before_size_id":90,"after_size_id":91},{"code_idx":414,"before_size_id":91,"after_size_id":91},{"code_idx":415,"before_size_id":91,"after_size_id":91},{"code_idx":416,"before_size_id":90,"after_size_id":92},{"code_idx":417,"before_size_id":92,"after_size_id":92},{"code_idx":418,"before_size_id":93,"after_size_id":93},{"code_idx":419,"before_size_id":92,"after_size_id":94},{"code_idx":423,"before_size_id":90,"after_size_id":94},{"code_idx":424,"before_size_id":94,"after_size_id":95},{"code_idx":425,"before_size_id":95,"after_size_id":95},{"code_idx":426,"before_size_id":95,"after_size_id":95},{"code_idx":427,"before_size_id":94,"after_size_id":96},{"code_idx":428,"before_size_id":96,"after_size_id":97},{"code_idx":429,"before_size_id":97,"after_size_id":97},{"code_idx":430,"before_size_id":97,"after_size_id":97},{"code_idx":431,"before_size_id":97,"after_size_id":97},{"code_idx":432,"before_size_id":97,"after_size_id":98},{"code_idx":433,"before_size_id":98,"after_size_id":98},{"code_idx":434,"before_size_id":98,"after_size_id":98},{"code_idx":435,"before_size_id":98,"after_size_id":98},{"code_idx":436,"before_size_id":98,"after_size_id":99},{"code_idx":197,"before_size_id":99,"after_size_id":99},{"code_idx":198,"before_size_id":99,"after_size_id":99},{"code_idx":199,"before_size_id":99,"after_size_id":99},{"code_idx":371,"before_size_id":99,"after_size_id":99},{"code_idx":372,"before_size_id":99,"after_size_id":99},{"code_idx":373,"before_size_id":99,"after_size_id":100},{"code_idx":374,"before_size_id":100,"after_size_id":100},{"code_idx":375,"before_size_id":100,"after_size_id":100},{"code_idx":376,"before_size_id":99,"after_size_id":101},{"code_idx":377,"before_size_id":101,"after_size_id":101},{"code_idx":378,"before_size_id":101,"after_size_id":102},{"code_idx":379,"before_size_id":102,"after_size_id":102},{"code_idx":380,"before_size_id":101,"after_size_id":103},{"code_idx":381,"before_size_id":103,"after_size_id":104},{"code_idx":382,"before_size_id":104,"after_size_id":104},{"code_idx":383,"before_size_id":104,"after_size_id":104},{"code_idx":384,"before_size_id":103,"after_size_id":105},{"code_idx":385,"before_size_id":105,"after_size_id":105},{"code_idx":386,"before_size_id":105,"after_size_id":106},{"code_idx":387,"before_size_id":106,"after_size_id":106},{"code_idx":388,"before_size_id":106,"after_size_id":106},{"code_idx":389,"before_size_id":106,"after_size_id":106},{"code_idx":390,"before_size_id":106,"after_size_id":106},{"code_idx":391,"before_size_id":106,"after_size_id":106},{"code_idx":184,"before_size_id":106,"after_size_id":106},{"code_idx":185,"before_size_id":106,"after_size_id":106},{"code_idx":186,"before_size_id":106,"after_size_id":106},{"code_idx":187,"before_size_id":106,"after_size_id":106},{"code_idx":188,"before_size_id":106,"after_size_id":106},{"code_idx":189,"before_size_id":106,"after_size_id":106},{"code_idx":190,"before_size_id":106,"after_size_id":106},{"code_idx":191,"before_size_id":106,"after_size_id":106},{"code_idx":192,"before_size_id":106,"after_size_id":106},{"code_idx":193,"before_size_id":106,"after_size_id":106},{"code_idx":194,"before_size_id":106,"after_size_id":106},{"code_idx":195,"before_size_id":106,"after_size_id":106},{"code_idx":196,"before_size_id":106,"after_size_id":106},{"code_idx":197,"before_size_id":106,"after_size_id":106},{"code_idx":198,"before_size_id":106,"after_size_id":106},{"code_idx":199,"before_size_id":106,"after_size_id":106},{"code_idx":392,"before_size_id":106,"after_size_id":106},{"code_idx":393,"before_size_id":106,"after_size_id":106},{"code_idx":394,"before_size_id":106,"after_size_id":107},{"code_idx":395,"before_size_id":107,"after_size_id":108},{"code_idx":396,"before_size_id":108,"after_size_id":108},{"code_idx":397,"before_size_id":108,"after_size_id":108},{"code_idx":398,"before_size_id":108,"after_size_id":108},{"code_idx":399,"before_size_id":108,"after_size_id":108},{"code_idx":400,"before_size_id":108,"after_size_id":109},{"code_idx":401,"before_size_id":109,"after_size_id":109},{"code_idx":402,"before_size_id":109,"after_size_id":109},{"code_idx":403,"before_size_id":109,"after_size_id":109},{"code_idx":404,"before_size_id":109,"after_size_id":109},{"code_idx":405,"before_size_id":109,"after_size_id":110},{"code_idx":197,"before_size_id":110,"after_size_id":110},{"code_idx":198,"before_size_id":110,"after_size_id":110},{"code_idx":199,"before_size_id":110,"after_size_id":110},{"code_idx":392,"before_size_id":110,"after_size_id":110},{"code_idx":393,"before_size_id":110,"after_size_id":110},{"code_idx":394,"before_size_id":110,"after_size_id":111},{"code_idx":395,"before_size_id":111,"after_size_id":112},{"code_idx":396,"before_size_id":112,"after_size_id":112},{"code_idx":397,"before_size_id":112,"after_size_id":112},{"code_idx":398,"before_size_id":112,"after_size_id":112},{"code_idx":399,"before_size_id":112,"after_size_id":112},{"code_idx":400,"before_size_id":112,"after_size_id":113},{"code_idx":401,"before_size_id":113,"after_size_id":113},{"code_idx":402,"before_size_id":113,"after_size_id":113},{"code_idx":403,"before_size_id":113,"after_size_id":113},{"code_idx":404,"before_size_id":113,"after_size_id":113},{"code_idx":405,"before_size_id":113,"after_size_id":114},{"code_idx":197,"before_size_id":114,"after_size_id":114},{"code_idx":198,"before_size_id":114,"after_size_id":114},{"code_idx":199,"before_size_id":114,"after_size_id":114},{"code_idx":392,"before_size_id":114,"after_size_id":114},{"code_idx":393,"before_size_id":114,"after_size_id":114},{"code_idx":394,"before_size_id":114,"after_size_id":115},{"code_idx":395,"before_size_id":115,"after_size_id":116},{"code_idx":396,"before_size_id":116,"after_size_id":116},{"code_idx":397,"before_size_id":116,"after_size_id":116},{"code_idx":398,"before_size_id":116,"after_size_id":116},{"code_idx":399,"before_size_id":116,"after_size_id":116},{"code_idx":400,"before_size_id":116,"after_size_id":117},{"code_idx":401,"before_size_id":117,"after_size_id":117},{"code_idx":402,"before_size_id":117,"after_size_id":117},{"code_idx":403,"before_size_id":117,"after_size_id":117},{"code_idx":404,"before_size_id":117,"after_size_id":117},{"code_idx":405,"before_size_id":117,"after_size_id":118},{"code_idx":197,"before_size_id":118,"after_size_id":118},{"code_idx":198,"before_size_id":118,"after_size_id":118},{"code_idx":199,"before_size_id":118,"after_size_id":118},{"code_idx":392,"before_size_id":118,"after_size_id":118},{"code_idx":393,"before_size_id":118,"after_size_id":118},{"code_idx":394,"before_size_id":118,"after_size_id":119},{"code_idx":395,"before_size_id":119,"after_size_id":120},{"code_idx":396,"before_size_id":120,"after_size_id":120},{"code_idx":397,"before_size_id":120,"after_size_id":120},{"code_idx":398,"before_size_id":120,"after_size_id":120},{"code_idx":399,"before_size_id":120,"after_size_id":120},{"code_idx":400,"before_size_id":120,"after_size_id":121},{"code_idx":401,"before_size_id":121,"after_size_id":121},{"code_idx":402,"before_size_id":121,"after_size_id":121},{"code_idx":403,"before_size_id":121,"after_size_id":121},{"code_idx":404,"before_size_id":121,"after_size_id":121},{"code_idx":405,"before_size_id":121,"after_size_id":122},{"code_idx":406,"before_size_id":106,"after_size_id":122},{"code_idx":407,"before_size_id":122,"after_size_id":122},{"code_idx":408,"before_size_id":122,"after_size_id":122},{"code_idx":409,"before_size_id":122,"after_size_id":122},{"code_idx":410,"before_size_id":106,"after_size_id":122},{"code_idx":411,"before_size_id":122,"after_size_id":122},{"code_idx":412,"before_size_id":122,"after_size_id":122},{"code_idx":413,"before_size_id":122,"after_size_id":122},{"code_idx":414,"before_size_id":122,"after_size_id":122},{"code_idx":415,"before_size_id":122,"after_size_id":122},{"code_idx":416,"before_size_id":122,"after_size_id":123},{"code_idx":417,"before_size_id":123,"after_size_id":123},{"code_idx":418,"before_size_id":124,"after_size_id":124},{"code_idx":419,"before_size_id":123,"after_size_id":125},{"code_idx":420,"before_size_id":122,"after_size_id":125},{"code_idx":421,"before_size_id":125,"after_size_id":125},{"code_idx":422,"before_size_id":125,"after_size_id":125},{"code_idx":413,"before_size_id":125,"after_size_id":126},{"code_idx":414,"before_size_id":126,"after_size_id":126},{"code_idx":415,"before_size_id":126,"after_size_id":126},{"code_idx":416,"before_size_id":125,"after_size_id":127},{"code_idx":417,"before_size_id":127,"after_size_id":127},{"code_idx":418,"before_size_id":128,"after_size_id":128},{"code_idx":419,"before_size_id":127,"after_size_id":129},{"code_idx":423,"before_size_id":125,"after_size_id":129},{"code_idx":424,"before_size_id":129,"after_size_id":130},{"code_idx":425,"before_size_id":130,"after_size_id":130},{"code_idx":426,"before_size_id":130,"after_size_id":130},{"code_idx":427,"before_size_id":129,"after_size_id":131},{"code_idx":428,"before_size_id":131,"after_size_id":132},{"code_idx":429,"before_size_id":132,"after_size_id":132},{"code_idx":430,"before_size_id":132,"after_size_id":132},{"code_idx":431,"before_size_id":132,"after_size_id":132},{"code_idx":432,"before_size_id":132,"after_size_id":133},{"code_idx":433,"before_size_id":133,"after_size_id":133},{"code_idx":434,"before_size_id":133,"after_size_id":133},{"code_idx":435,"before_size_id":133,"after_size_id":133},{"code_idx":436,"before_size_id":133,"after_size_id":134},{"code_idx":197,"before_size_id":134,"after_size_id":134},{"code_idx":198,"before_size_id":134,"after_size_id":134},{"code_idx":199,"before_size_id":134,"after_size_id":134},{"code_idx":371,"before_size_id":134,"after_size_id":134},{"code_idx":372,"before_size_id":134,"after_size_id":134},{"code_idx":373,"before_size_id":134,"after_size_id":135},{"code_idx":374,"before_size_id":135,"after_size_id":135},{"code_idx":375,"before_size_id":135,"after_size_id":135},{"code_idx":376,"before_size_id":134,"after_size_id":136},{"code_idx":377,"before_size_id":136,"after_size_id":136},{"code_idx":378,"before_size_id":136,"after_size_id":137},{"code_idx":379,"before_size_id":137,"after_size_id":137},{"code_idx":380,"before_size_id":136,"after_size_id":138},{"code_idx":381,"before_size_id":138,"after_size_id":139},{"code_idx":382,"before_size_id":139,"after_size_id":139},{"code_idx":383,"before_size_id":139,"after_size_id":139},{"code_idx":384,"before_size_id":138,"after_size_id":140},{"code_idx":385,"before_size_id":140,"after_size_id":140},{"code_idx":386,"before_size_id":140,"after_size_id":141},{"code_idx":387,"before_size_id":141,"after_size_id":141},{"code_idx":388,"before_size_id":141,"after_size_id":141},{"code_idx":389,"before_size_id":141,"after_size_id":141},{"code_idx":390,"before_size_id":141,"after_size_id":141},{"code_idx":391,"before_size_id":141,"after_size_id":141},{"code_idx":184,"before_size_id":141,"after_size_id":141},{"code_idx":185,"before_size_id":141,"after_size_id":141},{"code_idx":186,"before_size_id":141,"after_size_id":141},{"code_idx":187,"before_size_id":141,"after_size_id":141},{"code_idx":188,"before_size_id":141,"after_size_id":141},{"code_idx":189,"before_size_id":141,"after_size_id":141},{"code_idx":190,"before_size_id":141,"after_size_id":141},{"code_idx":191,"before_size_id":141,"after_size_id":141},{"code_idx":192,"before_size_id":141,"after_size_id":141},{"code_idx":193,"before_size_id":141,"after_size_id":141},{"code_idx":194,"before_size_id":141,"after_size_id":141},{"code_idx":195,"before_size_id":141,"after_size_id":141},{"code_idx":196,"before_size_id":141,"after_size_id":141},{"code_idx":197,"before_size_id":141,"after_size_id":141},{"code_idx":198,"before_size_id":141,"after_size_id":141},{"code_idx":199,"before_size_id":141,"after_size_id":141},{"code_idx":392,"before_size_id":141,"after_size_id":141},{"code_idx":393,"before_size_id":141,"after_size_id":141},{"code_idx":394,"before_size_id":141,"after_size_id":142},{"code_idx":395,"before_size_id":142,"after_size_id":143},{"code_idx":396,"before_size_id":143,"after_size_id":143},{"code_idx":397,"before_size_id":143,"after_size_id":143},{"code_idx":398,"before_size_id":143,"after_size_id":143},{"code_idx":399,"before_size_id":143,"after_size_id":143},{"code_idx":400,"before_size_id":143,"after_size_id":144},{"code_idx":401,"before_size_id":144,"after_size_id":144},{"code_idx":402,"before_size_id":144,"after_size_id":144},{"code_idx":403,"before_size_id":144,"after_size_id":144},{"code_idx":404,"before_size_id":144,"after_size_id":144},{"code_idx":405,"before_size_id":144,"after_size_id":145},{"code_idx":197,"before_size_id":145,"after_size_id":145},{"code_idx":198,"before_size_id":145,"after_size_id":145},{"code_idx":199,"before_size_id":145,"after_size_id":145},{"code_idx":392,"before_size_id":145,"after_size_id":145},{"code_idx":393,"before_size_id":145,"after_size_id":145},{"code_idx":394,"before_size_id":145,"after_size_id":146},{"code_idx":395,"before_size_id":146,"after_size_id":147},{"code_idx":396,"before_size_id":147,"after_size_id":147},{"code_idx":397,"before_size_id":147,"after_size_id":147},{"code_idx":398,"before_size_id":147,"after_size_id":147},{"code_idx":399,"before_size_id":147,"after_size_id":147},{"code_idx":400,"before_size_id":147,"after_size_id":148},{"code_idx":401,"before_size_id":148,"after_size_id":148},{"code_idx":402,"before_size_id":148,"after_size_id":148},{"code_idx":403,"before_size_id":148,"after_size_id":148},{"code_idx":404,"before_size_id":148,"after_size_id":148},{"code_idx":405,"before_size_id":148,"after_size_id":149},{"code_idx":197,"before_size_id":149,"after_size_id":149},{"code_idx":198,"before_size_id":149,"after_size_id":149},{"code_idx":199,"before_size_id":149,"after_size_id":149},{"code_idx":392,"before_size_id":149,"after_size_id":149},{"code_idx":393,"before_size_id":149,"after_size_id":149},{"code_idx":394,"before_size_id":149,"after_size_id":150},{"code_idx":395,"before_size_id":150,"after_size_id":151},{"code_idx":396,"before_size_id":151,"after_size_id":151},{"code_idx":397,"before_size_id":151,"after_size_id":151},{"code_idx":398,"before_size_id":151,"after_size_id":151},{"code_idx":399,"before_size_id":151,"after_size_id":151},{"code_idx":400,"before_size_id":151,"after_size_id":152},{"code_idx":401,"before_size_id":152,"after_size_id":152},{"code_idx":402,"before_size_id":152,"after_size_id":152},{"code_idx":403,"before_size_id":152,"after_size_id":152},{"code_idx":404,"before_size_id":152,"after_size_id":152},{"code_idx":405,"before_size_id":152,"after_size_id":153},{"code_idx":197,"before_size_id":153,"after_size_id":153},{"code_idx":198,"before_size_id":153,"after_size_id":153},{"code_idx":199,"before_size_id":153,"after_size_id":153},{"code_idx":392,"before_size_id":153,"after_size_id":153},{"code_idx":393,"before_size_id":153,"after_size_id":153},{"code_idx":394,"before_size_id":153,"after_size_id":154},{"code_idx":395,"before_size_id":154,"after_size_id":155},{"code_idx":396,"before_size_id":155,"after_size_id":155},{"code_idx":397,"before_size_id":155,"after_size_id":155},{"code_idx":398,"before_size_id":155,"after_size_id":155},{"code_idx":399,"before_size_id":155,"after_size_id":155},{"code_idx":400,"before_size_id":155,"after_size_id":156},{"code_idx":401,"before_size_id":156,"after_size_id":156},{"code_idx":402,"before_size_id":156,"after_size_id":156},{"code_idx":403,"before_size_id":156,"after_size_id":156},{"code_idx":404,"before_size_id":156,"after_size_id":156},{"code_idx":405,"before_size_id":156,"after_size_id":157},{"code_idx":406,"before_size_id":141,"after_size_id":157},{"code_idx":407,"before_size_id":157,"after_size_id":157},{"code_idx":408,"before_size_id":157,"after_size_id":157},{"code_idx":409,"before_size_id":157,"after_size_id":157},{"code_idx":410,"before_size_id":141,"after_size_id":157},{"code_idx":411,"before_size_id":157,"after_size_id":157},{"code_idx":412,"before_size_id":157,"after_size_id":157},{"code_idx":413,"before_size_id":157,"after_size_id":157},{"code_idx":414,"before_size_id":157,"after_size_id":157},{"code_idx":415,"before_size_id":157,"after_size_id":157},{"code_idx":416,"before_size_id":157,"after_size_id":158},{"code_idx":417,"before_size_id":158,"after_size_id":158},{"code_idx":418,"before_size_id":159,"after_size_id":159},{"code_idx":419,"before_size_id":158,"after_size_id":160},{"code_idx":420,"before_size_id":157,"after_size_id":160},{"code_idx":421,"before_size_id":160,"after_size_id":160},{"code_idx":422,"before_size_id":160,"after_size_id":160},{"code_idx":413,"before_size_id":160,"after_size_id":161},{"code_idx":414,"before_size_id":161,"after_size_id":161},{"code_idx":415,"before_size_id":161,"after_size_id":161},{"code_idx":416,"before_size_id":160,"after_size_id":162},{"code_idx":417,"before_size_id":162,"after_size_id":162},{"code_idx":418,"before_size_id":163,"after_size_id":163},{"code_idx":419,"before_size_id":162,"after_size_id":164},{"code_idx":423,"before_size_id":160,"after_size_id":164},{"code_idx":424,"before_size_id":164,"after_size_id":165},{"code_idx":425,"before_size_id":165,"after_size_id":165},{"code_idx":426,"before_size_id":165,"after_size_id":165},{"code_idx":427,"before_size_id":164,"after_size_id":166},{"code_idx":428,"before_size_id":166,"after_size_id":167},{"code_idx":429,"before_size_id":167,"after_size_id":167},{"code_idx":430,"before_size_id":167,"after_size_id":167},{"code_idx":431,"before_size_id":167,"after_size_id":167},{"code_idx":432,"before_size_id":167,"after_size_id":168},{"code_idx":433,"before_size_id":168,"after_size_id":168},{"code_idx":434,"before_size_id":168,"after_size_id":168},{"code_idx":435,"before_size_id":168,"after_size_id":168},{"code_idx":436,"before_size_id":168,"after_size_id":169},{"code_idx":437,"before_size_id":29,"after_size_id":169},{"code_idx":438,"before_size_id":169,"after_size_id":169},{"code_idx":439,"before_size_id":169,"after_size_id":169},{"code_idx":440,"before_size_id":169,"after_size_id":170},{"code_idx":441,"before_size_id":170,"after_size_id":171},{"code_idx":442,"before_size_id":171,"after_size_id":171},{"code_idx":443,"before_size_id":171,"after_size_id":171},{"code_idx":444,"before_size_id":170,"after_size_id":172},{"code_idx":445,"before_size_id":172,"after_size_id":172},{"code_idx":446,"before_size_id":172,"after_size_id":173},{"code_idx":447,"before_size_id":173,"after_size_id":173},{"code_idx":448,"before_size_id":173,"after_size_id":173},{"code_idx":449,"before_size_id":173,"after_size_id":174},{"code_idx":450,"before_size_id":173,"after_size_id":174},{"code_idx":451,"before_size_id":172,"after_size_id":175},{"code_idx":452,"before_size_id":175,"after_size_id":176},{"code_idx":453,"before_size_id":176,"after_size_id":176},{"code_idx":454,"before_size_id":176,"after_size_id":176},{"code_idx":455,"before_size_id":175,"after_size_id":177},{"code_idx":456,"before_size_id":177,"after_size_id":178},{"code_idx":457,"before_size_id":178,"after_size_id":178},{"code_idx":458,"before_size_id":178,"after_size_id":178},{"code_idx":459,"before_size_id":178,"after_size_id":178},{"code_idx":390,"before_size_id":178,"after_size_id":178},{"code_idx":391,"before_size_id":178,"after_size_id":178},{"code_idx":184,"before_size_id":178,"after_size_id":178},{"code_idx":185,"before_size_id":178,"after_size_id":178},{"code_idx":186,"before_size_id":178,"after_size_id":178},{"code_idx":187,"before_size_id":178,"after_size_id":178},{"code_idx":188,"before_size_id":178,"after_size_id":178},{"code_idx":189,"before_size_id":178,"after_size_id":178},{"code_idx":190,"before_size_id":178,"after_size_id":178},{"code_idx":191,"before_size_id":178,"after_size_id":178},{"code_idx":192,"before_size_id":178,"after_size_id":178},{"code_idx":193,"before_size_id":178,"after_size_id":178},{"code_idx":194,"before_size_id":178,"after_size_id":178},{"code_idx":195,"before_size_id":178,"after_size_id":178},{"code_idx":196,"before_size_id":178,"after_size_id":178},{"code_idx":197,"before_size_id":178,"after_size_id":178},{"code_idx":198,"before_size_id":178,"after_size_id":178},{"code_idx":199,"before_size_id":178,"after_size_id":178},{"code_idx":392,"before_size_id":178,"after_size_id":178},{"code_idx":393,"before_size_id":178,"after_size_id":178},{"code_idx":394,"before_size_id":178,"after_size_id":179},{"code_idx":395,"before_size_id":179,"after_size_id":180},{"code_idx":396,"before_size_id":180,"after_size_id":180},{"code_idx":397,"before_size_id":180,"after_size_id":180},{"code_idx":398,"before_size_id":180,"after_size_id":180},{"code_idx":399,"before_size_id":180,"after_size_id":180},{"code_idx":400,"before_size_id":180,"after_size_id":181},{"code_idx":401,"before_size_id":181,"after_size_id":181},{"code_idx":402,"before_size_id":181,"after_size_id":181},{"code_idx":403,"before_size_id":181,"after_size_id":181},{"code_idx":404,"before_size_id":181,"after_size_id":181},{"code_idx":405,"before_size_id":181,"after_size_id":182},{"code_idx":197,"before_size_id":182,"after_size_id":182},{"code_idx":198,"before_size_id":182,"after_size_id":182},{"code_idx":199,"before_size_id":182,"after_size_id":182},{"code_idx":392,"before_size_id":182,"after_size_id":182},{"code_idx":393,"before_size_id":182,"after_size_id":182},{"code_idx":394,"before_size_id":182,"after_size_id":183},{"code_idx":395,"before_size_id":183,"after_size_id":184},{"code_idx":396,"before_size_id":184,"after_size_id":184},{"code_idx":397,"before_size_id":184,"after_size_id":184},{"code_idx":398,"before_size_id":184,"after_size_id":184},{"code_idx":399,"before_size_id":184,"after_size_id":184},{"code_idx":400,"before_size_id":184,"after_size_id":185},{"code_idx":401,"before_size_id":185,"after_size_id":185},{"code_idx":402,"before_size_id":185,"after_size_id":185},{"code_idx":403,"before_size_id":185,"after_size_id":185},{"code_idx":404,"before_size_id":185,"after_size_id":185},{"code_idx":405,"before_size_id":185,"after_size_id":186},{"code_idx":197,"before_size_id":186,"after_size_id":186},{"code_idx":198,"before_size_id":186,"after_size_id":186},{"code_idx":199,"before_size_id":186,"after_size_id":186},{"code_idx":392,"before_size_id":186,"after_size_id":186},{"code_idx":393,"before_size_id":186,"after_size_id":186},{"code_idx":394,"before_size_id":186,"after_size_id":187},{"code_idx":395,"before_size_id":187,"after_size_id":188},{"code_idx":396,"before_size_id":188,"after_size_id":188},{"code_idx":397,"before_size_id":188,"after_size_id":188},{"code_idx":398,"before_size_id":188,"after_size_id":188},{"code_idx":399,"before_size_id":188,"after_size_id":188},{"code_idx":400,"before_size_id":188,"after_size_id":189},{"code_idx":401,"before_size_id":189,"after_size_id":189},{"code_idx":402,"before_size_id":189,"after_size_id":189},{"code_idx":403,"before_size_id":189,"after_size_id":189},{"code_idx":404,"before_size_id":189,"after_size_id":189},{"code_idx":405,"before_size_id":189,"after_size_id":190},{"code_idx":197,"before_size_id":190,"after_size_id":190},{"code_idx":198,"before_size_id":190,"after_size_id":190},{"code_idx":199,"before_size_id":190,"after_size_id":190},{"code_idx":392,"before_size_id":190,"after_size_id":190},{"code_idx":393,"before_size_id":190,"after_size_id":190},{"code_idx":394,"before_size_id":190,"after_size_id":191},{"code_idx":395,"before_size_id":191,"after_size_id":192},{"code_idx":396,"before_size_id":192,"after_size_id":192},{"code_idx":397,"before_size_id":192,"after_size_id":192},{"code_idx":398,"before_size_id":192,"after_size_id":192},{"code_idx":399,"before_size_id":192,"after_size_id":192},{"code_idx":400,"before_size_id":192,"after_size_id":193},{"code_idx":401,"before_size_id":193,"after_size_id":193},{"code_idx":402,"before_size_id":193,"after_size_id":193},{"code_idx":403,"before_size_id":193,"after_size_id":193},{"code_idx":404,"before_size_id":193,"after_size_id":193},{"code_idx":405,"before_size_id":193,"after_size_id":194},{"code_idx":406,"before_size_id":178,"after_size_id":194},{"code_idx":407,"before_size_id":194,"after_size_id":194},{"code_idx":408,"before_size_id":194,"after_size_id":194},{"code_idx":409,"before_size_id":194,"after_size_id":194},{"code_idx":460,"before_size_id":178,"after_size_id":194},{"code_idx":461,"before_size_id":194,"after_size_id":194},{"code_idx":462,"before_size_id":194,"after_size_id":194},{"code_idx":413,"before_size_id":194,"after_size_id":195},{"code_idx":414,"before_size_id":195,"after_size_id":195},{"code_idx":415,"before_size_id":195,"after_size_id":195},{"code_idx":416,"before_size_id":194,"after_size_id":196},{"code_idx":417,"before_size_id":196,"after_size_id":196},{"code_idx":418,"before_size_id":197,"after_size_id":197},{"code_idx":419,"before_size_id":196,"after_size_id":198},{"code_idx":463,"before_size_id":194,"after_size_id":198},{"code_idx":464,"before_size_id":198,"after_size_id":198},{"code_idx":465,"before_size_id":198,"after_size_id":198},{"code_idx":413,"before_size_id":198,"after_size_id":199},{"code_idx":414,"before_size_id":199,"after_size_id":199},{"code_idx":415,"before_size_id":199,"after_size_id":199},{"code_idx":416,"before_size_id":198,"after_size_id":200},{"code_idx":417,"before_size_id":200,"after_size_id":200},{"code_idx":418,"before_size_id":201,"after_size_id":201},{"code_idx":419,"before_size_id":200,"after_size_id":202},{"code_idx":466,"before_size_id":198,"after_size_id":202},{"code_idx":467,"before_size_id":202,"after_size_id":203},{"code_idx":468,"before_size_id":203,"after_size_id":203},{"code_idx":469,"before_size_id":203,"after_size_id":203},{"code_idx":470,"before_size_id":203,"after_size_id":203},{"code_idx":471,"before_size_id":203,"after_size_id":204},{"code_idx":472,"before_size_id":204,"after_size_id":204},{"code_idx":473,"before_size_id":204,"after_size_id":204},{"code_idx":474,"before_size_id":204,"after_size_id":204},{"code_idx":475,"before_size_id":204,"after_size_id":204},{"code_idx":476,"before_size_id":204,"after_size_id":204},{"code_idx":477,"before_size_id":204,"after_size_id":204},{"code_idx":478,"before_size_id":204,"after_size_id":204},{"code_idx":479,"before_size_id":204,"after_size_id":205},{"code_idx":480,"before_size_id":205,"after_size_id":205},{"code_idx":481,"before_size_id":205,"after_size_id":206},{"code_idx":482,"before_size_id":206,"after_size_id":206},{"code_idx":483,"before_size_id":206,"after_size_id":207},{"code_idx":484,"before_size_id":206,"after_size_id":208},{"code_idx":485,"before_size_id":205,"after_size_id":208},{"code_idx":486,"before_size_id":208,"after_size_id":209},{"code_idx":487,"before_size_id":209,"after_size_id":209},{"code_idx":488,"before_size_id":209,"after_size_id":209},{"code_idx":489,"before_size_id":209,"after_size_id":209},{"code_idx":490,"before_size_id":209,"after_size_id":209},{"code_idx":491,"before_size_id":209,"after_size_id":209},{"code_idx":492,"before_size_id":209,"after_size_id":209},{"code_idx":493,"before_size_id":209,"after_size_id":209},{"code_idx":494,"before_size_id":209,"after_size_id":210},{"code_idx":495,"before_size_id":210,"after_size_id":211},{"code_idx":496,"before_size_id":211,"after_size_id":212},{"code_idx":497,"before_size_id":212,"after_size_id":213},{"code_idx":498,"before_size_id":213,"after_size_id":213},{"code_idx":499,"before_size_id":213,"after_size_id":213},{"code_idx":500,"before_size_id":212,"after_size_id":214},{"code_idx":501,"before_size_id":214,"after_size_id":215},{"code_idx":502,"before_size_id":215,"after_size_id":216},{"code_idx":503,"before_size_id":217,"after_size_id":217},{"code_idx":504,"before_size_id":218,"after_size_id":218},{"code_idx":505,"before_size_id":216,"after_size_id":219},{"code_idx":506,"before_size_id":219,"after_size_id":219},{"code_idx":507,"before_size_id":219,"after_size_id":219},{"code_idx":508,"before_size_id":219,"after_size_id":220},{"code_idx":509,"before_size_id":219,"after_size_id":221},{"code_idx":510,"before_size_id":221,"after_size_id":221},{"code_idx":511,"before_size_id":221,"after_size_id":221},{"code_idx":512,"before_size_id":221,"after_size_id":221},{"code_idx":513,"before_size_id":221,"after_size_id":222},{"code_idx":514,"before_size_id":222,"after_size_id":223},{"code_idx":515,"before_size_id":223,"after_size_id":223},{"code_idx":516,"before_size_id":223,"after_size_id":223},{"code_idx":517,"before_size_id":223,"after_size_id":223},{"code_idx":518,"before_size_id":223,"after_size_id":223},{"code_idx":519,"before_size_id":223,"after_size_id":223},{"code_idx":520,"before_size_id":223,"after_size_id":223},{"code_idx":521,"before_size_id":223,"after_size_id":223},{"code_idx":522,"before_size_id":223,"after_size_id":223},{"code_idx":523,"before_size_id":223,"after_size_id":223},{"code_idx":524,"before_size_id":223,"after_size_id":223},{"code_idx":525,"before_size_id":222,"after_size_id":224},{"code_idx":526,"before_size_id":224,"after_size_id":225},{"code_idx":527,"before_size_id":225,"after_size_id":225},{"code_idx":528,"before_size_id":225,"after_size_id":225},{"code_idx":529,"before_size_id":224,"after_size_id":226},{"code_idx":530,"before_size_id":226,"after_size_id":226},{"code_idx":531,"before_size_id":226,"after_size_id":226},{"code_idx":532,"before_size_id":226,"after_size_id":226},{"code_idx":533,"before_size_id":226,"after_size_id":226},{"code_idx":534,"before_size_id":226,"after_size_id":226},{"code_idx":535,"before_size_id":226,"after_size_id":226},{"code_idx":536,"before_size_id":226,"after_size_id":226},{"code_idx":537,"before_size_id":226,"after_size_id":226},{"code_idx":184,"before_size_id":226,"after_size_id":226},{"code_idx":185,"before_size_id":226,"after_size_id":226},{"code_idx":186,"before_size_id":226,"after_size_id":226},{"code_idx":187,"before_size_id":226,"after_size_id":226},{"code_idx":188,"before_size_id":226,"after_size_id":226},{"code_idx":189,"before_size_id":226,"after_size_id":226},{"code_idx":190,"before_size_id":226,"after_size_id":226},{"code_idx":191,"before_size_id":226,"after_size_id":226},{"code_idx":192,"before_size_id":226,"after_size_id":226},{"code_idx":193,"before_size_id":226,"after_size_id":226},{"code_idx":194,"before_size_id":226,"after_size_id":226},{"code_idx":195,"before_size_id":226,"after_size_id":226},{"code_idx":196,"before_size_id":226,"after_size_id":226},{"code_idx":197,"before_size_id":226,"after_size_id":226},{"code_idx":198,"before_size_id":226,"after_size_id":226},{"code_idx":199,"before_size_id":226,"after_size_id":226},{"code_idx":538,"before_size_id":226,"after_size_id":226},{"code_idx":539,"before_size_id":226,"after_size_id":226},{"code_idx":540,"before_size_id":226,"after_size_id":226},{"code_idx":541,"before_size_id":226,"after_size_id":226},{"code_idx":542,"before_size_id":226,"after_size_id":226},{"code_idx":543,"before_size_id":226,"after_size_id":227},{"code_idx":544,"before_size_id":227,"after_size_id":227},{"code_idx":545,"before_size_id":227,"after_size_id":227},{"code_idx":546,"before_size_id":227,"after_size_id":227},{"code_idx":547,"before_size_id":227,"after_size_id":227},{"code_idx":548,"before_size_id":227,"after_size_id":228},{"code_idx":549,"before_size_id":228,"after_size_id":229},{"code_idx":550,"before_size_id":229,"after_size_id":229},{"code_idx":551,"before_size_id":229,"after_size_id":230},{"code_idx":552,"before_size_id":230,"after_size_id":230},{"code_idx":553,"before_size_id":230,"after_size_id":231},{"code_idx":554,"before_size_id":231,"after_size_id":231},{"code_idx":483,"before_size_id":231,"after_size_id":232},{"code_idx":484,"before_size_id":231,"after_size_id":233},{"code_idx":555,"before_size_id":230,"after_size_id":233},{"code_idx":556,"before_size_id":233,"after_size_id":233},{"code_idx":557,"before_size_id":233,"after_size_id":233},{"code_idx":558,"before_size_id":233,"after_size_id":233},{"code_idx":559,"before_size_id":233,"after_size_id":234},{"code_idx":560,"before_size_id":234,"after_size_id":234},{"code_idx":561,"before_size_id":234,"after_size_id":234},{"code_idx":413,"before_size_id":234,"after_size_id":235},{"code_idx":414,"before_size_id":235,"after_size_id":235},{"code_idx":415,"before_size_id":235,"after_size_id":235},{"code_idx":416,"before_size_id":234,"after_size_id":236},{"code_idx":417,"before_size_id":236,"after_size_id":236},{"code_idx":418,"before_size_id":237,"after_size_id":237},{"code_idx":419,"before_size_id":236,"after_size_id":238},{"code_idx":562,"before_size_id":234,"after_size_id":238},{"code_idx":563,"before_size_id":238,"after_size_id":238},{"code_idx":564,"before_size_id":238,"after_size_id":238},{"code_idx":565,"before_size_id":238,"after_size_id":238},{"code_idx":566,"before_size_id":238,"after_size_id":238},{"code_idx":567,"before_size_id":239,"after_size_id":240},{"code_idx":568,"before_size_id":240,"after_size_id":240},{"code_idx":569,"before_size_id":240,"after_size_id":240},{"code_idx":570,"before_size_id":239,"after_size_id":240},{"code_idx":571,"before_size_id":239,"after_size_id":241},{"code_idx":572,"before_size_id":238,"after_size_id":241},{"code_idx":573,"before_size_id":241,"after_size_id":241},{"code_idx":574,"before_size_id":241,"after_size_id":241},{"code_idx":575,"before_size_id":241,"after_size_id":241},{"code_idx":576,"before_size_id":241,"after_size_id":242},{"code_idx":577,"before_size_id":242,"after_size_id":242},{"code_idx":578,"before_size_id":242,"after_size_id":242},{"code_idx":579,"before_size_id":242,"after_size_id":243},{"code_idx":580,"before_size_id":243,"after_size_id":243},{"code_idx":581,"before_size_id":243,"after_size_id":243},{"code_idx":582,"before_size_id":243,"after_size_id":243},{"code_idx":583,"before_size_id":243,"after_size_id":243},{"code_idx":584,"before_size_id":243,"after_size_id":243},{"code_idx":585,"before_size_id":243,"after_size_id":244},{"code_idx":586,"before_size_id":244,"after_size_id":244},{"code_idx":587,"before_size_id":244,"after_size_id":244},{"code_idx":588,"before_size_id":244,"after_size_id":244},{"code_idx":589,"before_size_id":244,"after_size_id":244},{"code_idx":590,"before_size_id":244,"after_size_id":244},{"code_idx":591,"before_size_id":244,"after_size_id":245},{"code_idx":592,"before_size_id":245,"after_size_id":245},{"code_idx":593,"before_size_id":245,"after_size_id":245},{"code_idx":594,"before_size_id":245,"after_size_id":245},{"code_idx":595,"before_size_id":245,"after_size_id":245},{"code_idx":596,"before_size_id":245,"after_size_id":245},{"code_idx":597,"before_size_id":245,"after_size_id":246},{"code_idx":598,"before_size_id":245,"after_size_id":247},{"code_idx":197,"before_size_id":247,"after_size_id":247},{"code_idx":198,"before_size_id":247,"after_size_id":247},{"code_idx":199,"before_size_id":247,"after_size_id":247},{"code_idx":538,"before_size_id":247,"after_size_id":247},{"code_idx":539,"before_size_id":247,"after_size_id":247},{"code_idx":540,"before_size_id":247,"after_size_id":247},{"code_idx":541,"before_size_id":247,"after_size_id":247},{"code_idx":542,"before_size_id":247,"after_size_id":247},{"code_idx":543,"before_size_id":247,"after_size_id":248},{"code_idx":544,"before_size_id":248,"after_size_id":248},{"code_idx":545,"before_size_id":248,"after_size_id":248},{"code_idx":546,"before_size_id":248,"after_size_id":248},{"code_idx":547,"before_size_id":248,"after_size_id":248},{"code_idx":548,"before_size_id":248,"after_size_id":249},{"code_idx":549,"before_size_id":249,"after_size_id":250},{"code_idx":550,"before_size_id":250,"after_size_id":250},{"code_idx":551,"before_size_id":250,"after_size_id":251},{"code_idx":552,"before_size_id":251,"after_size_id":251},{"code_idx":553,"before_size_id":251,"after_size_id":252},{"code_idx":554,"before_size_id":252,"after_size_id":252},{"code_idx":483,"before_size_id":252,"after_size_id":253},{"code_idx":484,"before_size_id":252,"after_size_id":254},{"code_idx":555,"before_size_id":251,"after_size_id":254},{"code_idx":556,"before_size_id":254,"after_size_id":254},{"code_idx":557,"before_size_id":254,"after_size_id":254},{"code_idx":558,"before_size_id":254,"after_size_id":254},{"code_idx":559,"before_size_id":254,"after_size_id":255},{"code_idx":560,"before_size_id":255,"after_size_id":255},{"code_idx":561,"before_size_id":255,"after_size_id":255},{"code_idx":413,"before_size_id":255,"after_size_id":256},{"code_idx":414,"before_size_id":256,"after_size_id":256},{"code_idx":415,"before_size_id":256,"after_size_id":256},{"code_idx":416,"before_size_id":255,"after_size_id":257},{"code_idx":417,"before_size_id":257,"after_size_id":257},{"code_idx":418,"before_size_id":258,"after_size_id":258},{"code_idx":419,"before_size_id":257,"after_size_id":259},{"code_idx":562,"before_size_id":255,"after_size_id":259},{"code_idx":563,"before_size_id":259,"after_size_id":259},{"code_idx":564,"before_size_id":259,"after_size_id":259},{"code_idx":565,"before_size_id":259,"after_size_id":259},{"code_idx":566,"before_size_id":259,"after_size_id":259},{"code_idx":567,"before_size_id":260,"after_size_id":261},{"code_idx":568,"before_size_id":261,"after_size_id":261},{"code_idx":569,"before_size_id":261,"after_size_id":261},{"code_idx":570,"before_size_id":260,"after_size_id":261},{"code_idx":571,"before_size_id":260,"after_size_id":262},{"code_idx":572,"before_size_id":259,"after_size_id":262},{"code_idx":573,"before_size_id":262,"after_size_id":262},{"code_idx":574,"before_size_id":262,"after_size_id":262},{"code_idx":575,"before_size_id":262,"after_size_id":262},{"code_idx":576,"before_size_id":262,"after_size_id":263},{"code_idx":577,"before_size_id":263,"after_size_id":263},{"code_idx":578,"before_size_id":263,"after_size_id":263},{"code_idx":579,"before_size_id":263,"after_size_id":264},{"code_idx":580,"before_size_id":264,"after_size_id":264},{"code_idx":581,"before_size_id":264,"after_size_id":264},{"code_idx":582,"before_size_id":264,"after_size_id":264},{"code_idx":583,"before_size_id":264,"after_size_id":264},{"code_idx":584,"before_size_id":264,"after_size_id":264},{"code_idx":585,"before_size_id":264,"after_size_id":265},{"code_idx":586,"before_size_id":265,"after_size_id":265},{"code_idx":587,"before_size_id":265,"after_size_id":265},{"code_idx":588,"before_size_id":265,"after_size_id":265},{"code_idx":589,"before_size_id":265,"after_size_id":265},{"code_idx":590,"before_size_id":265,"after_size_id":265},{"code_idx":591,"before_size_id":265,"after_size_id":266},{"code_idx":592,"before_size_id":266,"after_size_id":266},{"code_idx":593,"before_size_id":266,"after_size_id":266},{"code_idx":594,"before_size_id":266,"after_size_id":266},{"code_idx":595,"before_size_id":266,"after_size_id":266},{"code_idx":596,"before_size_id":266,"after_size_id":266},{"code_idx":597,"before_size_id":266,"after_size_id":267},{"code_idx":598,"before_size_id":266,"after_size_id":268},{"code_idx":197,"before_size_id":268,"after_size_id":268},{"code_idx":198,"before_size_id":268,"after_size_id":268},{"code_idx":199,"before_size_id":268,"after_size_id":268},{"code_idx":538,"before_size_id":268,"after_size_id":268},{"code_idx":539,"before_size_id":268,"after_size_id":268},{"code_idx":540,"before_size_id":268,"after_size_id":268},{"code_idx":541,"before_size_id":268,"after_size_id":268},{"code_idx":542,"before_size_id":268,"after_size_id":268},{"code_idx":543,"before_size_id":268,"after_size_id":269},{"code_idx":544,"before_size_id":269,"after_size_id":269},{"code_idx":545,"before_size_id":269,"after_size_id":269},{"code_idx":546,"before_size_id":269,"after_size_id":269},{"code_idx":547,"before_size_id":269,"after_size_id":269},{"code_idx":548,"before_size_id":269,"after_size_id":270},{"code_idx":549,"before_size_id":270,"after_size_id":271},{"code_idx":550,"before_size_id":271,"after_size_id":271},{"code_idx":551,"before_size_id":271,"after_size_id":272},{"code_idx":552,"before_size_id":272,"after_size_id":272},{"code_idx":553,"before_size_id":272,"after_size_id":273},{"code_idx":554,"before_size_id":273,"after_size_id":273},{"code_idx":483,"before_size_id":273,"after_size_id":274},{"code_idx":484,"before_size_id":273,"after_size_id":275},{"code_idx":555,"before_size_id":272,"after_size_id":275},{"code_idx":556,"before_size_id":275,"after_size_id":275},{"code_idx":557,"before_size_id":275,"after_size_id":275},{"code_idx":558,"before_size_id":275,"after_size_id":275},{"code_idx":559,"before_size_id":275,"after_size_id":276},{"code_idx":560,"before_size_id":276,"after_size_id":276},{"code_idx":561,"before_size_id":276,"after_size_id":276},{"code_idx":413,"before_size_id":276,"after_size_id":277},{"code_idx":414,"before_size_id":277,"after_size_id":277},{"code_idx":415,"before_size_id":277,"after_size_id":277},{"code_idx":416,"before_size_id":276,"after_size_id":278},{"code_idx":417,"before_size_id":278,"after_size_id":278},{"code_idx":418,"before_size_id":279,"after_size_id":279},{"code_idx":419,"before_size_id":278,"after_size_id":280},{"code_idx":562,"before_size_id":276,"after_size_id":280},{"code_idx":563,"before_size_id":280,"after_size_id":280},{"code_idx":564,"before_size_id":280,"after_size_id":280},{"code_idx":565,"before_size_id":280,"after_size_id":280},{"code_idx":566,"before_size_id":280,"after_size_id":280},{"code_idx":567,"before_size_id":281,"after_size_id":282},{"code_idx":568,"before_size_id":282,"after_size_id":282},{"code_idx":569,"before_size_id":282,"after_size_id":282},{"code_idx":570,"before_size_id":281,"after_size_id":282},{"code_idx":571,"before_size_id":281,"after_size_id":283},{"code_idx":572,"before_size_id":280,"after_size_id":283},{"code_idx":573,"before_size_id":283,"after_size_id":283},{"code_idx":574,"before_size_id":283,"after_size_id":283},{"code_idx":575,"before_size_id":283,"after_size_id":283},{"code_idx":576,"before_size_id":283,"after_size_id":284},{"code_idx":577,"before_size_id":284,"after_size_id":284},{"code_idx":578,"before_size_id":284,"after_size_id":284},{"code_idx":579,"before_size_id":284,"after_size_id":285},{"code_idx":580,"before_size_id":285,"after_size_id":285},{"code_idx":581,"before_size_id":285,"after_size_id":285},{"code_idx":582,"before_size_id":285,"after_size_id":285},{"code_idx":583,"before_size_id":285,"after_size_id":285},{"code_idx":584,"before_size_id":285,"after_size_id":285},{"code_idx":585,"before_size_id":285,"after_size_id":286},{"code_idx":586,"before_size_id":286,"after_size_id":286},{"code_idx":587,"before_size_id":286,"after_size_id":286},{"code_idx":588,"before_size_id":286,"after_size_id":286},{"code_idx":589,"before_size_id":286,"after_size_id":286},{"code_idx":590,"before_size_id":286,"after_size_id":286},{"code_idx":591,"before_size_id":286,"after_size_id":287},{"code_idx":592,"before_size_id":287,"after_size_id":287},{"code_idx":593,"before_size_id":287,"after_size_id":287},{"code_idx":594,"before_size_id":287,"after_size_id":287},{"code_idx":595,"before_size_id":287,"after_size_id":287},{"code_idx":596,"before_size_id":287,"after_size_id":287},{"code_idx":597,"before_size_id":287,"after_size_id":288},{"code_idx":598,"before_size_id":287,"after_size_id":289},{"code_idx":197,"before_size_id":289,"after_size_id":289},{"code_idx":198,"before_size_id":289,"after_size_id":289},{"code_idx":199,"before_size_id":289,"after_size_id":289},{"code_idx":538,"before_size_id":289,"after_size_id":289},{"code_idx":539,"before_size_id":289,"after_size_id":289},{"code_idx":540,"before_size_id":289,"after_size_id":289},{"code_idx":541,"before_size_id":289,"after_size_id":289},{"code_idx":542,"before_size_id":289,"after_size_id":289},{"code_idx":543,"before_size_id":289,"after_size_id":290},{"code_idx":544,"before_size_id":290,"after_size_id":290},{"code_idx":545,"before_size_id":290,"after_size_id":290},{"code_idx":546,"before_size_id":290,"after_size_id":290},{"code_idx":547,"before_size_id":290,"after_size_id":290},{"code_idx":548,"before_size_id":290,"after_size_id":291},{"code_idx":549,"before_size_id":291,"after_size_id":292},{"code_idx":550,"before_size_id":292,"after_size_id":292},{"code_idx":551,"before_size_id":292,"after_size_id":293},{"code_idx":552,"before_size_id":293,"after_size_id":293},{"code_idx":553,"before_size_id":293,"after_size_id":294},{"code_idx":554,"before_size_id":294,"after_size_id":294},{"code_idx":483,"before_size_id":294,"after_size_id":295},{"code_idx":484,"before_size_id":294,"after_size_id":296},{"code_idx":555,"before_size_id":293,"after_size_id":296},{"code_idx":556,"before_size_id":296,"after_size_id":296},{"code_idx":557,"before_size_id":296,"after_size_id":296},{"code_idx":558,"before_size_id":296,"after_size_id":296},{"code_idx":559,"before_size_id":296,"after_size_id":297},{"code_idx":560,"before_size_id":297,"after_size_id":297},{"code_idx":561,"before_size_id":297,"after_size_id":297},{"code_idx":413,"before_size_id":297,"after_size_id":298},{"code_idx":414,"before_size_id":298,"after_size_id":298},{"code_idx":415,"before_size_id":298,"after_size_id":298},{"code_idx":416,"before_size_id":297,"after_size_id":299},{"code_idx":417,"before_size_id":299,"after_size_id":299},{"code_idx":418,"before_size_id":300,"after_size_id":300},{"code_idx":419,"before_size_id":299,"after_size_id":301},{"code_idx":562,"before_size_id":297,"after_size_id":301},{"code_idx":563,"before_size_id":301,"after_size_id":301},{"code_idx":564,"before_size_id":301,"after_size_id":301},{"code_idx":565,"before_size_id":301,"after_size_id":301},{"code_idx":566,"before_size_id":301,"after_size_id":301},{"code_idx":567,"before_size_id":302,"after_size_id":303},{"code_idx":568,"before_size_id":303,"after_size_id":303},{"code_idx":569,"before_size_id":303,"after_size_id":303},{"code_idx":570,"before_size_id":302,"after_size_id":303},{"code_idx":571,"before_size_id":302,"after_size_id":304},{"code_idx":572,"before_size_id":301,"after_size_id":304},{"code_idx":573,"before_size_id":304,"after_size_id":304},{"code_idx":574,"before_size_id":304,"after_size_id":304},{"code_idx":575,"before_size_id":304,"after_size_id":304},{"code_idx":576,"before_size_id":304,"after_size_id":305},{"code_idx":577,"before_size_id":305,"after_size_id":305},{"code_idx":578,"before_size_id":305,"after_size_id":305},{"code_idx":579,"before_size_id":305,"after_size_id":306},{"code_idx":580,"before_size_id":306,"after_size_id":306},{"code_idx":581,"before_size_id":306,"after_size_id":306},{"code_idx":582,"before_size_id":306,"after_size_id":306},{"code_idx":583,"before_size_id":306,"after_size_id":306},{"code_idx":584,"before_size_id":306,"after_size_id":306},{"code_idx":585,"before_size_id":306,"after_size_id":307},{"code_idx":586,"before_size_id":307,"after_size_id":307},{"code_idx":587,"before_size_id":307,"after_size_id":307},{"code_idx":588,"before_size_id":307,"after_size_id":307},{"code_idx":589,"before_size_id":307,"after_size_id":307},{"code_idx":590,"before_size_id":307,"after_size_id":307},{"code_idx":591,"before_size_id":307,"after_size_id":308},{"code_idx":592,"before_size_id":308,"after_size_id":308},{"code_idx":593,"before_size_id":308,"after_size_id":308},{"code_idx":594,"before_size_id":308,"after_size_id":308},{"code_idx":595,"before_size_id":308,"after_size_id":308},{"code_idx":596,"before_size_id":308,"after_size_id":308},{"code_idx":597,"before_size_id":308,"after_size_id":309},{"code_idx":598,"before_size_id":308,"after_size_id":310},{"code_idx":599,"before_size_id":226,"after_size_id":310},{"code_idx":600,"before_size_id":310,"after_size_id":310},{"code_idx":601,"before_size_id":310,"after_size_id":310},{"code_idx":602,"before_size_id":310,"after_size_id":310},{"code_idx":603,"before_size_id":310,"after_size_id":310},{"code_idx":604,"before_size_id":310,"after_size_id":310},{"code_idx":605,"before_size_id":310,"after_size_id":310},{"code_idx":606,"before_size_id":310,"after_size_id":310},{"code_idx":607,"before_size_id":310,"after_size_id":311},{"code_idx":608,"before_size_id":311,"after_size_id":312},{"code_idx":609,"before_size_id":312,"after_size_id":312},{"code_idx":610,"before_size_id":312,"after_size_id":312},{"code_idx":611,"before_size_id":311,"after_size_id":313},{"code_idx":612,"before_size_id":313,"after_size_id":313},{"code_idx":613,"before_size_id":313,"after_size_id":313},{"code_idx":614,"before_size_id":313,"after_size_id":313},{"code_idx":615,"before_size_id":313,"after_size_id":313},{"code_idx":616,"before_size_id":313,"after_size_id":314},{"code_idx":617,"before_size_id":313,"after_size_id":314},{"code_idx":618,"before_size_id":313,"after_size_id":315},{"code_idx":619,"before_size_id":315,"after_size_id":316},{"code_idx":620,"before_size_id":310,"after_size_id":316},{"code_idx":621,"before_size_id":316,"after_size_id":316},{"code_idx":3,"before_size_id":316,"after_size_id":317},{"code_idx":4,"before_size_id":317,"after_size_id":317},{"code_idx":5,"before_size_id":317,"after_size_id":317},{"code_idx":6,"before_size_id":317,"after_size_id":317},{"code_idx":7,"before_size_id":317,"after_size_id":318},{"code_idx":8,"before_size_id":318,"after_size_id":318},{"code_idx":9,"before_size_id":318,"after_size_id":318},{"code_idx":10,"before_size_id":318,"after_size_id":318},{"code_idx":11,"before_size_id":318,"after_size_id":318},{"code_idx":12,"before_size_id":318,"after_size_id":318},{"code_idx":13,"before_size_id":318,"after_size_id":318},{"code_idx":14,"before_size_id":318,"after_size_id":318},{"code_idx":15,"before_size_id":318,"after_size_id":318},{"code_idx":16,"before_size_id":317,"after_size_id":318},{"code_idx":622,"before_size_id":316,"after_size_id":318},{"code_idx":623,"before_size_id":318,"after_size_id":319},{"code_idx":624,"before_size_id":319,"after_size_id":320},{"code_idx":625,"before_size_id":320,"after_size_id":320},{"code_idx":626,"before_size_id":320,"after_size_id":320},{"code_idx":627,"before_size_id":320,"after_size_id":320},{"code_idx":628,"before_size_id":320,"after_size_id":320},{"code_idx":629,"before_size_id":320,"after_size_id":320},{"code_idx":22,"before_size_id":320,"after_size_id":321},{"code_idx":23,"before_size_id":321,"after_size_id":321},{"code_idx":24,"before_size_id":321,"after_size_id":321},{"code_idx":25,"before_size_id":321,"after_size_id":321},{"code_idx":26,"before_size_id":321,"after_size_id":322},{"code_idx":8,"before_size_id":322,"after_size_id":322},{"code_idx":9,"before_size_id":322,"after_size_id":322},{"code_idx":10,"before_size_id":322,"after_size_id":322},{"code_idx":11,"before_size_id":322,"after_size_id":322},{"code_idx":12,"before_size_id":322,"after_size_id":322},{"code_idx":13,"before_size_id":322,"after_size_id":322},{"code_idx":14,"before_size_id":322,"after_size_id":322},{"code_idx":15,"before_size_id":322,"after_size_id":322},{"code_idx":27,"before_size_id":321,"after_size_id":322},{"code_idx":630,"before_size_id":320,"after_size_id":322},{"code_idx":631,"before_size_id":322,"after_size_id":322},{"code_idx":632,"before_size_id":322,"after_size_id":322},{"code_idx":633,"before_size_id":322,"after_size_id":322},{"code_idx":634,"before_size_id":322,"after_size_id":322},{"code_idx":635,"before_size_id":322,"after_size_id":322},{"code_idx":22,"before_size_id":322,"after_size_id":323},{"code_idx":23,"before_size_id":323,"after_size_id":323},{"code_idx":24,"before_size_id":323,"after_size_id":323},{"code_idx":25,"before_size_id":323,"after_size_id":323},{"code_idx":26,"before_size_id":323,"after_size_id":324},{"code_idx":8,"before_size_id":324,"after_size_id":324},{"code_idx":9,"before_size_id":324,"after_size_id":324},{"code_idx":10,"before_size_id":324,"after_size_id":324},{"code_idx":11,"before_size_id":324,"after_size_id":324},{"code_idx":12,"before_size_id":324,"after_size_id":324},{"code_idx":13,"before_size_id":324,"after_size_id":324},{"code_idx":14,"before_size_id":324,"after_size_id":324},{"code_idx":15,"before_size_id":324,"after_size_id":324},{"code_idx":27,"before_size_id":323,"after_size_id":324},{"code_idx":636,"before_size_id":322,"after_size_id":324},{"code_idx":637,"before_size_id":324,"after_size_id":324},{"code_idx":638,"before_size_id":324,"after_size_id":324},{"code_idx":639,"before_size_id":324,"after_size_id":324},{"code_idx":640,"before_size_id":324,"after_size_id":324},{"code_idx":641,"before_size_id":324,"after_size_id":324},{"code_idx":22,"before_size_id":324,"after_size_id":325},{"code_idx":23,"before_size_id":325,"after_size_id":325},{"code_idx":24,"before_size_id":325,"after_size_id":325},{"code_idx":25,"before_size_id":325,"after_size_id":325},{"code_idx":26,"before_size_id":325,"after_size_id":326},{"code_idx":8,"before_size_id":326,"after_size_id":326},{"code_idx":9,"before_size_id":326,"after_size_id":326},{"code_idx":10,"before_size_id":326,"after_size_id":326},{"code_idx":11,"before_size_id":326,"after_size_id":326},{"code_idx":12,"before_size_id":326,"after_size_id":326},{"code_idx":13,"before_size_id":326,"after_size_id":326},{"code_idx":14,"before_size_id":326,"after_size_id":326},{"code_idx":15,"before_size_id":326,"after_size_id":326},{"code_idx":27,"before_size_id":325,"after_size_id":326},{"code_idx":642,"before_size_id":324,"after_size_id":326},{"code_idx":643,"before_size_id":326,"after_size_id":326},{"code_idx":644,"before_size_id":326,"after_size_id":326},{"code_idx":645,"before_size_id":326,"after_size_id":326},{"code_idx":646,"before_size_id":326,"after_size_id":326},{"code_idx":647,"before_size_id":326,"after_size_id":326},{"code_idx":22,"before_size_id":326,"after_size_id":327},{"code_idx":23,"before_size_id":327,"after_size_id":327},{"code_idx":24,"before_size_id":327,"after_size_id":327},{"code_idx":25,"before_size_id":327,"after_size_id":327},{"code_idx":26,"before_size_id":327,"after_size_id":328},{"code_idx":8,"before_size_id":328,"after_size_id":328},{"code_idx":9,"before_size_id":328,"after_size_id":328},{"code_idx":10,"before_size_id":328,"after_size_id":328},{"code_idx":11,"before_size_id":328,"after_size_id":328},{"code_idx":12,"before_size_id":328,"after_size_id":328},{"code_idx":13,"before_size_id":328,"after_size_id":328},{"code_idx":14,"before_size_id":328,"after_size_id":328},{"code_idx":15,"before_size_id":328,"after_size_id":328},{"code_idx":27,"before_size_id":327,"after_size_id":328},{"code_idx":648,"before_size_id":326,"after_size_id":328},{"code_idx":649,"before_size_id":328,"after_size_id":329},{"code_idx":650,"before_size_id":329,"after_size_id":330},{"code_idx":651,"before_size_id":330,"after_size_id":331},{"code_idx":652,"before_size_id":316,"after_size_id":331}]},"remove_liquidity":{"profilees":[{"code_idx":29,"before_size_id":0,"after_size_id":0},{"code_idx":30,"before_size_id":0,"after_size_id":0},{"code_idx":31,"before_size_id":0,"after_size_id":1},{"code_idx":722,"before_size_id":0,"after_size_id":1},{"code_idx":723,"before_size_id":1,"after_size_id":2},{"code_idx":724,"before_size_id":2,"after_size_id":2},{"code_idx":725,"before_size_id":2,"after_size_id":2},{"code_idx":726,"before_size_id":1,"after_size_id":3},{"code_idx":727,"before_size_id":3,"after_size_id":4},{"code_idx":728,"before_size_id":4,"after_size_id":4},{"code_idx":729,"before_size_id":4,"after_size_id":4},{"code_idx":730,"before_size_id":3,"after_size_id":5},{"code_idx":731,"before_size_id":5,"after_size_id":5},{"code_idx":732,"before_size_id":5,"after_size_id":5},{"code_idx":733,"before_size_id":5,"after_size_id":6},{"code_idx":734,"before_size_id":5,"after_size_id":6},{"code_idx":735,"before_size_id":6,"after_size_id":6},{"code_idx":736,"before_size_id":6,"after_size_id":6},{"code_idx":737,"before_size_id":6,"after_size_id":7},{"code_idx":738,"before_size_id":6,"after_size_id":7},{"code_idx":739,"before_size_id":7,"after_size_id":8},{"code_idx":740,"before_size_id":8,"after_size_id":9},{"code_idx":741,"before_size_id":7,"after_size_id":9},{"code_idx":742,"before_size_id":9,"after_size_id":9},{"code_idx":3,"before_size_id":9,"after_size_id":10},{"code_idx":4,"before_size_id":10,"after_size_id":10},{"code_idx":5,"before_size_id":10,"after_size_id":10},{"code_idx":6,"before_size_id":10,"after_size_id":10},{"code_idx":7,"before_size_id":10,"after_size_id":11},{"code_idx":8,"before_size_id":11,"after_size_id":11},{"code_idx":9,"before_size_id":11,"after_size_id":11},{"code_idx":10,"before_size_id":11,"after_size_id":11},{"code_idx":11,"before_size_id":11,"after_size_id":11},{"code_idx":12,"before_size_id":11,"after_size_id":11},{"code_idx":13,"before_size_id":11,"after_size_id":11},{"code_idx":14,"before_size_id":11,"after_size_id":11},{"code_idx":15,"before_size_id":11,"after_size_id":11},{"code_idx":16,"before_size_id":10,"after_size_id":11},{"code_idx":743,"before_size_id":9,"after_size_id":11},{"code_idx":744,"before_size_id":11,"after_size_id":12},{"code_idx":745,"before_size_id":12,"after_size_id":13},{"code_idx":746,"before_size_id":9,"after_size_id":13}]},"init_position":{"profilees":[{"code_idx":18,"before_size_id":0,"after_size_id":0},{"code_idx":19,"before_size_id":0,"after_size_id":0},{"code_idx":20,"before_size_id":0,"after_size_id":0},{"code_idx":21,"before_size_id":0,"after_size_id":0},{"code_idx":22,"before_size_id":0,"after_size_id":1},{"code_idx":23,"before_size_id":1,"after_size_id":1},{"code_idx":24,"before_size_id":1,"after_size_id":1},{"code_idx":25,"before_size_id":1,"after_size_id":1},{"code_idx":26,"before_size_id":1,"after_size_id":2},{"code_idx":8,"before_size_id":2,"after_size_id":2},{"code_idx":9,"before_size_id":2,"after_size_id":2},{"code_idx":10,"before_size_id":2,"after_size_id":2},{"code_idx":11,"before_size_id":2,"after_size_id":2},{"code_idx":12,"before_size_id":2,"after_size_id":2},{"code_idx":13,"before_size_id":2,"after_size_id":2},{"code_idx":14,"before_size_id":2,"after_size_id":2},{"code_idx":15,"before_size_id":2,"after_size_id":2},{"code_idx":27,"before_size_id":1,"after_size_id":2},{"code_idx":28,"before_size_id":0,"after_size_id":2}]},"init_cfmm_state":{"profilees":[{"code_idx":0,"before_size_id":0,"after_size_id":0},{"code_idx":1,"before_size_id":0,"after_size_id":0},{"code_idx":2,"before_size_id":0,"after_size_id":0},{"code_idx":3,"before_size_id":0,"after_size_id":1},{"code_idx":4,"before_size_id":1,"after_size_id":1},{"code_idx":5,"before_size_id":1,"after_size_id":1},{"code_idx":6,"before_size_id":1,"after_size_id":1},{"code_idx":7,"before_size_id":1,"after_size_id":2},{"code_idx":8,"before_size_id":2,"after_size_id":2},{"code_idx":9,"before_size_id":2,"after_size_id":2},{"code_idx":10,"before_size_id":2,"after_size_id":2},{"code_idx":11,"before_size_id":2,"after_size_id":2},{"code_idx":12,"before_size_id":2,"after_size_id":2},{"code_idx":13,"before_size_id":2,"after_size_id":2},{"code_idx":14,"before_size_id":2,"after_size_id":2},{"code_idx":15,"before_size_id":2,"after_size_id":2},{"code_idx":16,"before_size_id":1,"after_size_id":2},{"code_idx":17,"before_size_id":0,"after_size_id":2}]},"lmsr_trade":{"profilees":[{"code_idx":29,"before_size_id":0,"after_size_id":0},{"code_idx":30,"before_size_id":0,"after_size_id":0},{"code_idx":31,"before_size_id":0,"after_size_id":1},{"code_idx":149,"before_size_id":0,"after_size_id":1},{"code_idx":29,"before_size_id":1,"after_size_id":1},{"code_idx":30,"before_size_id":1,"after_size_id":1},{"code_idx":31,"before_size_id":1,"after_size_id":2},{"code_idx":150,"before_size_id":1,"after_size_id":2},{"code_idx":151,"before_size_id":2,"after_size_id":2},{"code_idx":152,"before_size_id":2,"after_size_id":2},{"code_idx":153,"before_size_id":2,"after_size_id":3},{"code_idx":154,"before_size_id":3,"after_size_id":3},{"code_idx":155,"before_size_id":3,"after_size_id":3},{"code_idx":156,"before_size_id":2,"after_size_id":4},{"code_idx":157,"before_size_id":4,"after_size_id":5},{"code_idx":158,"before_size_id":5,"after_size_id":6},{"code_idx":159,"before_size_id":6,"after_size_id":6},{"code_idx":160,"before_size_id":6,"after_size_id":6},{"code_idx":161,"before_size_id":6,"after_size_id":6},{"code_idx":162,"before_size_id":6,"after_size_id":6},{"code_idx":163,"before_size_id":6,"after_size_id":6},{"code_idx":164,"before_size_id":6,"after_size_id":6},{"code_idx":165,"before_size_id":6,"after_size_id":6},{"code_idx":166,"before_size_id":6,"after_size_id":7},{"code_idx":167,"before_size_id":7,"after_size_id":7},{"code_idx":168,"before_size_id":7,"after_size_id":7},{"code_idx":169,"before_size_id":7,"after_size_id":7},{"code_idx":170,"before_size_id":7,"after_size_id":7},{"code_idx":171,"before_size_id":7,"after_size_id":7},{"code_idx":172,"before_size_id":7,"after_size_id":7},{"code_idx":173,"before_size_id":7,"after_size_id":7},{"code_idx":174,"before_size_id":7,"after_size_id":8},{"code_idx":175,"before_size_id":8,"after_size_id":9},{"code_idx":176,"before_size_id":9,"after_size_id":9},{"code_idx":177,"before_size_id":9,"after_size_id":9},{"code_idx":178,"before_size_id":9,"after_size_id":9},{"code_idx":179,"before_size_id":9,"after_size_id":9},{"code_idx":180,"before_size_id":10,"after_size_id":10},{"code_idx":181,"before_size_id":10,"after_size_id":11},{"code_idx":182,"before_size_id":11,"after_size_id":11},{"code_idx":183,"before_size_id":11,"after_size_id":11},{"code_idx":184,"before_size_id":12,"after_size_id":12},{"code_idx":185,"before_size_id":12,"after_size_id":13},{"code_idx":186,"before_size_id":13,"after_size_id":13},{"code_idx":187,"before_size_id":13,"after_size_id":13},{"code_idx":188,"before_size_id":13,"after_size_id":13},{"code_idx":189,"before_size_id":12,"after_size_id":13},{"code_idx":190,"before_size_id":13,"after_size_id":14},{"code_idx":191,"before_size_id":14,"after_size_id":14},{"code_idx":192,"before_size_id":14,"after_size_id":14},{"code_idx":193,"before_size_id":14,"after_size_id":14},{"code_idx":194,"before_size_id":14,"after_size_id":14},{"code_idx":195,"before_size_id":14,"after_size_id":15},{"code_idx":196,"before_size_id":13,"after_size_id":15},{"code_idx":197,"before_size_id":15,"after_size_id":15},{"code_idx":198,"before_size_id":15,"after_size_id":16},{"code_idx":199,"before_size_id":16,"after_size_id":16},{"code_idx":200,"before_size_id":17,"after_size_id":18},{"code_idx":201,"before_size_id":16,"after_size_id":19},{"code_idx":202,"before_size_id":19,"after_size_id":20},{"code_idx":197,"before_size_id":20,"after_size_id":20},{"code_idx":198,"before_size_id":20,"after_size_id":21},{"code_idx":199,"before_size_id":21,"after_size_id":21},{"code_idx":200,"before_size_id":22,"after_size_id":23},{"code_idx":201,"before_size_id":21,"after_size_id":24},{"code_idx":202,"before_size_id":24,"after_size_id":25},{"code_idx":197,"before_size_id":25,"after_size_id":25},{"code_idx":198,"before_size_id":25,"after_size_id":26},{"code_idx":199,"before_size_id":26,"after_size_id":26},{"code_idx":200,"before_size_id":27,"after_size_id":28},{"code_idx":201,"before_size_id":26,"after_size_id":29},{"code_idx":202,"before_size_id":29,"after_size_id":30},{"code_idx":197,"before_size_id":30,"after_size_id":30},{"code_idx":198,"before_size_id":30,"after_size_id":31},{"code_idx":199,"before_size_id":31,"after_size_id":31},{"code_idx":200,"before_size_id":32,"after_size_id":33},{"code_idx":201,"before_size_id":31,"after_size_id":34},{"code_idx":202,"before_size_id":34,"after_size_id":35},{"code_idx":197,"before_size_id":35,"after_size_id":35},{"code_idx":198,"before_size_id":35,"after_size_id":36},{"code_idx":199,"before_size_id":36,"after_size_id":36},{"code_idx":200,"before_size_id":37,"after_size_id":38},{"code_idx":201,"before_size_id":36,"after_size_id":39},{"code_idx":202,"before_size_id":39,"after_size_id":40},{"code_idx":197,"before_size_id":40,"after_size_id":40},{"code_idx":198,"before_size_id":40,"after_size_id":41},{"code_idx":199,"before_size_id":41,"after_size_id":41},{"code_idx":200,"before_size_id":42,"after_size_id":43},{"code_idx":201,"before_size_id":41,"after_size_id":44},{"code_idx":202,"before_size_id":44,"after_size_id":45},{"code_idx":197,"before_size_id":45,"after_size_id":45},{"code_idx":198,"before_size_id":45,"after_size_id":46},{"code_idx":199,"before_size_id":46,"after_size_id":46},{"code_idx":200,"before_size_id":47,"after_size_id":48},{"code_idx":201,"before_size_id":46,"after_size_id":49},{"code_idx":202,"before_size_id":49,"after_size_id":50},{"code_idx":197,"before_size_id":50,"after_size_id":50},{"code_idx":198,"before_size_id":50,"after_size_id":51},{"code_idx":199,"before_size_id":51,"after_size_id":51},{"code_idx":200,"before_size_id":52,"after_size_id":53},{"code_idx":201,"before_size_id":51,"after_size_id":54},{"code_idx":202,"before_size_id":54,"after_size_id":55},{"code_idx":203,"before_size_id":11,"after_size_id":55},{"code_idx":184,"before_size_id":56,"after_size_id":56},{"code_idx":185,"before_size_id":56,"after_size_id":57},{"code_idx":186,"before_size_id":57,"after_size_id":57},{"code_idx":187,"before_size_id":57,"after_size_id":57},{"code_idx":188,"before_size_id":57,"after_size_id":57},{"code_idx":189,"before_size_id":56,"after_size_id":57},{"code_idx":190,"before_size_id":57,"after_size_id":58},{"code_idx":191,"before_size_id":58,"after_size_id":58},{"code_idx":192,"before_size_id":58,"after_size_id":58},{"code_idx":193,"before_size_id":58,"after_size_id":58},{"code_idx":194,"before_size_id":58,"after_size_id":58},{"code_idx":195,"before_size_id":58,"after_size_id":59},{"code_idx":196,"before_size_id":57,"after_size_id":59},{"code_idx":197,"before_size_id":59,"after_size_id":59},{"code_idx":198,"before_size_id":59,"after_size_id":60},{"code_idx":199,"before_size_id":60,"after_size_id":60},{"code_idx":204,"before_size_id":60,"after_size_id":61},{"code_idx":197,"before_size_id":61,"after_size_id":61},{"code_idx":198,"before_size_id":61,"after_size_id":62},{"code_idx":199,"before_size_id":62,"after_size_id":62},{"code_idx":204,"before_size_id":62,"after_size_id":63},{"code_idx":197,"before_size_id":63,"after_size_id":63},{"code_idx":198,"before_size_id":63,"after_size_id":64},{"code_idx":199,"before_size_id":64,"after_size_id":64},{"code_idx":204,"before_size_id":64,"after_size_id":65},{"code_idx":197,"before_size_id":65,"after_size_id":65},{"code_idx":198,"before_size_id":65,"after_size_id":66},{"code_idx":199,"before_size_id":66,"after_size_id":66},{"code_idx":204,"before_size_id":66,"after_size_id":67},{"code_idx":197,"before_size_id":67,"after_size_id":67},{"code_idx":198,"before_size_id":67,"after_size_id":68},{"code_idx":199,"before_size_id":68,"after_size_id":68},{"code_idx":204,"before_size_id":68,"after_size_id":69},{"code_idx":205,"before_size_id":55,"after_size_id":69},{"code_idx":206,"before_size_id":69,"after_size_id":69},{"code_idx":207,"before_size_id":9,"after_size_id":69},{"code_idx":208,"before_size_id":69,"after_size_id":70},{"code_idx":209,"before_size_id":70,"after_size_id":70},{"code_idx":210,"before_size_id":70,"after_size_id":71},{"code_idx":211,"before_size_id":70,"after_size_id":71},{"code_idx":212,"before_size_id":70,"after_size_id":72},{"code_idx":213,"before_size_id":72,"after_size_id":73},{"code_idx":214,"before_size_id":72,"after_size_id":73},{"code_idx":215,"before_size_id":72,"after_size_id":74},{"code_idx":216,"before_size_id":70,"after_size_id":75},{"code_idx":217,"before_size_id":75,"after_size_id":76},{"code_idx":218,"before_size_id":76,"after_size_id":76},{"code_idx":219,"before_size_id":76,"after_size_id":76},{"code_idx":220,"before_size_id":76,"after_size_id":76},{"code_idx":221,"before_size_id":76,"after_size_id":76},{"code_idx":180,"before_size_id":77,"after_size_id":77},{"code_idx":181,"before_size_id":77,"after_size_id":78},{"code_idx":182,"before_size_id":78,"after_size_id":78},{"code_idx":183,"before_size_id":78,"after_size_id":78},{"code_idx":184,"before_size_id":78,"after_size_id":78},{"code_idx":185,"before_size_id":78,"after_size_id":78},{"code_idx":186,"before_size_id":78,"after_size_id":78},{"code_idx":187,"before_size_id":78,"after_size_id":78},{"code_idx":188,"before_size_id":78,"after_size_id":78},{"code_idx":189,"before_size_id":78,"after_size_id":78},{"code_idx":190,"before_size_id":78,"after_size_id":78},{"code_idx":191,"before_size_id":78,"after_size_id":78},{"code_idx":192,"before_size_id":78,"after_size_id":78},{"code_idx":193,"before_size_id":78,"after_size_id":78},{"code_idx":194,"before_size_id":78,"after_size_id":78},{"code_idx":195,"before_size_id":78,"after_size_id":78},{"code_idx":196,"before_size_id":78,"after_size_id":78},{"code_idx":197,"before_size_id":78,"after_size_id":78},{"code_idx":198,"before_size_id":78,"after_size_id":78},{"code_idx":199,"before_size_id":78,"after_size_id":78},{"code_idx":200,"before_size_id":79,"after_size_id":79},{"code_idx":201,"before_size_id":78,"after_size_id":80},{"code_idx":202,"before_size_id":80,"after_size_id":81},{"code_idx":197,"before_size_id":81,"after_size_id":81},{"code_idx":198,"before_size_id":81,"after_size_id":81},{"code_idx":199,"before_size_id":81,"after_size_id":81},{"code_idx":200,"before_size_id":82,"after_size_id":82},{"code_idx":201,"before_size_id":81,"after_size_id":83},{"code_idx":202,"before_size_id":83,"after_size_id":84},{"code_idx":197,"before_size_id":84,"after_size_id":84},{"code_idx":198,"before_size_id":84,"after_size_id":84},{"code_idx":199,"before_size_id":84,"after_size_id":84},{"code_idx":200,"before_size_id":85,"after_size_id":85},{"code_idx":201,"before_size_id":84,"after_size_id":86},{"code_idx":202,"before_size_id":86,"after_size_id":87},{"code_idx":197,"before_size_id":87,"after_size_id":87},{"code_idx":198,"before_size_id":87,"after_size_id":87},{"code_idx":199,"before_size_id":87,"after_size_id":87},{"code_idx":200,"before_size_id":88,"after_size_id":88},{"code_idx":201,"before_size_id":87,"after_size_id":89},{"code_idx":202,"before_size_id":89,"after_size_id":90},{"code_idx":197,"before_size_id":90,"after_size_id":90},{"code_idx":198,"before_size_id":90,"after_size_id":90},{"code_idx":199,"before_size_id":90,"after_size_id":90},{"code_idx":200,"before_size_id":91,"after_size_id":91},{"code_idx":201,"before_size_id":90,"after_size_id":92},{"code_idx":202,"before_size_id":92,"after_size_id":93},{"code_idx":197,"before_size_id":93,"after_size_id":93},{"code_idx":198,"before_size_id":93,"after_size_id":93},{"code_idx":199,"before_size_id":93,"after_size_id":93},{"code_idx":200,"before_size_id":94,"after_size_id":94},{"code_idx":201,"before_size_id":93,"after_size_id":95},{"code_idx":202,"before_size_id":95,"after_size_id":96},{"code_idx":197,"before_size_id":96,"after_size_id":96},{"code_idx":198,"before_size_id":96,"after_size_id":96},{"code_idx":199,"before_size_id":96,"after_size_id":96},{"code_idx":200,"before_size_id":97,"after_size_id":97},{"code_idx":201,"before_size_id":96,"after_size_id":98},{"code_idx":202,"before_size_id":98,"after_size_id":99},{"code_idx":197,"before_size_id":99,"after_size_id":99},{"code_idx":198,"before_size_id":99,"after_size_id":99},{"code_idx":199,"before_size_id":99,"after_size_id":99},{"code_idx":200,"before_size_id":100,"after_size_id":100},{"code_idx":201,"before_size_id":99,"after_size_id":101},{"code_idx":202,"before_size_id":101,"after_size_id":102},{"code_idx":203,"before_size_id":78,"after_size_id":102},{"code_idx":184,"before_size_id":102,"after_size_id":102},{"code_idx":185,"before_size_id":102,"after_size_id":102},{"code_idx":186,"before_size_id":102,"after_size_id":102},{"code_idx":187,"before_size_id":102,"after_size_id":102},{"code_idx":188,"before_size_id":102,"after_size_id":102},{"code_idx":189,"before_size_id":102,"after_size_id":102},{"code_idx":190,"before_size_id":102,"after_size_id":102},{"code_idx":191,"before_size_id":102,"after_size_id":102},{"code_idx":192,"before_size_id":102,"after_size_id":102},{"code_idx":193,"before_size_id":102,"after_size_id":102},{"code_idx":194,"before_size_id":102,"after_size_id":102},{"code_idx":195,"before_size_id":102,"after_size_id":102},{"code_idx":196,"before_size_id":102,"after_size_id":102},{"code_idx":197,"before_size_id":102,"after_size_id":102},{"code_idx":198,"before_size_id":102,"after_size_id":102},{"code_idx":199,"before_size_id":102,"after_size_id":102},{"code_idx":204,"before_size_id":102,"after_size_id":103},{"code_idx":197,"before_size_id":103,"after_size_id":103},{"code_idx":198,"before_size_id":103,"after_size_id":103},{"code_idx":199,"before_size_id":103,"after_size_id":103},{"code_idx":204,"before_size_id":103,"after_size_id":104},{"code_idx":197,"before_size_id":104,"after_size_id":104},{"code_idx":198,"before_size_id":104,"after_size_id":104},{"code_idx":199,"before_size_id":104,"after_size_id":104},{"code_idx":204,"before_size_id":104,"after_size_id":105},{"code_idx":197,"before_size_id":105,"after_size_id":105},{"code_idx":198,"before_size_id":105,"after_size_id":105},{"code_idx":199,"before_size_id":105,"after_size_id":105},{"code_idx":204,"before_size_id":105,"after_size_id":106},{"code_idx":197,"before_size_id":106,"after_size_id":106},{"code_idx":198,"before_size_id":106,"after_size_id":106},{"code_idx":199,"before_size_id":106,"after_size_id":106},{"code_idx":204,"before_size_id":106,"after_size_id":107},{"code_idx":205,"before_size_id":102,"after_size_id":107},{"code_idx":206,"before_size_id":107,"after_size_id":107},{"code_idx":222,"before_size_id":76,"after_size_id":107},{"code_idx":223,"before_size_id":107,"after_size_id":107},{"code_idx":224,"before_size_id":107,"after_size_id":107},{"code_idx":225,"before_size_id":107,"after_size_id":108},{"code_idx":226,"before_size_id":108,"after_size_id":108},{"code_idx":227,"before_size_id":108,"after_size_id":108},{"code_idx":228,"before_size_id":107,"after_size_id":109},{"code_idx":229,"before_size_id":109,"after_size_id":110},{"code_idx":230,"before_size_id":109,"after_size_id":111},{"code_idx":231,"before_size_id":111,"after_size_id":111},{"code_idx":232,"before_size_id":111,"after_size_id":111},{"code_idx":233,"before_size_id":111,"after_size_id":112},{"code_idx":234,"before_size_id":112,"after_size_id":112},{"code_idx":235,"before_size_id":112,"after_size_id":112},{"code_idx":236,"before_size_id":111,"after_size_id":113},{"code_idx":237,"before_size_id":113,"after_size_id":113},{"code_idx":184,"before_size_id":113,"after_size_id":113},{"code_idx":185,"before_size_id":113,"after_size_id":114},{"code_idx":186,"before_size_id":114,"after_size_id":114},{"code_idx":187,"before_size_id":114,"after_size_id":114},{"code_idx":188,"before_size_id":114,"after_size_id":114},{"code_idx":189,"before_size_id":113,"after_size_id":114},{"code_idx":190,"before_size_id":114,"after_size_id":115},{"code_idx":191,"before_size_id":115,"after_size_id":115},{"code_idx":192,"before_size_id":115,"after_size_id":115},{"code_idx":193,"before_size_id":115,"after_size_id":115},{"code_idx":194,"before_size_id":115,"after_size_id":115},{"code_idx":195,"before_size_id":115,"after_size_id":116},{"code_idx":196,"before_size_id":114,"after_size_id":116},{"code_idx":197,"before_size_id":116,"after_size_id":116},{"code_idx":198,"before_size_id":116,"after_size_id":116},{"code_idx":199,"before_size_id":116,"after_size_id":116},{"code_idx":238,"before_size_id":116,"after_size_id":117},{"code_idx":239,"before_size_id":117,"after_size_id":117},{"code_idx":240,"before_size_id":117,"after_size_id":117},{"code_idx":241,"before_size_id":117,"after_size_id":118},{"code_idx":242,"before_size_id":117,"after_size_id":119},{"code_idx":243,"before_size_id":119,"after_size_id":119},{"code_idx":244,"before_size_id":119,"after_size_id":119},{"code_idx":245,"before_size_id":119,"after_size_id":119},{"code_idx":246,"before_size_id":119,"after_size_id":120},{"code_idx":197,"before_size_id":120,"after_size_id":120},{"code_idx":198,"before_size_id":120,"after_size_id":120},{"code_idx":199,"before_size_id":120,"after_size_id":120},{"code_idx":238,"before_size_id":120,"after_size_id":121},{"code_idx":239,"before_size_id":121,"after_size_id":121},{"code_idx":240,"before_size_id":121,"after_size_id":121},{"code_idx":241,"before_size_id":121,"after_size_id":122},{"code_idx":242,"before_size_id":121,"after_size_id":123},{"code_idx":243,"before_size_id":123,"after_size_id":123},{"code_idx":244,"before_size_id":123,"after_size_id":123},{"code_idx":245,"before_size_id":123,"after_size_id":124},{"code_idx":246,"before_size_id":123,"after_size_id":125},{"code_idx":197,"before_size_id":125,"after_size_id":125},{"code_idx":198,"before_size_id":125,"after_size_id":125},{"code_idx":199,"before_size_id":125,"after_size_id":125},{"code_idx":238,"before_size_id":125,"after_size_id":126},{"code_idx":239,"before_size_id":126,"after_size_id":126},{"code_idx":240,"before_size_id":126,"after_size_id":126},{"code_idx":241,"before_size_id":126,"after_size_id":127},{"code_idx":242,"before_size_id":126,"after_size_id":128},{"code_idx":243,"before_size_id":128,"after_size_id":128},{"code_idx":244,"before_size_id":128,"after_size_id":128},{"code_idx":245,"before_size_id":128,"after_size_id":129},{"code_idx":246,"before_size_id":128,"after_size_id":130},{"code_idx":197,"before_size_id":130,"after_size_id":130},{"code_idx":198,"before_size_id":130,"after_size_id":130},{"code_idx":199,"before_size_id":130,"after_size_id":130},{"code_idx":238,"before_size_id":130,"after_size_id":131},{"code_idx":239,"before_size_id":131,"after_size_id":131},{"code_idx":240,"before_size_id":131,"after_size_id":131},{"code_idx":241,"before_size_id":131,"after_size_id":132},{"code_idx":242,"before_size_id":131,"after_size_id":133},{"code_idx":243,"before_size_id":133,"after_size_id":133},{"code_idx":244,"before_size_id":133,"after_size_id":133},{"code_idx":245,"before_size_id":133,"after_size_id":134},{"code_idx":246,"before_size_id":133,"after_size_id":135},{"code_idx":197,"before_size_id":135,"after_size_id":135},{"code_idx":198,"before_size_id":135,"after_size_id":135},{"code_idx":199,"before_size_id":135,"after_size_id":135},{"code_idx":238,"before_size_id":135,"after_size_id":136},{"code_idx":239,"before_size_id":136,"after_size_id":136},{"code_idx":240,"before_size_id":136,"after_size_id":136},{"code_idx":241,"before_size_id":136,"after_size_id":137},{"code_idx":242,"before_size_id":136,"after_size_id":138},{"code_idx":243,"before_size_id":138,"after_size_id":138},{"code_idx":244,"before_size_id":138,"after_size_id":138},{"code_idx":245,"before_size_id":138,"after_size_id":139},{"code_idx":246,"before_size_id":138,"after_size_id":140},{"code_idx":197,"before_size_id":140,"after_size_id":140},{"code_idx":198,"before_size_id":140,"after_size_id":141},{"code_idx":199,"before_size_id":141,"after_size_id":141},{"code_idx":238,"before_size_id":141,"after_size_id":142},{"code_idx":239,"before_size_id":142,"after_size_id":142},{"code_idx":240,"before_size_id":142,"after_size_id":142},{"code_idx":241,"before_size_id":142,"after_size_id":143},{"code_idx":242,"before_size_id":142,"after_size_id":144},{"code_idx":243,"before_size_id":144,"after_size_id":144},{"code_idx":244,"before_size_id":144,"after_size_id":144},{"code_idx":245,"before_size_id":144,"after_size_id":145},{"code_idx":246,"before_size_id":144,"after_size_id":146},{"code_idx":197,"before_size_id":146,"after_size_id":146},{"code_idx":198,"before_size_id":146,"after_size_id":147},{"code_idx":199,"before_size_id":147,"after_size_id":147},{"code_idx":238,"before_size_id":147,"after_size_id":148},{"code_idx":239,"before_size_id":148,"after_size_id":148},{"code_idx":240,"before_size_id":148,"after_size_id":148},{"code_idx":241,"before_size_id":148,"after_size_id":149},{"code_idx":242,"before_size_id":148,"after_size_id":150},{"code_idx":243,"before_size_id":150,"after_size_id":150},{"code_idx":244,"before_size_id":150,"after_size_id":150},{"code_idx":245,"before_size_id":150,"after_size_id":151},{"code_idx":246,"before_size_id":150,"after_size_id":152},{"code_idx":197,"before_size_id":152,"after_size_id":152},{"code_idx":198,"before_size_id":152,"after_size_id":153},{"code_idx":199,"before_size_id":153,"after_size_id":153},{"code_idx":238,"before_size_id":153,"after_size_id":154},{"code_idx":239,"before_size_id":154,"after_size_id":154},{"code_idx":240,"before_size_id":154,"after_size_id":154},{"code_idx":241,"before_size_id":154,"after_size_id":155},{"code_idx":242,"before_size_id":154,"after_size_id":156},{"code_idx":243,"before_size_id":156,"after_size_id":156},{"code_idx":244,"before_size_id":156,"after_size_id":156},{"code_idx":245,"before_size_id":156,"after_size_id":157},{"code_idx":246,"before_size_id":156,"after_size_id":158},{"code_idx":197,"before_size_id":158,"after_size_id":158},{"code_idx":198,"before_size_id":158,"after_size_id":159},{"code_idx":199,"before_size_id":159,"after_size_id":159},{"code_idx":238,"before_size_id":159,"after_size_id":160},{"code_idx":239,"before_size_id":160,"after_size_id":160},{"code_idx":240,"before_size_id":160,"after_size_id":160},{"code_idx":241,"before_size_id":160,"after_size_id":161},{"code_idx":242,"before_size_id":160,"after_size_id":162},{"code_idx":243,"before_size_id":162,"after_size_id":162},{"code_idx":244,"before_size_id":162,"after_size_id":162},{"code_idx":245,"before_size_id":162,"after_size_id":163},{"code_idx":246,"before_size_id":162,"after_size_id":164},{"code_idx":197,"before_size_id":164,"after_size_id":164},{"code_idx":198,"before_size_id":164,"after_size_id":165},{"code_idx":199,"before_size_id":165,"after_size_id":165},{"code_idx":238,"before_size_id":165,"after_size_id":166},{"code_idx":239,"before_size_id":166,"after_size_id":166},{"code_idx":240,"before_size_id":166,"after_size_id":166},{"code_idx":241,"before_size_id":166,"after_size_id":167},{"code_idx":242,"before_size_id":166,"after_size_id":168},{"code_idx":243,"before_size_id":168,"after_size_id":168},{"code_idx":244,"before_size_id":168,"after_size_id":168},{"code_idx":245,"before_size_id":168,"after_size_id":169},{"code_idx":246,"before_size_id":168,"after_size_id":170},{"code_idx":197,"before_size_id":170,"after_size_id":170},{"code_idx":198,"before_size_id":170,"after_size_id":171},{"code_idx":199,"before_size_id":171,"after_size_id":171},{"code_idx":238,"before_size_id":171,"after_size_id":172},{"code_idx":239,"before_size_id":172,"after_size_id":172},{"code_idx":240,"before_size_id":172,"after_size_id":172},{"code_idx":241,"before_size_id":172,"after_size_id":173},{"code_idx":242,"before_size_id":172,"after_size_id":174},{"code_idx":243,"before_size_id":174,"after_size_id":174},{"code_idx":244,"before_size_id":174,"after_size_id":174},{"code_idx":245,"before_size_id":174,"after_size_id":175},{"code_idx":246,"before_size_id":174,"after_size_id":176},{"code_idx":197,"before_size_id":176,"after_size_id":176},{"code_idx":198,"before_size_id":176,"after_size_id":177},{"code_idx":199,"before_size_id":177,"after_size_id":177},{"code_idx":238,"before_size_id":177,"after_size_id":178},{"code_idx":239,"before_size_id":178,"after_size_id":178},{"code_idx":240,"before_size_id":178,"after_size_id":178},{"code_idx":241,"before_size_id":178,"after_size_id":179},{"code_idx":242,"before_size_id":178,"after_size_id":180},{"code_idx":243,"before_size_id":180,"after_size_id":180},{"code_idx":244,"before_size_id":180,"after_size_id":180},{"code_idx":245,"before_size_id":180,"after_size_id":181},{"code_idx":246,"before_size_id":180,"after_size_id":182},{"code_idx":197,"before_size_id":182,"after_size_id":182},{"code_idx":198,"before_size_id":182,"after_size_id":183},{"code_idx":199,"before_size_id":183,"after_size_id":183},{"code_idx":238,"before_size_id":183,"after_size_id":184},{"code_idx":239,"before_size_id":184,"after_size_id":184},{"code_idx":240,"before_size_id":184,"after_size_id":184},{"code_idx":241,"before_size_id":184,"after_size_id":185},{"code_idx":242,"before_size_id":184,"after_size_id":186},{"code_idx":243,"before_size_id":186,"after_size_id":186},{"code_idx":244,"before_size_id":186,"after_size_id":186},{"code_idx":245,"before_size_id":186,"after_size_id":187},{"code_idx":246,"before_size_id":186,"after_size_id":188},{"code_idx":197,"before_size_id":188,"after_size_id":188},{"code_idx":198,"before_size_id":188,"after_size_id":189},{"code_idx":199,"before_size_id":189,"after_size_id":189},{"code_idx":238,"before_size_id":189,"after_size_id":190},{"code_idx":239,"before_size_id":190,"after_size_id":190},{"code_idx":240,"before_size_id":190,"after_size_id":190},{"code_idx":241,"before_size_id":190,"after_size_id":191},{"code_idx":242,"before_size_id":190,"after_size_id":192},{"code_idx":243,"before_size_id":192,"after_size_id":192},{"code_idx":244,"before_size_id":192,"after_size_id":192},{"code_idx":245,"before_size_id":192,"after_size_id":193},{"code_idx":246,"before_size_id":192,"after_size_id":194},{"code_idx":197,"before_size_id":194,"after_size_id":194},{"code_idx":198,"before_size_id":194,"after_size_id":195},{"code_idx":199,"before_size_id":195,"after_size_id":195},{"code_idx":238,"before_size_id":195,"after_size_id":196},{"code_idx":239,"before_size_id":196,"after_size_id":196},{"code_idx":240,"before_size_id":196,"after_size_id":196},{"code_idx":241,"before_size_id":196,"after_size_id":197},{"code_idx":242,"before_size_id":196,"after_size_id":198},{"code_idx":243,"before_size_id":198,"after_size_id":198},{"code_idx":244,"before_size_id":198,"after_size_id":198},{"code_idx":245,"before_size_id":198,"after_size_id":199},{"code_idx":246,"before_size_id":198,"after_size_id":200},{"code_idx":197,"before_size_id":200,"after_size_id":200},{"code_idx":198,"before_size_id":200,"after_size_id":201},{"code_idx":199,"before_size_id":201,"after_size_id":201},{"code_idx":238,"before_size_id":201,"after_size_id":202},{"code_idx":239,"before_size_id":202,"after_size_id":202},{"code_idx":240,"before_size_id":202,"after_size_id":202},{"code_idx":241,"before_size_id":202,"after_size_id":203},{"code_idx":242,"before_size_id":202,"after_size_id":204},{"code_idx":243,"before_size_id":204,"after_size_id":204},{"code_idx":244,"before_size_id":204,"after_size_id":204},{"code_idx":245,"before_size_id":204,"after_size_id":205},{"code_idx":246,"before_size_id":204,"after_size_id":206},{"code_idx":197,"before_size_id":206,"after_size_id":206},{"code_idx":198,"before_size_id":206,"after_size_id":207},{"code_idx":199,"before_size_id":207,"after_size_id":207},{"code_idx":238,"before_size_id":207,"after_size_id":208},{"code_idx":239,"before_size_id":208,"after_size_id":208},{"code_idx":240,"before_size_id":208,"after_size_id":208},{"code_idx":241,"before_size_id":208,"after_size_id":209},{"code_idx":242,"before_size_id":208,"after_size_id":210},{"code_idx":243,"before_size_id":210,"after_size_id":210},{"code_idx":244,"before_size_id":210,"after_size_id":210},{"code_idx":245,"before_size_id":210,"after_size_id":211},{"code_idx":246,"before_size_id":210,"after_size_id":212},{"code_idx":197,"before_size_id":212,"after_size_id":212},{"code_idx":198,"before_size_id":212,"after_size_id":213},{"code_idx":199,"before_size_id":213,"after_size_id":213},{"code_idx":238,"before_size_id":213,"after_size_id":214},{"code_idx":239,"before_size_id":214,"after_size_id":214},{"code_idx":240,"before_size_id":214,"after_size_id":214},{"code_idx":241,"before_size_id":214,"after_size_id":215},{"code_idx":242,"before_size_id":214,"after_size_id":216},{"code_idx":243,"before_size_id":216,"after_size_id":216},{"code_idx":244,"before_size_id":216,"after_size_id":216},{"code_idx":245,"before_size_id":216,"after_size_id":217},{"code_idx":246,"before_size_id":216,"after_size_id":218},{"code_idx":197,"before_size_id":218,"after_size_id":218},{"code_idx":198,"before_size_id":218,"after_size_id":219},{"code_idx":199,"before_size_id":219,"after_size_id":219},{"code_idx":238,"before_size_id":219,"after_size_id":220},{"code_idx":239,"before_size_id":220,"after_size_id":220},{"code_idx":240,"before_size_id":220,"after_size_id":220},{"code_idx":241,"before_size_id":220,"after_size_id":221},{"code_idx":242,"before_size_id":220,"after_size_id":222},{"code_idx":243,"before_size_id":222,"after_size_id":222},{"code_idx":244,"before_size_id":222,"after_size_id":222},{"code_idx":245,"before_size_id":222,"after_size_id":223},{"code_idx":246,"before_size_id":222,"after_size_id":224},{"code_idx":197,"before_size_id":224,"after_size_id":224},{"code_idx":198,"before_size_id":224,"after_size_id":225},{"code_idx":199,"before_size_id":225,"after_size_id":225},{"code_idx":238,"before_size_id":225,"after_size_id":226},{"code_idx":239,"before_size_id":226,"after_size_id":226},{"code_idx":240,"before_size_id":226,"after_size_id":226},{"code_idx":241,"before_size_id":226,"after_size_id":227},{"code_idx":242,"before_size_id":226,"after_size_id":228},{"code_idx":243,"before_size_id":228,"after_size_id":228},{"code_idx":244,"before_size_id":228,"after_size_id":228},{"code_idx":245,"before_size_id":228,"after_size_id":229},{"code_idx":246,"before_size_id":228,"after_size_id":230},{"code_idx":197,"before_size_id":230,"after_size_id":230},{"code_idx":198,"before_size_id":230,"after_size_id":231},{"code_idx":199,"before_size_id":231,"after_size_id":231},{"code_idx":238,"before_size_id":231,"after_size_id":232},{"code_idx":239,"before_size_id":232,"after_size_id":232},{"code_idx":240,"before_size_id":232,"after_size_id":232},{"code_idx":241,"before_size_id":232,"after_size_id":233},{"code_idx":242,"before_size_id":232,"after_size_id":234},{"code_idx":243,"before_size_id":234,"after_size_id":234},{"code_idx":244,"before_size_id":234,"after_size_id":234},{"code_idx":245,"before_size_id":234,"after_size_id":235},{"code_idx":246,"before_size_id":234,"after_size_id":236},{"code_idx":197,"before_size_id":236,"after_size_id":236},{"code_idx":198,"before_size_id":236,"after_size_id":237},{"code_idx":199,"before_size_id":237,"after_size_id":237},{"code_idx":238,"before_size_id":237,"after_size_id":238},{"code_idx":239,"before_size_id":238,"after_size_id":238},{"code_idx":240,"before_size_id":238,"after_size_id":238},{"code_idx":241,"before_size_id":238,"after_size_id":239},{"code_idx":242,"before_size_id":238,"after_size_id":240},{"code_idx":243,"before_size_id":240,"after_size_id":240},{"code_idx":244,"before_size_id":240,"after_size_id":240},{"code_idx":245,"before_size_id":240,"after_size_id":241},{"code_idx":246,"before_size_id":240,"after_size_id":242},{"code_idx":197,"before_size_id":242,"after_size_id":242},{"code_idx":198,"before_size_id":242,"after_size_id":243},{"code_idx":199,"before_size_id":243,"after_size_id":243},{"code_idx":238,"before_size_id":243,"after_size_id":244},{"code_idx":239,"before_size_id":244,"after_size_id":244},{"code_idx":240,"before_size_id":244,"after_size_id":244},{"code_idx":241,"before_size_id":244,"after_size_id":245},{"code_idx":242,"before_size_id":244,"after_size_id":246},{"code_idx":243,"before_size_id":246,"after_size_id":246},{"code_idx":244,"before_size_id":246,"after_size_id":246},{"code_idx":245,"before_size_id":246,"after_size_id":247},{"code_idx":246,"before_size_id":246,"after_size_id":248},{"code_idx":197,"before_size_id":248,"after_size_id":248},{"code_idx":198,"before_size_id":248,"after_size_id":249},{"code_idx":199,"before_size_id":249,"after_size_id":249},{"code_idx":238,"before_size_id":249,"after_size_id":250},{"code_idx":239,"before_size_id":250,"after_size_id":250},{"code_idx":240,"before_size_id":250,"after_size_id":250},{"code_idx":241,"before_size_id":250,"after_size_id":251},{"code_idx":242,"before_size_id":250,"after_size_id":252},{"code_idx":243,"before_size_id":252,"after_size_id":252},{"code_idx":244,"before_size_id":252,"after_size_id":252},{"code_idx":245,"before_size_id":252,"after_size_id":253},{"code_idx":246,"before_size_id":252,"after_size_id":254},{"code_idx":197,"before_size_id":254,"after_size_id":254},{"code_idx":198,"before_size_id":254,"after_size_id":255},{"code_idx":199,"before_size_id":255,"after_size_id":255},{"code_idx":238,"before_size_id":255,"after_size_id":256},{"code_idx":239,"before_size_id":256,"after_size_id":256},{"code_idx":240,"before_size_id":256,"after_size_id":256},{"code_idx":241,"before_size_id":256,"after_size_id":257},{"code_idx":242,"before_size_id":256,"after_size_id":258},{"code_idx":243,"before_size_id":258,"after_size_id":258},{"code_idx":244,"before_size_id":258,"after_size_id":258},{"code_idx":245,"before_size_id":258,"after_size_id":259},{"code_idx":246,"before_size_id":258,"after_size_id":260},{"code_idx":197,"before_size_id":260,"after_size_id":260},{"code_idx":198,"before_size_id":260,"after_size_id":261},{"code_idx":199,"before_size_id":261,"after_size_id":261},{"code_idx":238,"before_size_id":261,"after_size_id":262},{"code_idx":239,"before_size_id":262,"after_size_id":262},{"code_idx":240,"before_size_id":262,"after_size_id":262},{"code_idx":241,"before_size_id":262,"after_size_id":263},{"code_idx":242,"before_size_id":262,"after_size_id":264},{"code_idx":243,"before_size_id":264,"after_size_id":264},{"code_idx":244,"before_size_id":264,"after_size_id":264},{"code_idx":245,"before_size_id":264,"after_size_id":265},{"code_idx":246,"before_size_id":264,"after_size_id":266},{"code_idx":197,"before_size_id":266,"after_size_id":266},{"code_idx":198,"before_size_id":266,"after_size_id":267},{"code_idx":199,"before_size_id":267,"after_size_id":267},{"code_idx":238,"before_size_id":267,"after_size_id":268},{"code_idx":239,"before_size_id":268,"after_size_id":268},{"code_idx":240,"before_size_id":268,"after_size_id":268},{"code_idx":241,"before_size_id":268,"after_size_id":269},{"code_idx":242,"before_size_id":268,"after_size_id":270},{"code_idx":243,"before_size_id":270,"after_size_id":270},{"code_idx":244,"before_size_id":270,"after_size_id":270},{"code_idx":245,"before_size_id":270,"after_size_id":271},{"code_idx":246,"before_size_id":270,"after_size_id":272},{"code_idx":197,"before_size_id":272,"after_size_id":272},{"code_idx":198,"before_size_id":272,"after_size_id":273},{"code_idx":199,"before_size_id":273,"after_size_id":273},{"code_idx":238,"before_size_id":273,"after_size_id":274},{"code_idx":239,"before_size_id":274,"after_size_id":274},{"code_idx":240,"before_size_id":274,"after_size_id":274},{"code_idx":241,"before_size_id":274,"after_size_id":275},{"code_idx":242,"before_size_id":274,"after_size_id":276},{"code_idx":243,"before_size_id":276,"after_size_id":276},{"code_idx":244,"before_size_id":276,"after_size_id":276},{"code_idx":245,"before_size_id":276,"after_size_id":277},{"code_idx":246,"before_size_id":276,"after_size_id":278},{"code_idx":197,"before_size_id":278,"after_size_id":278},{"code_idx":198,"before_size_id":278,"after_size_id":279},{"code_idx":199,"before_size_id":279,"after_size_id":279},{"code_idx":238,"before_size_id":279,"after_size_id":280},{"code_idx":239,"before_size_id":280,"after_size_id":280},{"code_idx":240,"before_size_id":280,"after_size_id":280},{"code_idx":241,"before_size_id":280,"after_size_id":281},{"code_idx":242,"before_size_id":280,"after_size_id":282},{"code_idx":243,"before_size_id":282,"after_size_id":282},{"code_idx":244,"before_size_id":282,"after_size_id":282},{"code_idx":245,"before_size_id":282,"after_size_id":283},{"code_idx":246,"before_size_id":282,"after_size_id":284},{"code_idx":197,"before_size_id":284,"after_size_id":284},{"code_idx":198,"before_size_id":284,"after_size_id":285},{"code_idx":199,"before_size_id":285,"after_size_id":285},{"code_idx":238,"before_size_id":285,"after_size_id":286},{"code_idx":239,"before_size_id":286,"after_size_id":286},{"code_idx":240,"before_size_id":286,"after_size_id":286},{"code_idx":241,"before_size_id":286,"after_size_id":287},{"code_idx":242,"before_size_id":286,"after_size_id":288},{"code_idx":243,"before_size_id":288,"after_size_id":288},{"code_idx":244,"before_size_id":288,"after_size_id":288},{"code_idx":245,"before_size_id":288,"after_size_id":289},{"code_idx":246,"before_size_id":288,"after_size_id":290},{"code_idx":197,"before_size_id":290,"after_size_id":290},{"code_idx":198,"before_size_id":290,"after_size_id":291},{"code_idx":199,"before_size_id":291,"after_size_id":291},{"code_idx":238,"before_size_id":291,"after_size_id":292},{"code_idx":239,"before_size_id":292,"after_size_id":292},{"code_idx":240,"before_size_id":292,"after_size_id":292},{"code_idx":241,"before_size_id":292,"after_size_id":293},{"code_idx":242,"before_size_id":292,"after_size_id":294},{"code_idx":243,"before_size_id":294,"after_size_id":294},{"code_idx":244,"before_size_id":294,"after_size_id":294},{"code_idx":245,"before_size_id":294,"after_size_id":295},{"code_idx":246,"before_size_id":294,"after_size_id":296},{"code_idx":197,"before_size_id":296,"after_size_id":296},{"code_idx":198,"before_size_id":296,"after_size_id":297},{"code_idx":199,"before_size_id":297,"after_size_id":297},{"code_idx":238,"before_size_id":297,"after_size_id":298},{"code_idx":239,"before_size_id":298,"after_size_id":298},{"code_idx":240,"before_size_id":298,"after_size_id":298},{"code_idx":241,"before_size_id":298,"after_size_id":299},{"code_idx":242,"before_size_id":298,"after_size_id":300},{"code_idx":243,"before_size_id":300,"after_size_id":300},{"code_idx":244,"before_size_id":300,"after_size_id":300},{"code_idx":245,"before_size_id":300,"after_size_id":301},{"code_idx":246,"before_size_id":300,"after_size_id":302},{"code_idx":197,"before_size_id":302,"after_size_id":302},{"code_idx":198,"before_size_id":302,"after_size_id":303},{"code_idx":199,"before_size_id":303,"after_size_id":303},{"code_idx":238,"before_size_id":303,"after_size_id":304},{"code_idx":239,"before_size_id":304,"after_size_id":304},{"code_idx":240,"before_size_id":304,"after_size_id":304},{"code_idx":241,"before_size_id":304,"after_size_id":305},{"code_idx":242,"before_size_id":304,"after_size_id":306},{"code_idx":243,"before_size_id":306,"after_size_id":306},{"code_idx":244,"before_size_id":306,"after_size_id":306},{"code_idx":245,"before_size_id":306,"after_size_id":307},{"code_idx":246,"before_size_id":306,"after_size_id":308},{"code_idx":197,"before_size_id":308,"after_size_id":308},{"code_idx":198,"before_size_id":308,"after_size_id":309},{"code_idx":199,"before_size_id":309,"after_size_id":309},{"code_idx":238,"before_size_id":309,"after_size_id":310},{"code_idx":239,"before_size_id":310,"after_size_id":310},{"code_idx":240,"before_size_id":310,"after_size_id":310},{"code_idx":241,"before_size_id":310,"after_size_id":311},{"code_idx":242,"before_size_id":310,"after_size_id":312},{"code_idx":243,"before_size_id":312,"after_size_id":312},{"code_idx":244,"before_size_id":312,"after_size_id":312},{"code_idx":245,"before_size_id":312,"after_size_id":313},{"code_idx":246,"before_size_id":312,"after_size_id":314},{"code_idx":197,"before_size_id":314,"after_size_id":314},{"code_idx":198,"before_size_id":314,"after_size_id":315},{"code_idx":199,"before_size_id":315,"after_size_id":315},{"code_idx":238,"before_size_id":315,"after_size_id":316},{"code_idx":239,"before_size_id":316,"after_size_id":316},{"code_idx":240,"before_size_id":316,"after_size_id":316},{"code_idx":241,"before_size_id":316,"after_size_id":317},{"code_idx":242,"before_size_id":316,"after_size_id":318},{"code_idx":243,"before_size_id":318,"after_size_id":318},{"code_idx":244,"before_size_id":318,"after_size_id":318},{"code_idx":245,"before_size_id":318,"after_size_id":319},{"code_idx":246,"before_size_id":318,"after_size_id":320},{"code_idx":197,"before_size_id":320,"after_size_id":320},{"code_idx":198,"before_size_id":320,"after_size_id":321},{"code_idx":199,"before_size_id":321,"after_size_id":321},{"code_idx":238,"before_size_id":321,"after_size_id":322},{"code_idx":239,"before_size_id":322,"after_size_id":322},{"code_idx":240,"before_size_id":322,"after_size_id":322},{"code_idx":241,"before_size_id":322,"after_size_id":323},{"code_idx":242,"before_size_id":322,"after_size_id":324},{"code_idx":243,"before_size_id":324,"after_size_id":324},{"code_idx":244,"before_size_id":324,"after_size_id":324},{"code_idx":245,"before_size_id":324,"after_size_id":325},{"code_idx":246,"before_size_id":324,"after_size_id":326},{"code_idx":197,"before_size_id":326,"after_size_id":326},{"code_idx":198,"before_size_id":326,"after_size_id":327},{"code_idx":199,"before_size_id":327,"after_size_id":327},{"code_idx":238,"before_size_id":327,"after_size_id":328},{"code_idx":239,"before_size_id":328,"after_size_id":328},{"code_idx":240,"before_size_id":328,"after_size_id":328},{"code_idx":241,"before_size_id":328,"after_size_id":329},{"code_idx":242,"before_size_id":328,"after_size_id":330},{"code_idx":243,"before_size_id":330,"after_size_id":330},{"code_idx":244,"before_size_id":330,"after_size_id":330},{"code_idx":245,"before_size_id":330,"after_size_id":331},{"code_idx":246,"before_size_id":330,"after_size_id":332},{"code_idx":197,"before_size_id":332,"after_size_id":332},{"code_idx":198,"before_size_id":332,"after_size_id":333},{"code_idx":199,"before_size_id":333,"after_size_id":333},{"code_idx":238,"before_size_id":333,"after_size_id":334},{"code_idx":239,"before_size_id":334,"after_size_id":334},{"code_idx":240,"before_size_id":334,"after_size_id":334},{"code_idx":241,"before_size_id":334,"after_size_id":335},{"code_idx":242,"before_size_id":334,"after_size_id":336},{"code_idx":243,"before_size_id":336,"after_size_id":336},{"code_idx":244,"before_size_id":336,"after_size_id":336},{"code_idx":245,"before_size_id":336,"after_size_id":337},{"code_idx":246,"before_size_id":336,"after_size_id":338},{"code_idx":197,"before_size_id":338,"after_size_id":338},{"code_idx":198,"before_size_id":338,"after_size_id":339},{"code_idx":199,"before_size_id":339,"after_size_id":339},{"code_idx":238,"before_size_id":339,"after_size_id":340},{"code_idx":239,"before_size_id":340,"after_size_id":340},{"code_idx":240,"before_size_id":340,"after_size_id":340},{"code_idx":241,"before_size_id":340,"after_size_id":341},{"code_idx":242,"before_size_id":340,"after_size_id":342},{"code_idx":243,"before_size_id":342,"after_size_id":342},{"code_idx":244,"before_size_id":342,"after_size_id":342},{"code_idx":245,"before_size_id":342,"after_size_id":343},{"code_idx":246,"before_size_id":342,"after_size_id":344},{"code_idx":197,"before_size_id":344,"after_size_id":344},{"code_idx":198,"before_size_id":344,"after_size_id":345},{"code_idx":199,"before_size_id":345,"after_size_id":345},{"code_idx":238,"before_size_id":345,"after_size_id":346},{"code_idx":239,"before_size_id":346,"after_size_id":346},{"code_idx":240,"before_size_id":346,"after_size_id":346},{"code_idx":241,"before_size_id":346,"after_size_id":347},{"code_idx":242,"before_size_id":346,"after_size_id":348},{"code_idx":243,"before_size_id":348,"after_size_id":348},{"code_idx":244,"before_size_id":348,"after_size_id":348},{"code_idx":245,"before_size_id":348,"after_size_id":349},{"code_idx":246,"before_size_id":348,"after_size_id":350},{"code_idx":197,"before_size_id":350,"after_size_id":350},{"code_idx":198,"before_size_id":350,"after_size_id":351},{"code_idx":199,"before_size_id":351,"after_size_id":351},{"code_idx":238,"before_size_id":351,"after_size_id":352},{"code_idx":239,"before_size_id":352,"after_size_id":352},{"code_idx":240,"before_size_id":352,"after_size_id":352},{"code_idx":241,"before_size_id":352,"after_size_id":353},{"code_idx":242,"before_size_id":352,"after_size_id":354},{"code_idx":243,"before_size_id":354,"after_size_id":354},{"code_idx":244,"before_size_id":354,"after_size_id":354},{"code_idx":245,"before_size_id":354,"after_size_id":355},{"code_idx":246,"before_size_id":354,"after_size_id":356},{"code_idx":197,"before_size_id":356,"after_size_id":356},{"code_idx":198,"before_size_id":356,"after_size_id":357},{"code_idx":199,"before_size_id":357,"after_size_id":357},{"code_idx":238,"before_size_id":357,"after_size_id":358},{"code_idx":239,"before_size_id":358,"after_size_id":358},{"code_idx":240,"before_size_id":358,"after_size_id":358},{"code_idx":241,"before_size_id":358,"after_size_id":359},{"code_idx":242,"before_size_id":358,"after_size_id":360},{"code_idx":243,"before_size_id":360,"after_size_id":360},{"code_idx":244,"before_size_id":360,"after_size_id":360},{"code_idx":245,"before_size_id":360,"after_size_id":361},{"code_idx":246,"before_size_id":360,"after_size_id":362},{"code_idx":197,"before_size_id":362,"after_size_id":362},{"code_idx":198,"before_size_id":362,"after_size_id":363},{"code_idx":199,"before_size_id":363,"after_size_id":363},{"code_idx":238,"before_size_id":363,"after_size_id":364},{"code_idx":239,"before_size_id":364,"after_size_id":364},{"code_idx":240,"before_size_id":364,"after_size_id":364},{"code_idx":241,"before_size_id":364,"after_size_id":365},{"code_idx":242,"before_size_id":364,"after_size_id":366},{"code_idx":243,"before_size_id":366,"after_size_id":366},{"code_idx":244,"before_size_id":366,"after_size_id":366},{"code_idx":245,"before_size_id":366,"after_size_id":367},{"code_idx":246,"before_size_id":366,"after_size_id":368},{"code_idx":197,"before_size_id":368,"after_size_id":368},{"code_idx":198,"before_size_id":368,"after_size_id":369},{"code_idx":199,"before_size_id":369,"after_size_id":369},{"code_idx":238,"before_size_id":369,"after_size_id":370},{"code_idx":239,"before_size_id":370,"after_size_id":370},{"code_idx":240,"before_size_id":370,"after_size_id":370},{"code_idx":241,"before_size_id":370,"after_size_id":371},{"code_idx":242,"before_size_id":370,"after_size_id":372},{"code_idx":243,"before_size_id":372,"after_size_id":372},{"code_idx":244,"before_size_id":372,"after_size_id":372},{"code_idx":245,"before_size_id":372,"after_size_id":373},{"code_idx":246,"before_size_id":372,"after_size_id":374},{"code_idx":197,"before_size_id":374,"after_size_id":374},{"code_idx":198,"before_size_id":374,"after_size_id":375},{"code_idx":199,"before_size_id":375,"after_size_id":375},{"code_idx":238,"before_size_id":375,"after_size_id":376},{"code_idx":239,"before_size_id":376,"after_size_id":376},{"code_idx":240,"before_size_id":376,"after_size_id":376},{"code_idx":241,"before_size_id":376,"after_size_id":377},{"code_idx":242,"before_size_id":376,"after_size_id":378},{"code_idx":243,"before_size_id":378,"after_size_id":378},{"code_idx":244,"before_size_id":378,"after_size_id":378},{"code_idx":245,"before_size_id":378,"after_size_id":379},{"code_idx":246,"before_size_id":378,"after_size_id":380},{"code_idx":197,"before_size_id":380,"after_size_id":380},{"code_idx":198,"before_size_id":380,"after_size_id":381},{"code_idx":199,"before_size_id":381,"after_size_id":381},{"code_idx":238,"before_size_id":381,"after_size_id":382},{"code_idx":239,"before_size_id":382,"after_size_id":382},{"code_idx":240,"before_size_id":382,"after_size_id":382},{"code_idx":241,"before_size_id":382,"after_size_id":383},{"code_idx":242,"before_size_id":382,"after_size_id":384},{"code_idx":243,"before_size_id":384,"after_size_id":384},{"code_idx":244,"before_size_id":384,"after_size_id":384},{"code_idx":245,"before_size_id":384,"after_size_id":385},{"code_idx":246,"before_size_id":384,"after_size_id":386},{"code_idx":197,"before_size_id":386,"after_size_id":386},{"code_idx":198,"before_size_id":386,"after_size_id":387},{"code_idx":199,"before_size_id":387,"after_size_id":387},{"code_idx":238,"before_size_id":387,"after_size_id":388},{"code_idx":239,"before_size_id":388,"after_size_id":388},{"code_idx":240,"before_size_id":388,"after_size_id":388},{"code_idx":241,"before_size_id":388,"after_size_id":389},{"code_idx":242,"before_size_id":388,"after_size_id":390},{"code_idx":243,"before_size_id":390,"after_size_id":390},{"code_idx":244,"before_size_id":390,"after_size_id":390},{"code_idx":245,"before_size_id":390,"after_size_id":391},{"code_idx":246,"before_size_id":390,"after_size_id":392},{"code_idx":197,"before_size_id":392,"after_size_id":392},{"code_idx":198,"before_size_id":392,"after_size_id":393},{"code_idx":199,"before_size_id":393,"after_size_id":393},{"code_idx":238,"before_size_id":393,"after_size_id":394},{"code_idx":239,"before_size_id":394,"after_size_id":394},{"code_idx":240,"before_size_id":394,"after_size_id":394},{"code_idx":241,"before_size_id":394,"after_size_id":395},{"code_idx":242,"before_size_id":394,"after_size_id":396},{"code_idx":243,"before_size_id":396,"after_size_id":396},{"code_idx":244,"before_size_id":396,"after_size_id":396},{"code_idx":245,"before_size_id":396,"after_size_id":397},{"code_idx":246,"before_size_id":396,"after_size_id":398},{"code_idx":197,"before_size_id":398,"after_size_id":398},{"code_idx":198,"before_size_id":398,"after_size_id":399},{"code_idx":199,"before_size_id":399,"after_size_id":399},{"code_idx":238,"before_size_id":399,"after_size_id":400},{"code_idx":239,"before_size_id":400,"after_size_id":400},{"code_idx":240,"before_size_id":400,"after_size_id":400},{"code_idx":241,"before_size_id":400,"after_size_id":401},{"code_idx":242,"before_size_id":400,"after_size_id":402},{"code_idx":243,"before_size_id":402,"after_size_id":402},{"code_idx":244,"before_size_id":402,"after_size_id":402},{"code_idx":245,"before_size_id":402,"after_size_id":403},{"code_idx":246,"before_size_id":402,"after_size_id":404},{"code_idx":197,"before_size_id":404,"after_size_id":404},{"code_idx":198,"before_size_id":404,"after_size_id":405},{"code_idx":199,"before_size_id":405,"after_size_id":405},{"code_idx":238,"before_size_id":405,"after_size_id":406},{"code_idx":239,"before_size_id":406,"after_size_id":406},{"code_idx":240,"before_size_id":406,"after_size_id":406},{"code_idx":241,"before_size_id":406,"after_size_id":407},{"code_idx":242,"before_size_id":406,"after_size_id":408},{"code_idx":243,"before_size_id":408,"after_size_id":408},{"code_idx":244,"before_size_id":408,"after_size_id":408},{"code_idx":245,"before_size_id":408,"after_size_id":409},{"code_idx":246,"before_size_id":408,"after_size_id":410},{"code_idx":197,"before_size_id":410,"after_size_id":410},{"code_idx":198,"before_size_id":410,"after_size_id":411},{"code_idx":199,"before_size_id":411,"after_size_id":411},{"code_idx":238,"before_size_id":411,"after_size_id":412},{"code_idx":239,"before_size_id":412,"after_size_id":412},{"code_idx":240,"before_size_id":412,"after_size_id":412},{"code_idx":241,"before_size_id":412,"after_size_id":413},{"code_idx":242,"before_size_id":412,"after_size_id":414},{"code_idx":243,"before_size_id":414,"after_size_id":414},{"code_idx":244,"before_size_id":414,"after_size_id":414},{"code_idx":245,"before_size_id":414,"after_size_id":415},{"code_idx":246,"before_size_id":414,"after_size_id":416},{"code_idx":197,"before_size_id":416,"after_size_id":416},{"code_idx":198,"before_size_id":416,"after_size_id":417},{"code_idx":199,"before_size_id":417,"after_size_id":417},{"code_idx":238,"before_size_id":417,"after_size_id":418},{"code_idx":239,"before_size_id":418,"after_size_id":418},{"code_idx":240,"before_size_id":418,"after_size_id":418},{"code_idx":241,"before_size_id":418,"after_size_id":419},{"code_idx":242,"before_size_id":418,"after_size_id":420},{"code_idx":243,"before_size_id":420,"after_size_id":420},{"code_idx":244,"before_size_id":420,"after_size_id":420},{"code_idx":245,"before_size_id":420,"after_size_id":421},{"code_idx":246,"before_size_id":420,"after_size_id":422},{"code_idx":197,"before_size_id":422,"after_size_id":422},{"code_idx":198,"before_size_id":422,"after_size_id":423},{"code_idx":199,"before_size_id":423,"after_size_id":423},{"code_idx":238,"before_size_id":423,"after_size_id":424},{"code_idx":239,"before_size_id":424,"after_size_id":424},{"code_idx":240,"before_size_id":424,"after_size_id":424},{"code_idx":241,"before_size_id":424,"after_size_id":425},{"code_idx":242,"before_size_id":424,"after_size_id":426},{"code_idx":243,"before_size_id":426,"after_size_id":426},{"code_idx":244,"before_size_id":426,"after_size_id":426},{"code_idx":245,"before_size_id":426,"after_size_id":427},{"code_idx":246,"before_size_id":426,"after_size_id":428},{"code_idx":197,"before_size_id":428,"after_size_id":428},{"code_idx":198,"before_size_id":428,"after_size_id":429},{"code_idx":199,"before_size_id":429,"after_size_id":429},{"code_idx":238,"before_size_id":429,"after_size_id":430},{"code_idx":239,"before_size_id":430,"after_size_id":430},{"code_idx":240,"before_size_id":430,"after_size_id":430},{"code_idx":241,"before_size_id":430,"after_size_id":431},{"code_idx":242,"before_size_id":430,"after_size_id":432},{"code_idx":243,"before_size_id":432,"after_size_id":432},{"code_idx":244,"before_size_id":432,"after_size_id":432},{"code_idx":245,"before_size_id":432,"after_size_id":433},{"code_idx":246,"before_size_id":432,"after_size_id":434},{"code_idx":197,"before_size_id":434,"after_size_id":434},{"code_idx":198,"before_size_id":434,"after_size_id":435},{"code_idx":199,"before_size_id":435,"after_size_id":435},{"code_idx":238,"before_size_id":435,"after_size_id":436},{"code_idx":239,"before_size_id":436,"after_size_id":436},{"code_idx":240,"before_size_id":436,"after_size_id":436},{"code_idx":241,"before_size_id":436,"after_size_id":437},{"code_idx":242,"before_size_id":436,"after_size_id":438},{"code_idx":243,"before_size_id":438,"after_size_id":438},{"code_idx":244,"before_size_id":438,"after_size_id":438},{"code_idx":245,"before_size_id":438,"after_size_id":439},{"code_idx":246,"before_size_id":438,"after_size_id":440},{"code_idx":197,"before_size_id":440,"after_size_id":440},{"code_idx":198,"before_size_id":440,"after_size_id":441},{"code_idx":199,"before_size_id":441,"after_size_id":441},{"code_idx":238,"before_size_id":441,"after_size_id":442},{"code_idx":239,"before_size_id":442,"after_size_id":442},{"code_idx":240,"before_size_id":442,"after_size_id":442},{"code_idx":241,"before_size_id":442,"after_size_id":443},{"code_idx":242,"before_size_id":442,"after_size_id":444},{"code_idx":243,"before_size_id":444,"after_size_id":444},{"code_idx":244,"before_size_id":444,"after_size_id":444},{"code_idx":245,"before_size_id":444,"after_size_id":445},{"code_idx":246,"before_size_id":444,"after_size_id":446},{"code_idx":197,"before_size_id":446,"after_size_id":446},{"code_idx":198,"before_size_id":446,"after_size_id":447},{"code_idx":199,"before_size_id":447,"after_size_id":447},{"code_idx":238,"before_size_id":447,"after_size_id":448},{"code_idx":239,"before_size_id":448,"after_size_id":448},{"code_idx":240,"before_size_id":448,"after_size_id":448},{"code_idx":241,"before_size_id":448,"after_size_id":449},{"code_idx":242,"before_size_id":448,"after_size_id":450},{"code_idx":243,"before_size_id":450,"after_size_id":450},{"code_idx":244,"before_size_id":450,"after_size_id":450},{"code_idx":245,"before_size_id":450,"after_size_id":451},{"code_idx":246,"before_size_id":450,"after_size_id":452},{"code_idx":197,"before_size_id":452,"after_size_id":452},{"code_idx":198,"before_size_id":452,"after_size_id":453},{"code_idx":199,"before_size_id":453,"after_size_id":453},{"code_idx":238,"before_size_id":453,"after_size_id":454},{"code_idx":239,"before_size_id":454,"after_size_id":454},{"code_idx":240,"before_size_id":454,"after_size_id":454},{"code_idx":241,"before_size_id":454,"after_size_id":455},{"code_idx":242,"before_size_id":454,"after_size_id":456},{"code_idx":243,"before_size_id":456,"after_size_id":456},{"code_idx":244,"before_size_id":456,"after_size_id":456},{"code_idx":245,"before_size_id":456,"after_size_id":457},{"code_idx":246,"before_size_id":456,"after_size_id":458},{"code_idx":197,"before_size_id":458,"after_size_id":458},{"code_idx":198,"before_size_id":458,"after_size_id":459},{"code_idx":199,"before_size_id":459,"after_size_id":459},{"code_idx":238,"before_size_id":459,"after_size_id":460},{"code_idx":239,"before_size_id":460,"after_size_id":460},{"code_idx":240,"before_size_id":460,"after_size_id":460},{"code_idx":241,"before_size_id":460,"after_size_id":461},{"code_idx":242,"before_size_id":460,"after_size_id":462},{"code_idx":243,"before_size_id":462,"after_size_id":462},{"code_idx":244,"before_size_id":462,"after_size_id":462},{"code_idx":245,"before_size_id":462,"after_size_id":463},{"code_idx":246,"before_size_id":462,"after_size_id":464},{"code_idx":197,"before_size_id":464,"after_size_id":464},{"code_idx":198,"before_size_id":464,"after_size_id":465},{"code_idx":199,"before_size_id":465,"after_size_id":465},{"code_idx":238,"before_size_id":465,"after_size_id":466},{"code_idx":239,"before_size_id":466,"after_size_id":466},{"code_idx":240,"before_size_id":466,"after_size_id":466},{"code_idx":241,"before_size_id":466,"after_size_id":467},{"code_idx":242,"before_size_id":466,"after_size_id":468},{"code_idx":243,"before_size_id":468,"after_size_id":468},{"code_idx":244,"before_size_id":468,"after_size_id":468},{"code_idx":245,"before_size_id":468,"after_size_id":469},{"code_idx":246,"before_size_id":468,"after_size_id":470},{"code_idx":197,"before_size_id":470,"after_size_id":470},{"code_idx":198,"before_size_id":470,"after_size_id":471},{"code_idx":199,"before_size_id":471,"after_size_id":471},{"code_idx":238,"before_size_id":471,"after_size_id":472},{"code_idx":239,"before_size_id":472,"after_size_id":472},{"code_idx":240,"before_size_id":472,"after_size_id":472},{"code_idx":241,"before_size_id":472,"after_size_id":473},{"code_idx":242,"before_size_id":472,"after_size_id":474},{"code_idx":243,"before_size_id":474,"after_size_id":474},{"code_idx":244,"before_size_id":474,"after_size_id":474},{"code_idx":245,"before_size_id":474,"after_size_id":475},{"code_idx":246,"before_size_id":474,"after_size_id":476},{"code_idx":197,"before_size_id":476,"after_size_id":476},{"code_idx":198,"before_size_id":476,"after_size_id":477},{"code_idx":199,"before_size_id":477,"after_size_id":477},{"code_idx":238,"before_size_id":477,"after_size_id":478},{"code_idx":239,"before_size_id":478,"after_size_id":478},{"code_idx":240,"before_size_id":478,"after_size_id":478},{"code_idx":241,"before_size_id":478,"after_size_id":479},{"code_idx":242,"before_size_id":478,"after_size_id":480},{"code_idx":243,"before_size_id":480,"after_size_id":480},{"code_idx":244,"before_size_id":480,"after_size_id":480},{"code_idx":245,"before_size_id":480,"after_size_id":481},{"code_idx":246,"before_size_id":480,"after_size_id":482},{"code_idx":197,"before_size_id":482,"after_size_id":482},{"code_idx":198,"before_size_id":482,"after_size_id":483},{"code_idx":199,"before_size_id":483,"after_size_id":483},{"code_idx":238,"before_size_id":483,"after_size_id":484},{"code_idx":239,"before_size_id":484,"after_size_id":484},{"code_idx":240,"before_size_id":484,"after_size_id":484},{"code_idx":241,"before_size_id":484,"after_size_id":485},{"code_idx":242,"before_size_id":484,"after_size_id":486},{"code_idx":243,"before_size_id":486,"after_size_id":486},{"code_idx":244,"before_size_id":486,"after_size_id":486},{"code_idx":245,"before_size_id":486,"after_size_id":487},{"code_idx":246,"before_size_id":486,"after_size_id":488},{"code_idx":197,"before_size_id":488,"after_size_id":488},{"code_idx":198,"before_size_id":488,"after_size_id":489},{"code_idx":199,"before_size_id":489,"after_size_id":489},{"code_idx":238,"before_size_id":489,"after_size_id":490},{"code_idx":239,"before_size_id":490,"after_size_id":490},{"code_idx":240,"before_size_id":490,"after_size_id":490},{"code_idx":241,"before_size_id":490,"after_size_id":491},{"code_idx":242,"before_size_id":490,"after_size_id":492},{"code_idx":243,"before_size_id":492,"after_size_id":492},{"code_idx":244,"before_size_id":492,"after_size_id":492},{"code_idx":245,"before_size_id":492,"after_size_id":493},{"code_idx":246,"before_size_id":492,"after_size_id":494},{"code_idx":247,"before_size_id":113,"after_size_id":494},{"code_idx":248,"before_size_id":494,"after_size_id":495},{"code_idx":249,"before_size_id":496,"after_size_id":497},{"code_idx":250,"before_size_id":494,"after_size_id":498},{"code_idx":251,"before_size_id":498,"after_size_id":499},{"code_idx":252,"before_size_id":499,"after_size_id":499},{"code_idx":253,"before_size_id":499,"after_size_id":499},{"code_idx":254,"before_size_id":499,"after_size_id":500},{"code_idx":255,"before_size_id":500,"after_size_id":500},{"code_idx":256,"before_size_id":500,"after_size_id":501},{"code_idx":257,"before_size_id":501,"after_size_id":501},{"code_idx":258,"before_size_id":501,"after_size_id":502},{"code_idx":259,"before_size_id":502,"after_size_id":502},{"code_idx":260,"before_size_id":502,"after_size_id":502},{"code_idx":261,"before_size_id":502,"after_size_id":502},{"code_idx":262,"before_size_id":502,"after_size_id":502},{"code_idx":263,"before_size_id":502,"after_size_id":502},{"code_idx":264,"before_size_id":502,"after_size_id":502},{"code_idx":265,"before_size_id":502,"after_size_id":503},{"code_idx":266,"before_size_id":503,"after_size_id":503},{"code_idx":267,"before_size_id":503,"after_size_id":503},{"code_idx":268,"before_size_id":503,"after_size_id":503},{"code_idx":269,"before_size_id":503,"after_size_id":503},{"code_idx":270,"before_size_id":503,"after_size_id":504},{"code_idx":271,"before_size_id":504,"after_size_id":505},{"code_idx":266,"before_size_id":505,"after_size_id":505},{"code_idx":267,"before_size_id":505,"after_size_id":505},{"code_idx":268,"before_size_id":505,"after_size_id":505},{"code_idx":269,"before_size_id":505,"after_size_id":505},{"code_idx":270,"before_size_id":505,"after_size_id":506},{"code_idx":271,"before_size_id":506,"after_size_id":507},{"code_idx":266,"before_size_id":507,"after_size_id":507},{"code_idx":267,"before_size_id":507,"after_size_id":507},{"code_idx":268,"before_size_id":507,"after_size_id":507},{"code_idx":269,"before_size_id":507,"after_size_id":507},{"code_idx":270,"before_size_id":507,"after_size_id":508},{"code_idx":271,"before_size_id":508,"after_size_id":509},{"code_idx":266,"before_size_id":509,"after_size_id":509},{"code_idx":267,"before_size_id":509,"after_size_id":509},{"code_idx":268,"before_size_id":509,"after_size_id":509},{"code_idx":269,"before_size_id":509,"after_size_id":509},{"code_idx":270,"before_size_id":509,"after_size_id":510},{"code_idx":271,"before_size_id":510,"after_size_id":511},{"code_idx":266,"before_size_id":511,"after_size_id":511},{"code_idx":267,"before_size_id":511,"after_size_id":511},{"code_idx":268,"before_size_id":511,"after_size_id":511},{"code_idx":269,"before_size_id":511,"after_size_id":511},{"code_idx":270,"before_size_id":511,"after_size_id":512},{"code_idx":271,"before_size_id":512,"after_size_id":513},{"code_idx":272,"before_size_id":499,"after_size_id":513},{"code_idx":273,"before_size_id":513,"after_size_id":514},{"code_idx":274,"before_size_id":111,"after_size_id":515},{"code_idx":275,"before_size_id":111,"after_size_id":515},{"code_idx":276,"before_size_id":515,"after_size_id":515},{"code_idx":277,"before_size_id":515,"after_size_id":515},{"code_idx":278,"before_size_id":515,"after_size_id":515},{"code_idx":279,"before_size_id":515,"after_size_id":515},{"code_idx":280,"before_size_id":7,"after_size_id":516},{"code_idx":281,"before_size_id":516,"after_size_id":517},{"code_idx":282,"before_size_id":517,"after_size_id":517},{"code_idx":283,"before_size_id":517,"after_size_id":517},{"code_idx":284,"before_size_id":516,"after_size_id":518},{"code_idx":285,"before_size_id":518,"after_size_id":518},{"code_idx":286,"before_size_id":518,"after_size_id":518},{"code_idx":287,"before_size_id":519,"after_size_id":519},{"code_idx":288,"before_size_id":518,"after_size_id":520},{"code_idx":289,"before_size_id":518,"after_size_id":520},{"code_idx":290,"before_size_id":520,"after_size_id":521},{"code_idx":291,"before_size_id":521,"after_size_id":522},{"code_idx":292,"before_size_id":522,"after_size_id":522},{"code_idx":293,"before_size_id":522,"after_size_id":522},{"code_idx":294,"before_size_id":522,"after_size_id":523},{"code_idx":295,"before_size_id":522,"after_size_id":523},{"code_idx":296,"before_size_id":523,"after_size_id":524},{"code_idx":297,"before_size_id":524,"after_size_id":524},{"code_idx":298,"before_size_id":523,"after_size_id":524},{"code_idx":299,"before_size_id":524,"after_size_id":524},{"code_idx":300,"before_size_id":524,"after_size_id":524},{"code_idx":301,"before_size_id":524,"after_size_id":524},{"code_idx":302,"before_size_id":521,"after_size_id":525},{"code_idx":303,"before_size_id":525,"after_size_id":525},{"code_idx":304,"before_size_id":525,"after_size_id":525},{"code_idx":305,"before_size_id":525,"after_size_id":525},{"code_idx":306,"before_size_id":525,"after_size_id":525},{"code_idx":307,"before_size_id":525,"after_size_id":525},{"code_idx":308,"before_size_id":525,"after_size_id":525},{"code_idx":309,"before_size_id":525,"after_size_id":525},{"code_idx":310,"before_size_id":525,"after_size_id":525},{"code_idx":311,"before_size_id":525,"after_size_id":525},{"code_idx":312,"before_size_id":525,"after_size_id":525},{"code_idx":313,"before_size_id":525,"after_size_id":526},{"code_idx":314,"before_size_id":526,"after_size_id":526},{"code_idx":315,"before_size_id":526,"after_size_id":526},{"code_idx":316,"before_size_id":526,"after_size_id":526},{"code_idx":29,"before_size_id":526,"after_size_id":526},{"code_idx":30,"before_size_id":526,"after_size_id":526},{"code_idx":31,"before_size_id":526,"after_size_id":527},{"code_idx":317,"before_size_id":526,"after_size_id":527},{"code_idx":318,"before_size_id":527,"after_size_id":527},{"code_idx":319,"before_size_id":527,"after_size_id":527},{"code_idx":320,"before_size_id":527,"after_size_id":527},{"code_idx":321,"before_size_id":527,"after_size_id":527},{"code_idx":103,"before_size_id":527,"after_size_id":527},{"code_idx":104,"before_size_id":528,"after_size_id":528},{"code_idx":105,"before_size_id":528,"after_size_id":528},{"code_idx":106,"before_size_id":527,"after_size_id":529},{"code_idx":107,"before_size_id":529,"after_size_id":530},{"code_idx":108,"before_size_id":531,"after_size_id":531},{"code_idx":109,"before_size_id":531,"after_size_id":531},{"code_idx":110,"before_size_id":529,"after_size_id":532},{"code_idx":111,"before_size_id":532,"after_size_id":532},{"code_idx":112,"before_size_id":532,"after_size_id":532},{"code_idx":113,"before_size_id":532,"after_size_id":532},{"code_idx":114,"before_size_id":532,"after_size_id":533},{"code_idx":115,"before_size_id":533,"after_size_id":534},{"code_idx":116,"before_size_id":534,"after_size_id":535},{"code_idx":117,"before_size_id":535,"after_size_id":536},{"code_idx":118,"before_size_id":533,"after_size_id":536},{"code_idx":322,"before_size_id":526,"after_size_id":536},{"code_idx":323,"before_size_id":536,"after_size_id":536},{"code_idx":324,"before_size_id":536,"after_size_id":536},{"code_idx":325,"before_size_id":536,"after_size_id":536},{"code_idx":326,"before_size_id":536,"after_size_id":536},{"code_idx":327,"before_size_id":536,"after_size_id":536},{"code_idx":125,"before_size_id":536,"after_size_id":536},{"code_idx":126,"before_size_id":536,"after_size_id":536},{"code_idx":127,"before_size_id":536,"after_size_id":536},{"code_idx":128,"before_size_id":536,"after_size_id":536},{"code_idx":129,"before_size_id":536,"after_size_id":536},{"code_idx":130,"before_size_id":536,"after_size_id":536},{"code_idx":131,"before_size_id":536,"after_size_id":536},{"code_idx":132,"before_size_id":536,"after_size_id":536},{"code_idx":133,"before_size_id":536,"after_size_id":536},{"code_idx":134,"before_size_id":536,"after_size_id":536},{"code_idx":135,"before_size_id":536,"after_size_id":537},{"code_idx":136,"before_size_id":536,"after_size_id":537},{"code_idx":328,"before_size_id":536,"after_size_id":537},{"code_idx":329,"before_size_id":537,"after_size_id":537},{"code_idx":22,"before_size_id":537,"after_size_id":538},{"code_idx":23,"before_size_id":538,"after_size_id":538},{"code_idx":24,"before_size_id":538,"after_size_id":538},{"code_idx":25,"before_size_id":538,"after_size_id":538},{"code_idx":26,"before_size_id":538,"after_size_id":539},{"code_idx":8,"before_size_id":539,"after_size_id":539},{"code_idx":9,"before_size_id":539,"after_size_id":539},{"code_idx":10,"before_size_id":539,"after_size_id":539},{"code_idx":11,"before_size_id":539,"after_size_id":539},{"code_idx":12,"before_size_id":539,"after_size_id":539},{"code_idx":13,"before_size_id":539,"after_size_id":539},{"code_idx":14,"before_size_id":539,"after_size_id":539},{"code_idx":15,"before_size_id":539,"after_size_id":539},{"code_idx":27,"before_size_id":538,"after_size_id":539},{"code_idx":330,"before_size_id":537,"after_size_id":539},{"code_idx":331,"before_size_id":539,"after_size_id":539},{"code_idx":3,"before_size_id":539,"after_size_id":540},{"code_idx":4,"before_size_id":540,"after_size_id":540},{"code_idx":5,"before_size_id":540,"after_size_id":540},{"code_idx":6,"before_size_id":540,"after_size_id":540},{"code_idx":7,"before_size_id":540,"after_size_id":541},{"code_idx":8,"before_size_id":541,"after_size_id":541},{"code_idx":9,"before_size_id":541,"after_size_id":541},{"code_idx":10,"before_size_id":541,"after_size_id":541},{"code_idx":11,"before_size_id":541,"after_size_id":541},{"code_idx":12,"before_size_id":541,"after_size_id":541},{"code_idx":13,"before_size_id":541,"after_size_id":541},{"code_idx":14,"before_size_id":541,"after_size_id":541},{"code_idx":15,"before_size_id":541,"after_size_id":541},{"code_idx":16,"before_size_id":540,"after_size_id":541},{"code_idx":332,"before_size_id":539,"after_size_id":541},{"code_idx":333,"before_size_id":541,"after_size_id":541},{"code_idx":22,"before_size_id":541,"after_size_id":542},{"code_idx":23,"before_size_id":542,"after_size_id":542},{"code_idx":24,"before_size_id":542,"after_size_id":542},{"code_idx":25,"before_size_id":542,"after_size_id":542},{"code_idx":26,"before_size_id":542,"after_size_id":543},{"code_idx":8,"before_size_id":543,"after_size_id":543},{"code_idx":9,"before_size_id":543,"after_size_id":543},{"code_idx":10,"before_size_id":543,"after_size_id":543},{"code_idx":11,"before_size_id":543,"after_size_id":543},{"code_idx":12,"before_size_id":543,"after_size_id":543},{"code_idx":13,"before_size_id":543,"after_size_id":543},{"code_idx":14,"before_size_id":543,"after_size_id":543},{"code_idx":15,"before_size_id":543,"after_size_id":543},{"code_idx":27,"before_size_id":542,"after_size_id":543},{"code_idx":334,"before_size_id":541,"after_size_id":543},{"code_idx":335,"before_size_id":543,"after_size_id":544},{"code_idx":336,"before_size_id":544,"after_size_id":545},{"code_idx":337,"before_size_id":545,"after_size_id":546},{"code_idx":338,"before_size_id":546,"after_size_id":547},{"code_idx":339,"before_size_id":537,"after_size_id":547}]},"add_liquidity":{"profilees":[{"code_idx":29,"before_size_id":0,"after_size_id":0},{"code_idx":30,"before_size_id":0,"after_size_id":0},{"code_idx":31,"before_size_id":0,"after_size_id":1},{"code_idx":653,"before_size_id":0,"after_size_id":1},{"code_idx":654,"before_size_id":1,"after_size_id":2},{"code_idx":655,"before_size_id":2,"after_size_id":2},{"code_idx":656,"before_size_id":2,"after_size_id":2},{"code_idx":657,"before_size_id":1,"after_size_id":3},{"code_idx":658,"before_size_id":3,"after_size_id":4},{"code_idx":659,"before_size_id":4,"after_size_id":5},{"code_idx":660,"before_size_id":5,"after_size_id":6},{"code_idx":661,"before_size_id":7,"after_size_id":8},{"code_idx":662,"before_size_id":5,"after_size_id":9},{"code_idx":663,"before_size_id":9,"after_size_id":9},{"code_idx":664,"before_size_id":9,"after_size_id":9},{"code_idx":665,"before_size_id":9,"after_size_id":9},{"code_idx":666,"before_size_id":9,"after_size_id":10},{"code_idx":667,"before_size_id":10,"after_size_id":10},{"code_idx":668,"before_size_id":10,"after_size_id":11},{"code_idx":669,"before_size_id":11,"after_size_id":11},{"code_idx":670,"before_size_id":11,"after_size_id":11},{"code_idx":671,"before_size_id":11,"after_size_id":11},{"code_idx":672,"before_size_id":11,"after_size_id":11},{"code_idx":673,"before_size_id":11,"after_size_id":12},{"code_idx":674,"before_size_id":11,"after_size_id":12},{"code_idx":675,"before_size_id":12,"after_size_id":12},{"code_idx":676,"before_size_id":12,"after_size_id":12},{"code_idx":677,"before_size_id":11,"after_size_id":13},{"code_idx":678,"before_size_id":13,"after_size_id":13},{"code_idx":679,"before_size_id":13,"after_size_id":13},{"code_idx":680,"before_size_id":13,"after_size_id":13},{"code_idx":681,"before_size_id":13,"after_size_id":14},{"code_idx":682,"before_size_id":13,"after_size_id":14},{"code_idx":683,"before_size_id":14,"after_size_id":14},{"code_idx":684,"before_size_id":14,"after_size_id":14},{"code_idx":685,"before_size_id":13,"after_size_id":15},{"code_idx":686,"before_size_id":15,"after_size_id":15},{"code_idx":687,"before_size_id":15,"after_size_id":15},{"code_idx":688,"before_size_id":15,"after_size_id":15},{"code_idx":689,"before_size_id":15,"after_size_id":16},{"code_idx":690,"before_size_id":15,"after_size_id":16},{"code_idx":691,"before_size_id":16,"after_size_id":16},{"code_idx":692,"before_size_id":15,"after_size_id":17},{"code_idx":693,"before_size_id":17,"after_size_id":17},{"code_idx":694,"before_size_id":17,"after_size_id":17},{"code_idx":695,"before_size_id":17,"after_size_id":17},{"code_idx":696,"before_size_id":17,"after_size_id":18},{"code_idx":697,"before_size_id":17,"after_size_id":18},{"code_idx":698,"before_size_id":18,"after_size_id":18},{"code_idx":699,"before_size_id":17,"after_size_id":19},{"code_idx":700,"before_size_id":19,"after_size_id":19},{"code_idx":701,"before_size_id":19,"after_size_id":20},{"code_idx":702,"before_size_id":20,"after_size_id":20},{"code_idx":703,"before_size_id":19,"after_size_id":21},{"code_idx":704,"before_size_id":21,"after_size_id":21},{"code_idx":705,"before_size_id":21,"after_size_id":22},{"code_idx":706,"before_size_id":22,"after_size_id":22},{"code_idx":707,"before_size_id":21,"after_size_id":23},{"code_idx":708,"before_size_id":23,"after_size_id":23},{"code_idx":709,"before_size_id":23,"after_size_id":24},{"code_idx":710,"before_size_id":24,"after_size_id":24},{"code_idx":711,"before_size_id":23,"after_size_id":25},{"code_idx":712,"before_size_id":25,"after_size_id":26},{"code_idx":713,"before_size_id":26,"after_size_id":27},{"code_idx":714,"before_size_id":25,"after_size_id":27},{"code_idx":715,"before_size_id":27,"after_size_id":27},{"code_idx":3,"before_size_id":27,"after_size_id":28},{"code_idx":4,"before_size_id":28,"after_size_id":28},{"code_idx":5,"before_size_id":28,"after_size_id":28},{"code_idx":6,"before_size_id":28,"after_size_id":28},{"code_idx":7,"before_size_id":28,"after_size_id":29},{"code_idx":8,"before_size_id":29,"after_size_id":29},{"code_idx":9,"before_size_id":29,"after_size_id":29},{"code_idx":10,"before_size_id":29,"after_size_id":29},{"code_idx":11,"before_size_id":29,"after_size_id":29},{"code_idx":12,"before_size_id":29,"after_size_id":29},{"code_idx":13,"before_size_id":29,"after_size_id":29},{"code_idx":14,"before_size_id":29,"after_size_id":29},{"code_idx":15,"before_size_id":29,"after_size_id":29},{"code_idx":16,"before_size_id":28,"after_size_id":29},{"code_idx":716,"before_size_id":27,"after_size_id":29},{"code_idx":717,"before_size_id":29,"after_size_id":30},{"code_idx":718,"before_size_id":30,"after_size_id":31},{"code_idx":719,"before_size_id":31,"after_size_id":32},{"code_idx":720,"before_size_id":32,"after_size_id":33},{"code_idx":721,"before_size_id":27,"after_size_id":33}]},"resolve_market":{"profilees":[{"code_idx":747,"before_size_id":0,"after_size_id":0},{"code_idx":748,"before_size_id":0,"after_size_id":0},{"code_idx":259,"before_size_id":0,"after_size_id":0},{"code_idx":260,"before_size_id":0,"after_size_id":0},{"code_idx":261,"before_size_id":0,"after_size_id":0},{"code_idx":262,"before_size_id":0,"after_size_id":0},{"code_idx":263,"before_size_id":0,"after_size_id":0},{"code_idx":264,"before_size_id":0,"after_size_id":0},{"code_idx":749,"before_size_id":0,"after_size_id":0},{"code_idx":750,"before_size_id":0,"after_size_id":0},{"code_idx":259,"before_size_id":0,"after_size_id":0},{"code_idx":260,"before_size_id":0,"after_size_id":0},{"code_idx":261,"before_size_id":0,"after_size_id":0},{"code_idx":262,"before_size_id":0,"after_size_id":0},{"code_idx":263,"before_size_id":0,"after_size_id":0},{"code_idx":264,"before_size_id":0,"after_size_id":0},{"code_idx":751,"before_size_id":0,"after_size_id":0},{"code_idx":752,"before_size_id":0,"after_size_id":0},{"code_idx":753,"before_size_id":0,"after_size_id":0},{"code_idx":754,"before_size_id":0,"after_size_id":0},{"code_idx":755,"before_size_id":0,"after_size_id":0},{"code_idx":265,"before_size_id":0,"after_size_id":1},{"code_idx":265,"before_size_id":1,"after_size_id":1},{"code_idx":756,"before_size_id":1,"after_size_id":1},{"code_idx":757,"before_size_id":1,"after_size_id":1},{"code_idx":758,"before_size_id":1,"after_size_id":1},{"code_idx":759,"before_size_id":1,"after_size_id":1},{"code_idx":760,"before_size_id":1,"after_size_id":2},{"code_idx":761,"before_size_id":0,"after_size_id":2},{"code_idx":266,"before_size_id":2,"after_size_id":2},{"code_idx":267,"before_size_id":2,"after_size_id":2},{"code_idx":268,"before_size_id":2,"after_size_id":3},{"code_idx":269,"before_size_id":3,"after_size_id":3},{"code_idx":762,"before_size_id":2,"after_size_id":3},{"code_idx":266,"before_size_id":3,"after_size_id":3},{"code_idx":267,"before_size_id":3,"after_size_id":3},{"code_idx":268,"before_size_id":3,"after_size_id":3},{"code_idx":269,"before_size_id":3,"after_size_id":3},{"code_idx":763,"before_size_id":3,"after_size_id":3},{"code_idx":764,"before_size_id":2,"after_size_id":3},{"code_idx":29,"before_size_id":3,"after_size_id":3},{"code_idx":30,"before_size_id":3,"after_size_id":3},{"code_idx":31,"before_size_id":3,"after_size_id":4},{"code_idx":765,"before_size_id":3,"after_size_id":4},{"code_idx":766,"before_size_id":4,"after_size_id":4},{"code_idx":767,"before_size_id":4,"after_size_id":4},{"code_idx":768,"before_size_id":4,"after_size_id":4},{"code_idx":769,"before_size_id":4,"after_size_id":5},{"code_idx":770,"before_size_id":5,"after_size_id":5},{"code_idx":771,"before_size_id":5,"after_size_id":5},{"code_idx":772,"before_size_id":5,"after_size_id":5},{"code_idx":773,"before_size_id":5,"after_size_id":6},{"code_idx":266,"before_size_id":6,"after_size_id":6},{"code_idx":267,"before_size_id":6,"after_size_id":6},{"code_idx":268,"before_size_id":6,"after_size_id":7},{"code_idx":269,"before_size_id":7,"after_size_id":7},{"code_idx":762,"before_size_id":6,"after_size_id":7},{"code_idx":266,"before_size_id":7,"after_size_id":7},{"code_idx":267,"before_size_id":7,"after_size_id":7},{"code_idx":268,"before_size_id":7,"after_size_id":7},{"code_idx":269,"before_size_id":7,"after_size_id":7},{"code_idx":763,"before_size_id":7,"after_size_id":7},{"code_idx":764,"before_size_id":6,"after_size_id":7},{"code_idx":29,"before_size_id":7,"after_size_id":7},{"code_idx":30,"before_size_id":7,"after_size_id":7},{"code_idx":31,"before_size_id":7,"after_size_id":8},{"code_idx":765,"before_size_id":7,"after_size_id":8},{"code_idx":766,"before_size_id":8,"after_size_id":8},{"code_idx":767,"before_size_id":8,"after_size_id":8},{"code_idx":768,"before_size_id":8,"after_size_id":8},{"code_idx":769,"before_size_id":8,"after_size_id":9},{"code_idx":770,"before_size_id":9,"after_size_id":9},{"code_idx":771,"before_size_id":9,"after_size_id":9},{"code_idx":772,"before_size_id":9,"after_size_id":9},{"code_idx":773,"before_size_id":9,"after_size_id":10},{"code_idx":266,"before_size_id":10,"after_size_id":10},{"code_idx":267,"before_size_id":10,"after_size_id":10},{"code_idx":268,"before_size_id":10,"after_size_id":11},{"code_idx":269,"before_size_id":11,"after_size_id":11},{"code_idx":762,"before_size_id":10,"after_size_id":11},{"code_idx":266,"before_size_id":11,"after_size_id":11},{"code_idx":267,"before_size_id":11,"after_size_id":11},{"code_idx":268,"before_size_id":11,"after_size_id":11},{"code_idx":269,"before_size_id":11,"after_size_id":11},{"code_idx":763,"before_size_id":11,"after_size_id":11},{"code_idx":764,"before_size_id":10,"after_size_id":11},{"code_idx":29,"before_size_id":11,"after_size_id":11},{"code_idx":30,"before_size_id":11,"after_size_id":11},{"code_idx":31,"before_size_id":11,"after_size_id":12},{"code_idx":765,"before_size_id":11,"after_size_id":12},{"code_idx":766,"before_size_id":12,"after_size_id":12},{"code_idx":767,"before_size_id":12,"after_size_id":12},{"code_idx":768,"before_size_id":12,"after_size_id":12},{"code_idx":769,"before_size_id":12,"after_size_id":13},{"code_idx":770,"before_size_id":13,"after_size_id":13},{"code_idx":771,"before_size_id":13,"after_size_id":13},{"code_idx":772,"before_size_id":13,"after_size_id":13},{"code_idx":773,"before_size_id":13,"after_size_id":14},{"code_idx":266,"before_size_id":14,"after_size_id":14},{"code_idx":267,"before_size_id":14,"after_size_id":14},{"code_idx":268,"before_size_id":14,"after_size_id":15},{"code_idx":269,"before_size_id":15,"after_size_id":15},{"code_idx":762,"before_size_id":14,"after_size_id":15},{"code_idx":266,"before_size_id":15,"after_size_id":15},{"code_idx":267,"before_size_id":15,"after_size_id":15},{"code_idx":268,"before_size_id":15,"after_size_id":15},{"code_idx":269,"before_size_id":15,"after_size_id":15},{"code_idx":763,"before_size_id":15,"after_size_id":15},{"code_idx":764,"before_size_id":14,"after_size_id":15},{"code_idx":29,"before_size_id":15,"after_size_id":15},{"code_idx":30,"before_size_id":15,"after_size_id":15},{"code_idx":31,"before_size_id":15,"after_size_id":16},{"code_idx":765,"before_size_id":15,"after_size_id":16},{"code_idx":766,"before_size_id":16,"after_size_id":16},{"code_idx":767,"before_size_id":16,"after_size_id":16},{"code_idx":768,"before_size_id":16,"after_size_id":16},{"code_idx":769,"before_size_id":16,"after_size_id":17},{"code_idx":770,"before_size_id":17,"after_size_id":17},{"code_idx":771,"before_size_id":17,"after_size_id":17},{"code_idx":772,"before_size_id":17,"after_size_id":17},{"code_idx":773,"before_size_id":17,"after_size_id":18},{"code_idx":266,"before_size_id":18,"after_size_id":18},{"code_idx":267,"before_size_id":18,"after_size_id":18},{"code_idx":268,"before_size_id":18,"after_size_id":19},{"code_idx":269,"before_size_id":19,"after_size_id":19},{"code_idx":762,"before_size_id":18,"after_size_id":19},{"code_idx":266,"before_size_id":19,"after_size_id":19},{"code_idx":267,"before_size_id":19,"after_size_id":19},{"code_idx":268,"before_size_id":19,"after_size_id":19},{"code_idx":269,"before_size_id":19,"after_size_id":19},{"code_idx":763,"before_size_id":19,"after_size_id":19},{"code_idx":764,"before_size_id":18,"after_size_id":19},{"code_idx":29,"before_size_id":19,"after_size_id":19},{"code_idx":30,"before_size_id":19,"after_size_id":19},{"code_idx":31,"before_size_id":19,"after_size_id":20},{"code_idx":765,"before_size_id":19,"after_size_id":20},{"code_idx":766,"before_size_id":20,"after_size_id":20},{"code_idx":767,"before_size_id":20,"after_size_id":20},{"code_idx":768,"before_size_id":20,"after_size_id":20},{"code_idx":769,"before_size_id":20,"after_size_id":21},{"code_idx":770,"before_size_id":21,"after_size_id":21},{"code_idx":771,"before_size_id":21,"after_size_id":21},{"code_idx":772,"before_size_id":21,"after_size_id":21},{"code_idx":773,"before_size_id":21,"after_size_id":22},{"code_idx":266,"before_size_id":22,"after_size_id":22},{"code_idx":267,"before_size_id":22,"after_size_id":22},{"code_idx":268,"before_size_id":22,"after_size_id":23},{"code_idx":269,"before_size_id":23,"after_size_id":23},{"code_idx":762,"before_size_id":22,"after_size_id":23},{"code_idx":266,"before_size_id":23,"after_size_id":23},{"code_idx":267,"before_size_id":23,"after_size_id":23},{"code_idx":268,"before_size_id":23,"after_size_id":23},{"code_idx":269,"before_size_id":23,"after_size_id":23},{"code_idx":763,"before_size_id":23,"after_size_id":23},{"code_idx":764,"before_size_id":22,"after_size_id":23},{"code_idx":29,"before_size_id":23,"after_size_id":23},{"code_idx":30,"before_size_id":23,"after_size_id":23},{"code_idx":31,"before_size_id":23,"after_size_id":24},{"code_idx":765,"before_size_id":23,"after_size_id":24},{"code_idx":766,"before_size_id":24,"after_size_id":24},{"code_idx":767,"before_size_id":24,"after_size_id":24},{"code_idx":768,"before_size_id":24,"after_size_id":24},{"code_idx":769,"before_size_id":24,"after_size_id":25},{"code_idx":770,"before_size_id":25,"after_size_id":25},{"code_idx":771,"before_size_id":25,"after_size_id":25},{"code_idx":772,"before_size_id":25,"after_size_id":25},{"code_idx":773,"before_size_id":25,"after_size_id":26},{"code_idx":266,"before_size_id":26,"after_size_id":26},{"code_idx":267,"before_size_id":26,"after_size_id":26},{"code_idx":268,"before_size_id":26,"after_size_id":27},{"code_idx":269,"before_size_id":27,"after_size_id":27},{"code_idx":762,"before_size_id":26,"after_size_id":27},{"code_idx":266,"before_size_id":27,"after_size_id":27},{"code_idx":267,"before_size_id":27,"after_size_id":27},{"code_idx":268,"before_size_id":27,"after_size_id":27},{"code_idx":269,"before_size_id":27,"after_size_id":27},{"code_idx":763,"before_size_id":27,"after_size_id":27},{"code_idx":764,"before_size_id":26,"after_size_id":27},{"code_idx":29,"before_size_id":27,"after_size_id":27},{"code_idx":30,"before_size_id":27,"after_size_id":27},{"code_idx":31,"before_size_id":27,"after_size_id":28},{"code_idx":765,"before_size_id":27,"after_size_id":28},{"code_idx":766,"before_size_id":28,"after_size_id":28},{"code_idx":767,"before_size_id":28,"after_size_id":28},{"code_idx":768,"before_size_id":28,"after_size_id":28},{"code_idx":769,"before_size_id":28,"after_size_id":29},{"code_idx":770,"before_size_id":29,"after_size_id":29},{"code_idx":771,"before_size_id":29,"after_size_id":29},{"code_idx":772,"before_size_id":29,"after_size_id":29},{"code_idx":773,"before_size_id":29,"after_size_id":30},{"code_idx":266,"before_size_id":30,"after_size_id":30},{"code_idx":267,"before_size_id":30,"after_size_id":30},{"code_idx":268,"before_size_id":30,"after_size_id":31},{"code_idx":269,"before_size_id":31,"after_size_id":31},{"code_idx":762,"before_size_id":30,"after_size_id":31},{"code_idx":266,"before_size_id":31,"after_size_id":31},{"code_idx":267,"before_size_id":31,"after_size_id":31},{"code_idx":268,"before_size_id":31,"after_size_id":31},{"code_idx":269,"before_size_id":31,"after_size_id":31},{"code_idx":763,"before_size_id":31,"after_size_id":31},{"code_idx":764,"before_size_id":30,"after_size_id":31},{"code_idx":29,"before_size_id":31,"after_size_id":31},{"code_idx":30,"before_size_id":31,"after_size_id":31},{"code_idx":31,"before_size_id":31,"after_size_id":32},{"code_idx":765,"before_size_id":31,"after_size_id":32},{"code_idx":766,"before_size_id":32,"after_size_id":32},{"code_idx":767,"before_size_id":32,"after_size_id":32},{"code_idx":768,"before_size_id":32,"after_size_id":32},{"code_idx":769,"before_size_id":32,"after_size_id":33},{"code_idx":770,"before_size_id":33,"after_size_id":33},{"code_idx":771,"before_size_id":33,"after_size_id":33},{"code_idx":772,"before_size_id":33,"after_size_id":33},{"code_idx":773,"before_size_id":33,"after_size_id":34},{"code_idx":266,"before_size_id":34,"after_size_id":34},{"code_idx":267,"before_size_id":34,"after_size_id":34},{"code_idx":268,"before_size_id":34,"after_size_id":35},{"code_idx":269,"before_size_id":35,"after_size_id":35},{"code_idx":762,"before_size_id":34,"after_size_id":35},{"code_idx":266,"before_size_id":35,"after_size_id":35},{"code_idx":267,"before_size_id":35,"after_size_id":35},{"code_idx":268,"before_size_id":35,"after_size_id":35},{"code_idx":269,"before_size_id":35,"after_size_id":35},{"code_idx":763,"before_size_id":35,"after_size_id":35},{"code_idx":764,"before_size_id":34,"after_size_id":35},{"code_idx":29,"before_size_id":35,"after_size_id":35},{"code_idx":30,"before_size_id":35,"after_size_id":35},{"code_idx":31,"before_size_id":35,"after_size_id":36},{"code_idx":765,"before_size_id":35,"after_size_id":36},{"code_idx":766,"before_size_id":36,"after_size_id":36},{"code_idx":767,"before_size_id":36,"after_size_id":36},{"code_idx":768,"before_size_id":36,"after_size_id":36},{"code_idx":769,"before_size_id":36,"after_size_id":37},{"code_idx":770,"before_size_id":37,"after_size_id":37},{"code_idx":771,"before_size_id":37,"after_size_id":37},{"code_idx":772,"before_size_id":37,"after_size_id":37},{"code_idx":773,"before_size_id":37,"after_size_id":38},{"code_idx":266,"before_size_id":38,"after_size_id":38},{"code_idx":267,"before_size_id":38,"after_size_id":38},{"code_idx":268,"before_size_id":38,"after_size_id":39},{"code_idx":269,"before_size_id":39,"after_size_id":39},{"code_idx":762,"before_size_id":38,"after_size_id":39},{"code_idx":266,"before_size_id":39,"after_size_id":39},{"code_idx":267,"before_size_id":39,"after_size_id":39},{"code_idx":268,"before_size_id":39,"after_size_id":39},{"code_idx":269,"before_size_id":39,"after_size_id":39},{"code_idx":763,"before_size_id":39,"after_size_id":39},{"code_idx":764,"before_size_id":38,"after_size_id":39},{"code_idx":29,"before_size_id":39,"after_size_id":39},{"code_idx":30,"before_size_id":39,"after_size_id":39},{"code_idx":31,"before_size_id":39,"after_size_id":40},{"code_idx":765,"before_size_id":39,"after_size_id":40},{"code_idx":766,"before_size_id":40,"after_size_id":40},{"code_idx":767,"before_size_id":40,"after_size_id":40},{"code_idx":768,"before_size_id":40,"after_size_id":40},{"code_idx":769,"before_size_id":40,"after_size_id":41},{"code_idx":770,"before_size_id":41,"after_size_id":41},{"code_idx":771,"before_size_id":41,"after_size_id":41},{"code_idx":772,"before_size_id":41,"after_size_id":41},{"code_idx":773,"before_size_id":41,"after_size_id":42},{"code_idx":774,"before_size_id":0,"after_size_id":42},{"code_idx":775,"before_size_id":42,"after_size_id":43},{"code_idx":776,"before_size_id":43,"after_size_id":44},{"code_idx":777,"before_size_id":44,"after_size_id":44},{"code_idx":778,"before_size_id":44,"after_size_id":44},{"code_idx":779,"before_size_id":44,"after_size_id":44},{"code_idx":780,"before_size_id":42,"after_size_id":45},{"code_idx":781,"before_size_id":45,"after_size_id":45},{"code_idx":782,"before_size_id":45,"after_size_id":45},{"code_idx":783,"before_size_id":45,"after_size_id":45},{"code_idx":784,"before_size_id":45,"after_size_id":46},{"code_idx":785,"before_size_id":46,"after_size_id":46},{"code_idx":786,"before_size_id":46,"after_size_id":46},{"code_idx":787,"before_size_id":46,"after_size_id":46},{"code_idx":788,"before_size_id":46,"after_size_id":47},{"code_idx":789,"before_size_id":47,"after_size_id":48},{"code_idx":790,"before_size_id":46,"after_size_id":48}]},"private_trade":{"profilees":[{"code_idx":29,"before_size_id":0,"after_size_id":0},{"code_idx":30,"before_size_id":0,"after_size_id":0},{"code_idx":31,"before_size_id":0,"after_size_id":1},{"code_idx":32,"before_size_id":0,"after_size_id":1},{"code_idx":29,"before_size_id":1,"after_size_id":1},{"code_idx":30,"before_size_id":1,"after_size_id":1},{"code_idx":31,"before_size_id":1,"after_size_id":2},{"code_idx":33,"before_size_id":1,"after_size_id":2},{"code_idx":34,"before_size_id":2,"after_size_id":2},{"code_idx":35,"before_size_id":2,"after_size_id":2},{"code_idx":36,"before_size_id":2,"after_size_id":3},{"code_idx":37,"before_size_id":3,"after_size_id":3},{"code_idx":38,"before_size_id":3,"after_size_id":3},{"code_idx":39,"before_size_id":2,"after_size_id":4},{"code_idx":40,"before_size_id":4,"after_size_id":5},{"code_idx":41,"before_size_id":5,"after_size_id":6},{"code_idx":42,"before_size_id":6,"after_size_id":6},{"code_idx":43,"before_size_id":6,"after_size_id":6},{"code_idx":44,"before_size_id":6,"after_size_id":6},{"code_idx":45,"before_size_id":6,"after_size_id":6},{"code_idx":46,"before_size_id":6,"after_size_id":6},{"code_idx":47,"before_size_id":6,"after_size_id":6},{"code_idx":48,"before_size_id":6,"after_size_id":6},{"code_idx":49,"before_size_id":6,"after_size_id":7},{"code_idx":50,"before_size_id":7,"after_size_id":7},{"code_idx":51,"before_size_id":7,"after_size_id":7},{"code_idx":52,"before_size_id":7,"after_size_id":8},{"code_idx":53,"before_size_id":8,"after_size_id":9},{"code_idx":54,"before_size_id":9,"after_size_id":10},{"code_idx":55,"before_size_id":10,"after_size_id":10},{"code_idx":56,"before_size_id":10,"after_size_id":10},{"code_idx":57,"before_size_id":9,"after_size_id":11},{"code_idx":58,"before_size_id":11,"after_size_id":11},{"code_idx":59,"before_size_id":11,"after_size_id":12},{"code_idx":60,"before_size_id":11,"after_size_id":12},{"code_idx":61,"before_size_id":12,"after_size_id":13},{"code_idx":62,"before_size_id":13,"after_size_id":14},{"code_idx":63,"before_size_id":14,"after_size_id":14},{"code_idx":64,"before_size_id":14,"after_size_id":14},{"code_idx":65,"before_size_id":13,"after_size_id":15},{"code_idx":66,"before_size_id":15,"after_size_id":15},{"code_idx":67,"before_size_id":15,"after_size_id":15},{"code_idx":68,"before_size_id":16,"after_size_id":16},{"code_idx":69,"before_size_id":15,"after_size_id":17},{"code_idx":70,"before_size_id":15,"after_size_id":17},{"code_idx":71,"before_size_id":17,"after_size_id":18},{"code_idx":72,"before_size_id":18,"after_size_id":19},{"code_idx":73,"before_size_id":19,"after_size_id":19},{"code_idx":74,"before_size_id":19,"after_size_id":19},{"code_idx":75,"before_size_id":19,"after_size_id":19},{"code_idx":76,"before_size_id":19,"after_size_id":19},{"code_idx":77,"before_size_id":19,"after_size_id":19},{"code_idx":78,"before_size_id":19,"after_size_id":19},{"code_idx":79,"before_size_id":19,"after_size_id":19},{"code_idx":80,"before_size_id":19,"after_size_id":19},{"code_idx":81,"before_size_id":19,"after_size_id":19},{"code_idx":82,"before_size_id":19,"after_size_id":19},{"code_idx":83,"before_size_id":18,"after_size_id":20},{"code_idx":84,"before_size_id":20,"after_size_id":20},{"code_idx":85,"before_size_id":20,"after_size_id":20},{"code_idx":86,"before_size_id":20,"after_size_id":20},{"code_idx":87,"before_size_id":20,"after_size_id":20},{"code_idx":88,"before_size_id":20,"after_size_id":20},{"code_idx":89,"before_size_id":20,"after_size_id":20},{"code_idx":90,"before_size_id":20,"after_size_id":20},{"code_idx":91,"before_size_id":20,"after_size_id":20},{"code_idx":92,"before_size_id":20,"after_size_id":20},{"code_idx":93,"before_size_id":20,"after_size_id":20},{"code_idx":94,"before_size_id":20,"after_size_id":21},{"code_idx":95,"before_size_id":21,"after_size_id":21},{"code_idx":96,"before_size_id":21,"after_size_id":21},{"code_idx":97,"before_size_id":21,"after_size_id":21},{"code_idx":29,"before_size_id":21,"after_size_id":21},{"code_idx":30,"before_size_id":21,"after_size_id":21},{"code_idx":31,"before_size_id":21,"after_size_id":22},{"code_idx":98,"before_size_id":21,"after_size_id":22},{"code_idx":99,"before_size_id":22,"after_size_id":22},{"code_idx":100,"before_size_id":22,"after_size_id":22},{"code_idx":101,"before_size_id":22,"after_size_id":22},{"code_idx":102,"before_size_id":22,"after_size_id":22},{"code_idx":103,"before_size_id":22,"after_size_id":22},{"code_idx":104,"before_size_id":23,"after_size_id":23},{"code_idx":105,"before_size_id":23,"after_size_id":23},{"code_idx":106,"before_size_id":22,"after_size_id":24},{"code_idx":107,"before_size_id":24,"after_size_id":25},{"code_idx":108,"before_size_id":26,"after_size_id":26},{"code_idx":109,"before_size_id":26,"after_size_id":26},{"code_idx":110,"before_size_id":24,"after_size_id":27},{"code_idx":111,"before_size_id":27,"after_size_id":27},{"code_idx":112,"before_size_id":27,"after_size_id":27},{"code_idx":113,"before_size_id":27,"after_size_id":27},{"code_idx":114,"before_size_id":27,"after_size_id":28},{"code_idx":115,"before_size_id":28,"after_size_id":29},{"code_idx":116,"before_size_id":29,"after_size_id":30},{"code_idx":117,"before_size_id":30,"after_size_id":31},{"code_idx":118,"before_size_id":28,"after_size_id":31},{"code_idx":119,"before_size_id":21,"after_size_id":31},{"code_idx":120,"before_size_id":31,"after_size_id":31},{"code_idx":121,"before_size_id":31,"after_size_id":31},{"code_idx":122,"before_size_id":31,"after_size_id":31},{"code_idx":123,"before_size_id":31,"after_size_id":31},{"code_idx":124,"before_size_id":31,"after_size_id":31},{"code_idx":125,"before_size_id":31,"after_size_id":31},{"code_idx":126,"before_size_id":31,"after_size_id":31},{"code_idx":127,"before_size_id":31,"after_size_id":31},{"code_idx":128,"before_size_id":31,"after_size_id":31},{"code_idx":129,"before_size_id":31,"after_size_id":31},{"code_idx":130,"before_size_id":31,"after_size_id":31},{"code_idx":131,"before_size_id":31,"after_size_id":31},{"code_idx":132,"before_size_id":31,"after_size_id":31},{"code_idx":133,"before_size_id":31,"after_size_id":31},{"code_idx":134,"before_size_id":31,"after_size_id":31},{"code_idx":135,"before_size_id":31,"after_size_id":32},{"code_idx":136,"before_size_id":31,"after_size_id":32},{"code_idx":137,"before_size_id":31,"after_size_id":32},{"code_idx":138,"before_size_id":32,"after_size_id":32},{"code_idx":22,"before_size_id":32,"after_size_id":33},{"code_idx":23,"before_size_id":33,"after_size_id":33},{"code_idx":24,"before_size_id":33,"after_size_id":33},{"code_idx":25,"before_size_id":33,"after_size_id":33},{"code_idx":26,"before_size_id":33,"after_size_id":34},{"code_idx":8,"before_size_id":34,"after_size_id":34},{"code_idx":9,"before_size_id":34,"after_size_id":34},{"code_idx":10,"before_size_id":34,"after_size_id":34},{"code_idx":11,"before_size_id":34,"after_size_id":34},{"code_idx":12,"before_size_id":34,"after_size_id":34},{"code_idx":13,"before_size_id":34,"after_size_id":34},{"code_idx":14,"before_size_id":34,"after_size_id":34},{"code_idx":15,"before_size_id":34,"after_size_id":34},{"code_idx":27,"before_size_id":33,"after_size_id":34},{"code_idx":139,"before_size_id":32,"after_size_id":34},{"code_idx":140,"before_size_id":34,"after_size_id":34},{"code_idx":3,"before_size_id":34,"after_size_id":35},{"code_idx":4,"before_size_id":35,"after_size_id":35},{"code_idx":5,"before_size_id":35,"after_size_id":35},{"code_idx":6,"before_size_id":35,"after_size_id":35},{"code_idx":7,"before_size_id":35,"after_size_id":36},{"code_idx":8,"before_size_id":36,"after_size_id":36},{"code_idx":9,"before_size_id":36,"after_size_id":36},{"code_idx":10,"before_size_id":36,"after_size_id":36},{"code_idx":11,"before_size_id":36,"after_size_id":36},{"code_idx":12,"before_size_id":36,"after_size_id":36},{"code_idx":13,"before_size_id":36,"after_size_id":36},{"code_idx":14,"before_size_id":36,"after_size_id":36},{"code_idx":15,"before_size_id":36,"after_size_id":36},{"code_idx":16,"before_size_id":35,"after_size_id":36},{"code_idx":141,"before_size_id":34,"after_size_id":36},{"code_idx":142,"before_size_id":36,"after_size_id":36},{"code_idx":22,"before_size_id":36,"after_size_id":37},{"code_idx":23,"before_size_id":37,"after_size_id":37},{"code_idx":24,"before_size_id":37,"after_size_id":37},{"code_idx":25,"before_size_id":37,"after_size_id":37},{"code_idx":26,"before_size_id":37,"after_size_id":38},{"code_idx":8,"before_size_id":38,"after_size_id":38},{"code_idx":9,"before_size_id":38,"after_size_id":38},{"code_idx":10,"before_size_id":38,"after_size_id":38},{"code_idx":11,"before_size_id":38,"after_size_id":38},{"code_idx":12,"before_size_id":38,"after_size_id":38},{"code_idx":13,"before_size_id":38,"after_size_id":38},{"code_idx":14,"before_size_id":38,"after_size_id":38},{"code_idx":15,"before_size_id":38,"after_size_id":38},{"code_idx":27,"before_size_id":37,"after_size_id":38},{"code_idx":143,"before_size_id":36,"after_size_id":38},{"code_idx":144,"before_size_id":38,"after_size_id":39},{"code_idx":145,"before_size_id":39,"after_size_id":40},{"code_idx":146,"before_size_id":40,"after_size_id":41},{"code_idx":147,"before_size_id":41,"after_size_id":42},{"code_idx":148,"before_size_id":32,"after_size_id":42}]}}
+, 'instruction_locations':
+{"remove_liquidity":{"Known":[{"line":689,"column":4},{"line":689,"column":18}]},"init_position":{"Known":[{"line":33,"column":4},{"line":33,"column":18}]},"batch_clear":{"Known":[{"line":451,"column":4},{"line":451,"column":18}]},"init_cfmm_state":{"Known":[{"line":15,"column":4},{"line":15,"column":18}]},"add_liquidity":{"Known":[{"line":621,"column":4},{"line":621,"column":18}]},"private_trade":{"Known":[{"line":120,"column":4},{"line":120,"column":18}]},"lmsr_trade":{"Known":[{"line":294,"column":4},{"line":294,"column":18}]},"resolve_market":{"Known":[{"line":737,"column":4},{"line":737,"column":18}]}}
+};
@@ -0,0 +1 @@
+export type InitCfmmState = {"name":"init_cfmm_state","inputs":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"}],"outputs":[{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}]}
\ No newline at end of file
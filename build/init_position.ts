@@ -0,0 +1 @@
+export type InitPosition = {"name":"init_position","inputs":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"}],"outputs":[{"content":[{"content":[{"size_in_bits":255,"type":"public_key"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}]}
\ No newline at end of file
@@ -4,6 +4,79 @@ use arcis_imports::*;
 mod circuits {
     use arcis_imports::*;
 
+    // ========== Encrypted CFMM State ==========
+
+    /// Pool reserves, only ever held as `Enc<Mxe, CfmmState>`. For LMSR
+    /// markets the two fields hold the outstanding share quantities q_yes / q_no.
+    pub struct CfmmState {
+        yes_reserves: u64,
+        no_reserves: u64,
+    }
+
+    #[instruction]
+    pub fn init_cfmm_state(mxe: Mxe) -> Enc<Mxe, CfmmState> {
+        mxe.from_arcis(CfmmState {
+            yes_reserves: 0,
+            no_reserves: 0,
+        })
+    }
+
+    // ========== Encrypted User Positions ==========
+
+    /// A trader's holdings in one market, only ever held as
+    /// `Enc<Shared, PositionState>` so the trader alone can decrypt it
+    pub struct PositionState {
+        yes_shares: u64,
+        no_shares: u64,
+        cost_basis: u64,
+    }
+
+    #[instruction]
+    pub fn init_position(owner: Shared) -> Enc<Shared, PositionState> {
+        owner.from_arcis(PositionState {
+            yes_shares: 0,
+            no_shares: 0,
+            cost_basis: 0,
+        })
+    }
+
+    /// Credit a filled trade's shares and spend to the trader's position
+    fn apply_fill(
+        position: PositionState,
+        side: bool,
+        shares_out: u64,
+        user_amount: u64,
+        filled: bool,
+    ) -> PositionState {
+        let yes_add = if filled && side { shares_out } else { 0 };
+        let no_add = if filled && !side { shares_out } else { 0 };
+        let cost_add = if filled { user_amount } else { 0 };
+        PositionState {
+            yes_shares: position.yes_shares + yes_add,
+            no_shares: position.no_shares + no_add,
+            cost_basis: position.cost_basis + cost_add,
+        }
+    }
+
+    /// Token movements for a trade, revealed so the callback can settle it:
+    /// (side, shares to mint, collateral spent, collateral refunded).
+    /// Rejected trades mint nothing and refund the whole escrow.
+    fn settle(
+        filled: bool,
+        side: bool,
+        shares_out: u64,
+        user_amount: u64,
+        escrow: u64,
+    ) -> (bool, u64, u64, u64) {
+        let spent = if filled { user_amount } else { 0 };
+        (
+            filled && side,
+            if filled { shares_out } else { 0 },
+            spent,
+            escrow - spent,
+        )
+    }
+
     // ========== Private Trade Circuit ==========
 
     pub struct PrivateTradeInput {
@@ -12,30 +85,93 @@ mod circuits {
         max_price: u64,
     }
 
-    pub struct CfmmState {
-        yes_reserves: u64,
-        no_reserves: u64,
+    /// Fixed-point scale for prices (1.0 == PRICE_SCALE)
+    const PRICE_SCALE: u64 = 1_000_000;
+
+    /// Basis point denominator for the trading fee
+    const BPS_DENOMINATOR: u64 = 10_000;
+
+    pub struct TradeReceipt {
+        shares_out: u64,
+        effective_price: u64,
+        fee: u64,
+        filled: bool,
     }
 
+    /// Trade circuit output: the trader's encrypted receipt, the new pool
+    /// state, the trader's new position, then the revealed settlement
+    /// (side, shares to mint, collateral spent, collateral refunded).
+    ///
+    /// The settlement has to be public because the program mints SPL
+    /// outcome tokens and moves collateral in the clear. Each fill is
+    /// therefore visible, and anyone replaying fills and liquidity events
+    /// can track the pool, so the encrypted reserves and positions hide the
+    /// trader's limit price and running totals, not what a trade did.
+    type TradeOutput = (
+        Enc<Shared, TradeReceipt>,
+        Enc<Mxe, CfmmState>,
+        Enc<Shared, PositionState>,
+        bool,
+        u64,
+        u64,
+        u64,
+    );
+
+    /// Plaintext reference: programs/private-markets/src/cpmm.rs
     #[instruction]
     pub fn private_trade(
         input_ctxt: Enc<Shared, PrivateTradeInput>,
-        yes_reserves: u64,
-        no_reserves: u64,
-    ) -> Enc<Shared, CfmmState> {
+        state_ctxt: Enc<Mxe, CfmmState>,
+        position_ctxt: Enc<Shared, PositionState>,
+        escrow: u64,
+        fee_bps: u64,
+    ) -> TradeOutput {
         let input = input_ctxt.to_arcis();
+        let state = state_ctxt.to_arcis();
+        let yes_reserves = state.yes_reserves;
+        let no_reserves = state.no_reserves;
 
-        // CFMM logic: add to reserves based on trade side
-        let new_yes_reserves = if input.side {
-            yes_reserves + input.user_amount
+        // The trader can never spend more than the collateral they escrowed
+        let user_amount = if input.user_amount > escrow { escrow } else { input.user_amount };
+        let fee = user_amount * fee_bps / BPS_DENOMINATOR;
+        let amount_in = user_amount - fee;
+
+        // Constant product (x * y = k): the collateral mints a complete set
+        // into both reserves, then the bought side is withdrawn until the
+        // product is restored.
+        let (bought_reserve, other_reserve) = if input.side {
+            (yes_reserves, no_reserves)
         } else {
-            yes_reserves
+            (no_reserves, yes_reserves)
         };
+        let k = (bought_reserve as u128) * (other_reserve as u128);
+        let new_other = other_reserve + amount_in;
+        let new_other_denominator = if new_other > 0 { new_other } else { 1 };
+        let new_bought = (k / (new_other_denominator as u128)) as u64;
+        let shares_out = bought_reserve + amount_in - new_bought;
+
+        let price_denominator = if shares_out > 0 { shares_out } else { 1 };
+        let effective_price = ((user_amount as u128) * (PRICE_SCALE as u128)
+            / (price_denominator as u128)) as u64;
 
-        let new_no_reserves = if !input.side {
-            no_reserves + input.user_amount
+        // An empty pool has no price to trade against, and the trader's
+        // max_price is their slippage limit
+        let filled = k > 0 && shares_out > 0 && effective_price <= input.max_price;
+
+        // Rejected trades leave the pool untouched
+        let (new_yes_reserves, new_no_reserves) = if !filled {
+            (yes_reserves, no_reserves)
+        } else if input.side {
+            (new_bought, new_other)
         } else {
-            no_reserves
+            (new_other, new_bought)
+        };
+
+        let receipt = TradeReceipt {
+            shares_out: if filled { shares_out } else { 0 },
+            effective_price,
+            fee: if filled { fee } else { 0 },
+            filled,
         };
 
         let new_state = CfmmState {
@@ -43,7 +179,185 @@ mod circuits {
             no_reserves: new_no_reserves,
         };
 
-        input_ctxt.owner.from_arcis(new_state)
+        let new_position = apply_fill(
+            position_ctxt.to_arcis(),
+            input.side,
+            shares_out,
+            user_amount,
+            filled,
+        );
+        let settlement = settle(filled, input.side, shares_out, user_amount, escrow);
+
+        // Trader gets the encrypted receipt and position; the reserves stay
+        // encrypted to the MXE. Only the settlement needed to move tokens is
+        // revealed.
+        (
+            input_ctxt.owner.from_arcis(receipt),
+            state_ctxt.owner.from_arcis(new_state),
+            position_ctxt.owner.from_arcis(new_position),
+            settlement.0.reveal(),
+            settlement.1.reveal(),
+            settlement.2.reveal(),
+            settlement.3.reveal(),
+        )
+    }
+
+    // ========== LMSR Trade Circuit ==========
+    // Plaintext reference: programs/private-markets/src/lmsr.rs
+
+    /// Fixed-point scale for the exp/ln internals (1.0 == FIXED_ONE)
+    const FIXED_ONE: u128 = 1_000_000_000_000;
+
+    /// Largest exponent argument exp_fixed takes (e^15), keeps products
+    /// within u128
+    const MAX_EXP_ARG: u128 = 15 * FIXED_ONE;
+
+    /// ln(2) in FIXED_ONE fixed point
+    const LN_2: u128 = 693_147_180_560;
+
+    /// e^x for 0 <= x <= MAX_EXP_ARG in FIXED_ONE fixed point: Taylor series
+    /// on x / 32, then squared five times. Callers keep x in range.
+    fn exp_fixed(x: u128) -> u128 {
+        let one = FIXED_ONE;
+        let r = x / 32;
+
+        let mut term = one;
+        let mut sum = one;
+        for i in 1..9u128 {
+            term = term * r / (one * i);
+            sum += term;
+        }
+        for _ in 0..5 {
+            sum = sum * sum / one;
+        }
+        sum
+    }
+
+    /// ln(y) for y >= 1 in FIXED_ONE fixed point: halve into [1, 2), then
+    /// the atanh series ln(y) = 2 * (z + z^3/3 + ...) with z = (y - 1) / (y + 1)
+    fn ln_fixed(y: u128) -> u128 {
+        let one = FIXED_ONE;
+        let mut y = if y < one { one } else { y };
+        let mut halvings = 0u128;
+        for _ in 0..64 {
+            let halve = y >= 2 * one;
+            y = if halve { y / 2 } else { y };
+            halvings = if halve { halvings + 1 } else { halvings };
+        }
+
+        let z = (y - one) * one / (y + one);
+        let z2 = z * z / one;
+        let mut term = z;
+        let mut sum = z;
+        for k in [3u128, 5, 7, 9, 11] {
+            term = term * z2 / one;
+            sum += term / k;
+        }
+        halvings * LN_2 + 2 * sum
+    }
+
+    /// Shares of one outcome bought for `amount_in` collateral under
+    /// C(q) = b * ln(e^(q_bought / b) + e^(q_other / b)):
+    /// shares = b * ln(e^(x / b) * (1 + e^d) - e^d), d = (q_other - q_bought) / b.
+    /// Zero, so the trade is rejected, when x / b is past MAX_EXP_ARG.
+    fn lmsr_shares_out(q_bought: u64, q_other: u64, b: u64, amount_in: u64) -> u64 {
+        let one = FIXED_ONE;
+        let b = b as u128;
+
+        let x = (amount_in as u128) * one / b;
+        let in_range = x <= MAX_EXP_ARG;
+        let e_x = exp_fixed(if in_range { x } else { 0 });
+
+        // Past MAX_EXP_ARG the trailing outcome costs under e^-15. Holding e^d
+        // at e^15 when buying it, or taking e^-d as 0 when buying the leader,
+        // both sit below the exact curve, so the pool never overpays.
+        let other_ahead = q_other >= q_bought;
+        let d_abs = if other_ahead {
+            ((q_other - q_bought) as u128) * one / b
+        } else {
+            ((q_bought - q_other) as u128) * one / b
+        };
+        let d_saturated = d_abs > MAX_EXP_ARG;
+        let e_d_abs = exp_fixed(if d_saturated { MAX_EXP_ARG } else { d_abs });
+        let e_d = if other_ahead {
+            e_d_abs
+        } else if d_saturated {
+            0
+        } else {
+            one * one / e_d_abs
+        };
+
+        let inner = e_x * (one + e_d) / one - e_d;
+        let shares = (b * ln_fixed(inner) / one) as u64;
+        if in_range { shares } else { 0 }
+    }
+
+    #[instruction]
+    pub fn lmsr_trade(
+        input_ctxt: Enc<Shared, PrivateTradeInput>,
+        state_ctxt: Enc<Mxe, CfmmState>,
+        position_ctxt: Enc<Shared, PositionState>,
+        escrow: u64,
+        liquidity_b: u64,
+        fee_bps: u64,
+    ) -> TradeOutput {
+        let input = input_ctxt.to_arcis();
+        let state = state_ctxt.to_arcis();
+        let q_yes = state.yes_reserves;
+        let q_no = state.no_reserves;
+
+        let user_amount = if input.user_amount > escrow { escrow } else { input.user_amount };
+        let fee = user_amount * fee_bps / BPS_DENOMINATOR;
+        let amount_in = user_amount - fee;
+
+        let (q_bought, q_other) = if input.side { (q_yes, q_no) } else { (q_no, q_yes) };
+        let shares_out = lmsr_shares_out(q_bought, q_other, liquidity_b, amount_in);
+
+        let price_denominator = if shares_out > 0 { shares_out } else { 1 };
+        let effective_price = ((user_amount as u128) * (PRICE_SCALE as u128)
+            / (price_denominator as u128)) as u64;
+
+        let filled = shares_out > 0 && effective_price <= input.max_price;
+
+        // Outstanding share quantities only move for filled trades
+        let (new_q_yes, new_q_no) = if !filled {
+            (q_yes, q_no)
+        } else if input.side {
+            (q_yes + shares_out, q_no)
+        } else {
+            (q_yes, q_no + shares_out)
+        };
+
+        let receipt = TradeReceipt {
+            shares_out: if filled { shares_out } else { 0 },
+            effective_price,
+            fee: if filled { fee } else { 0 },
+            filled,
+        };
+
+        let new_state = CfmmState {
+            yes_reserves: new_q_yes,
+            no_reserves: new_q_no,
+        };
+
+        let new_position = apply_fill(
+            position_ctxt.to_arcis(),
+            input.side,
+            shares_out,
+            user_amount,
+            filled,
+        );
+        let settlement = settle(filled, input.side, shares_out, user_amount, escrow);
+
+        (
+            input_ctxt.owner.from_arcis(receipt),
+            state_ctxt.owner.from_arcis(new_state),
+            position_ctxt.owner.from_arcis(new_position),
+            settlement.0.reveal(),
+            settlement.1.reveal(),
+            settlement.2.reveal(),
+            settlement.3.reveal(),
+        )
     }
 
     // ========== Batch Clear Circuit ==========
@@ -54,82 +368,403 @@ mod circuits {
         limit_price: u64,
     }
 
-    pub struct BatchClearResult {
-        clearing_price: u64,
-        total_yes_filled: u64,
-        total_no_filled: u64,
-        new_yes_reserves: u64,
-        new_no_reserves: u64,
+    /// Granularity of the publicly revealed price (1% of PRICE_SCALE)
+    const PRICE_TICK: u64 = 10_000;
+
+    /// YES price implied by the reserves, rounded down to PRICE_TICK
+    fn coarse_price(yes_reserves: u64, no_reserves: u64) -> u64 {
+        let total = (yes_reserves as u128) + (no_reserves as u128);
+        let denominator = if total > 0 { total } else { 1 };
+        let price = if total > 0 {
+            ((no_reserves as u128) * (PRICE_SCALE as u128) / denominator) as u64
+        } else {
+            PRICE_SCALE / 2
+        };
+        price / PRICE_TICK * PRICE_TICK
+    }
+
+    /// Orders per batch; must match MAX_BATCH_ORDERS in the program
+    const MAX_BATCH_ORDERS: usize = 4;
+
+    /// A trader's result from one batch, encrypted to the trader
+    pub struct OrderFill {
+        shares: u64,
+        spent: u64,
     }
 
+    /// Largest value a u64 output can carry
+    const U64_MAX: u128 = 18_446_744_073_709_551_615;
+
+    /// `value` as a u64, saturating at u64::MAX
+    fn saturate_u64(value: u128) -> u64 {
+        (if value > U64_MAX { U64_MAX } else { value }) as u64
+    }
+
+    /// Collateral from `orders` willing to trade at YES price `yes_price`,
+    /// split into (YES side, NO side). A NO limit of p is a YES limit of 1 - p.
+    /// Summed in u128 so a full batch of large escrows cannot overflow.
+    fn eligible_collateral(
+        orders: &[BatchOrder; MAX_BATCH_ORDERS],
+        amounts: &[u64; MAX_BATCH_ORDERS],
+        yes_price: u64,
+    ) -> (u128, u128) {
+        let mut yes_collateral = 0u128;
+        let mut no_collateral = 0u128;
+        for i in 0..MAX_BATCH_ORDERS {
+            let order = &orders[i];
+            let yes_ok = order.side && order.limit_price >= yes_price;
+            let no_ok = !order.side && order.limit_price >= PRICE_SCALE - yes_price;
+            yes_collateral += if yes_ok { amounts[i] as u128 } else { 0 };
+            no_collateral += if no_ok { amounts[i] as u128 } else { 0 };
+        }
+        (yes_collateral, no_collateral)
+    }
+
+    /// Shares bought for `collateral` at `price` (PRICE_SCALE fixed point)
+    fn shares_at(collateral: u128, price: u64) -> u128 {
+        let denominator = if price > 0 { price } else { 1 };
+        collateral * (PRICE_SCALE as u128) / (denominator as u128)
+    }
+
+    /// `n / d` rounded up, for `d > 0`. Spelled out because the circuit
+    /// build of `u128::div_ceil` does not match Rust's.
+    #[allow(clippy::manual_div_ceil)]
+    fn div_ceil(n: u128, d: u128) -> u128 {
+        (n + d - 1) / d
+    }
+
+    /// Batch clear output: the new pool state, the public coarse price, the
+    /// uniform clearing price, each slot's fill encrypted to its trader, then
+    /// each slot's revealed settlement (side, shares, refund)
+    type BatchClearOutput = (
+        Enc<Mxe, CfmmState>,
+        u64,
+        u64,
+        Enc<Shared, OrderFill>,
+        Enc<Shared, OrderFill>,
+        Enc<Shared, OrderFill>,
+        Enc<Shared, OrderFill>,
+        [bool; MAX_BATCH_ORDERS],
+        [u64; MAX_BATCH_ORDERS],
+        [u64; MAX_BATCH_ORDERS],
+    );
+
     #[instruction]
     pub fn batch_clear(
-        // Single order for simplification - in production would aggregate multiple
-        order_ctxt: Enc<Shared, BatchOrder>,
-        yes_reserves: u64,
-        no_reserves: u64,
-    ) -> Enc<Shared, BatchClearResult> {
-        let order = order_ctxt.to_arcis();
+        order_ctxts: [Enc<Shared, BatchOrder>; MAX_BATCH_ORDERS],
+        escrows: [u64; MAX_BATCH_ORDERS],
+        order_count: u8,
+        route_to_pool: bool,
+        state_ctxt: Enc<Mxe, CfmmState>,
+    ) -> BatchClearOutput {
+        let [order_0, order_1, order_2, order_3] = order_ctxts;
+        let orders = [
+            order_0.to_arcis(),
+            order_1.to_arcis(),
+            order_2.to_arcis(),
+            order_3.to_arcis(),
+        ];
+        let state = state_ctxt.to_arcis();
+
+        // Each order spends at most its escrow; unused slots spend nothing
+        let mut active = [false; MAX_BATCH_ORDERS];
+        let mut amounts = [0u64; MAX_BATCH_ORDERS];
+        for i in 0..MAX_BATCH_ORDERS {
+            active[i] = (i as u8) < order_count;
+            let amount = if orders[i].amount > escrows[i] { escrows[i] } else { orders[i].amount };
+            amounts[i] = if active[i] { amount } else { 0 };
+        }
+
+        // Uniform price: the order limit (in YES terms) that matches the most
+        // YES against NO shares. A YES share at p and a NO share at 1 - p
+        // together are one complete set, fully backed by their collateral.
+        let mut best_price = 0u64;
+        let mut best_matched = 0u128;
+        for j in 0..MAX_BATCH_ORDERS {
+            let order = &orders[j];
+            let no_limit = if order.limit_price > PRICE_SCALE { PRICE_SCALE } else { order.limit_price };
+            let candidate = if order.side { order.limit_price } else { PRICE_SCALE - no_limit };
+            let candidate = if candidate > PRICE_SCALE { PRICE_SCALE } else { candidate };
+            let valid = amounts[j] > 0 && candidate > 0 && candidate < PRICE_SCALE;
+
+            let (yes_collateral, no_collateral) = eligible_collateral(&orders, &amounts, candidate);
+            let yes_shares = shares_at(yes_collateral, candidate);
+            let no_shares = shares_at(no_collateral, PRICE_SCALE - candidate);
+            let matched = if yes_shares < no_shares { yes_shares } else { no_shares };
+
+            let better = valid && matched > best_matched;
+            best_price = if better { candidate } else { best_price };
+            best_matched = if better { matched } else { best_matched };
+        }
+
+        // Without a cross, orders trade against the pool at its current price
+        let pool_total = (state.yes_reserves as u128) + (state.no_reserves as u128);
+        let pool_denominator = if pool_total > 0 { pool_total } else { 1 };
+        let pool_price = if pool_total > 0 {
+            ((state.no_reserves as u128) * (PRICE_SCALE as u128) / pool_denominator) as u64
+        } else {
+            PRICE_SCALE / 2
+        };
+        let clearing_price = if best_matched > 0 { best_price } else { pool_price };
+        let no_price = PRICE_SCALE - clearing_price;
+
+        let (yes_collateral, no_collateral) = eligible_collateral(&orders, &amounts, clearing_price);
+        let yes_shares = shares_at(yes_collateral, clearing_price);
+        let no_shares = shares_at(no_collateral, no_price);
+        let long_is_yes = yes_shares > no_shares;
+        let matched = if long_is_yes { no_shares } else { yes_shares };
 
-        // Simplified batch clearing logic
-        let total_yes_demand = if order.side { order.amount } else { 0 };
-        let total_no_demand = if !order.side { order.amount } else { 0 };
+        // The side with more demand is only matched up to the other side;
+        // its residual collateral is offered to the pool
+        let (long_collateral, long_price) = if long_is_yes {
+            (yes_collateral, clearing_price)
+        } else {
+            (no_collateral, no_price)
+        };
+        // Rounded up so the matched shares are fully paid for
+        let matched_collateral = div_ceil(matched * (long_price as u128), PRICE_SCALE as u128);
+        let residual = long_collateral - matched_collateral;
 
-        // Calculate clearing price
-        let clearing_price = if total_yes_demand > 0 && total_no_demand > 0 {
-            (total_no_demand * 1000) / (total_yes_demand + total_no_demand)
+        let (bought_reserve, other_reserve) = if long_is_yes {
+            (state.yes_reserves as u128, state.no_reserves as u128)
         } else {
-            500 // Default 50/50 if unbalanced
+            (state.no_reserves as u128, state.yes_reserves as u128)
         };
+        let k = bought_reserve * other_reserve;
+        let new_other = other_reserve + residual;
+        let new_other_denominator = if new_other > 0 { new_other } else { 1 };
+        let new_bought = k / new_other_denominator;
+        let pool_shares = bought_reserve + residual - new_bought;
 
-        // Calculate fills
-        let total_yes_filled = total_yes_demand / 2;
-        let total_no_filled = total_no_demand / 2;
+        // Only route to the pool if its average price is within the uniform
+        // price and its reserves still fit once the residual is added
+        let routed = route_to_pool
+            && residual > 0
+            && pool_shares > 0
+            && new_other <= U64_MAX
+            && pool_shares * (long_price as u128) >= residual * (PRICE_SCALE as u128);
 
-        // Update reserves
-        let new_yes_reserves = yes_reserves + total_no_filled;
-        let new_no_reserves = no_reserves + total_yes_filled;
+        let long_shares = if routed { matched + pool_shares } else { matched };
+        let long_spent = if routed { long_collateral } else { matched_collateral };
 
-        let result = BatchClearResult {
-            clearing_price,
-            total_yes_filled,
-            total_no_filled,
-            new_yes_reserves,
-            new_no_reserves,
+        let (new_yes_reserves, new_no_reserves) = if !routed {
+            (state.yes_reserves, state.no_reserves)
+        } else if long_is_yes {
+            (new_bought as u64, new_other as u64)
+        } else {
+            (new_other as u64, new_bought as u64)
         };
 
-        order_ctxt.owner.from_arcis(result)
+        // Short side fills in full at the uniform price; the long side is
+        // rationed pro rata by collateral, with shares rounded down and spend
+        // rounded up so every order pays at least the price of its shares
+        let long_denominator = if long_collateral > 0 { long_collateral } else { 1 };
+        let mut sides = [false; MAX_BATCH_ORDERS];
+        let mut shares = [0u64; MAX_BATCH_ORDERS];
+        let mut spent = [0u64; MAX_BATCH_ORDERS];
+        let mut refunds = [0u64; MAX_BATCH_ORDERS];
+        for i in 0..MAX_BATCH_ORDERS {
+            let order = &orders[i];
+            let eligible = if order.side {
+                order.limit_price >= clearing_price
+            } else {
+                order.limit_price >= no_price
+            };
+            let amount = if eligible { amounts[i] } else { 0 };
+            let on_long_side = order.side == long_is_yes;
+
+            let long_fill_shares = (amount as u128) * long_shares / long_denominator;
+            let long_fill_spent = div_ceil((amount as u128) * long_spent, long_denominator) as u64;
+            let short_price = if order.side { clearing_price } else { no_price };
+            let short_fill_shares = shares_at(amount as u128, short_price);
+
+            let order_shares = saturate_u64(if on_long_side { long_fill_shares } else { short_fill_shares });
+            let order_spent = if on_long_side { long_fill_spent } else { amount };
+
+            // Whatever an unused slot holds, it settles to nothing
+            sides[i] = active[i] && order.side && order_shares > 0;
+            shares[i] = if active[i] { order_shares } else { 0 };
+            spent[i] = if active[i] { order_spent } else { 0 };
+            refunds[i] = if active[i] { escrows[i] - order_spent } else { 0 };
+        }
+
+        let new_state = CfmmState {
+            yes_reserves: new_yes_reserves,
+            no_reserves: new_no_reserves,
+        };
+        let public_price = coarse_price(new_state.yes_reserves, new_state.no_reserves);
+
+        // The uniform price and each order's settlement are public once the
+        // batch clears; each trader also gets their fill encrypted to them
+        (
+            state_ctxt.owner.from_arcis(new_state),
+            public_price.reveal(),
+            clearing_price.reveal(),
+            order_0.owner.from_arcis(OrderFill { shares: shares[0], spent: spent[0] }),
+            order_1.owner.from_arcis(OrderFill { shares: shares[1], spent: spent[1] }),
+            order_2.owner.from_arcis(OrderFill { shares: shares[2], spent: spent[2] }),
+            order_3.owner.from_arcis(OrderFill { shares: shares[3], spent: spent[3] }),
+            sides.reveal(),
+            shares.reveal(),
+            refunds.reveal(),
+        )
+    }
+
+    // ========== Liquidity Circuits ==========
+
+    /// Deposit `amount` complete sets into the pool. Shares are priced against
+    /// the pool weight max(yes, no) plus the LP fees already accrued, so part
+    /// of the deposit (`fee_buy_in`) buys into those fees. The pool absorbs
+    /// the rest in proportion to its reserves and the leftover outcome tokens
+    /// go back to the provider. Those are minted publicly, so a deposit
+    /// reveals the pool ratio at that moment. Only a deposit into a pool with
+    /// neither shares nor reserves seeds it at one share per complete set.
+    #[instruction]
+    pub fn add_liquidity(
+        state_ctxt: Enc<Mxe, CfmmState>,
+        amount: u64,
+        total_shares: u64,
+        lp_fees_accrued: u64,
+    ) -> (Enc<Mxe, CfmmState>, u64, u64, u64, u64) {
+        let state = state_ctxt.to_arcis();
+
+        let pool_weight = if state.yes_reserves > state.no_reserves {
+            state.yes_reserves
+        } else {
+            state.no_reserves
+        };
+        let empty_pool = pool_weight == 0;
+        let seeding = total_shares == 0 && empty_pool;
+        // Shares with no reserves behind them, or reserves no shares claim,
+        // mean the books are out of step. Pricing against them would dilute
+        // the existing providers or hand the depositor ownerless reserves, so
+        // the deposit comes back as a complete set of outcome tokens instead.
+        let mismatched = (total_shares == 0 && !empty_pool) || (total_shares > 0 && empty_pool);
+        let weight = if empty_pool { 1 } else { pool_weight as u128 };
+        let value = weight + (lp_fees_accrued as u128);
+
+        let shares = if seeding {
+            amount
+        } else if mismatched {
+            0
+        } else {
+            ((amount as u128) * (total_shares as u128) / value) as u64
+        };
+        let deposited = if seeding {
+            amount
+        } else if mismatched {
+            0
+        } else {
+            ((amount as u128) * weight / value) as u64
+        };
+        let yes_added = if seeding {
+            amount
+        } else {
+            ((deposited as u128) * (state.yes_reserves as u128) / weight) as u64
+        };
+        let no_added = if seeding {
+            amount
+        } else {
+            ((deposited as u128) * (state.no_reserves as u128) / weight) as u64
+        };
+        let fee_buy_in = if mismatched { 0 } else { amount - deposited };
+        let yes_returned = if mismatched { amount } else { deposited - yes_added };
+        let no_returned = if mismatched { amount } else { deposited - no_added };
+
+        let new_state = CfmmState {
+            yes_reserves: state.yes_reserves + yes_added,
+            no_reserves: state.no_reserves + no_added,
+        };
+
+        (
+            state_ctxt.owner.from_arcis(new_state),
+            shares.reveal(),
+            fee_buy_in.reveal(),
+            yes_returned.reveal(),
+            no_returned.reveal(),
+        )
+    }
+
+    /// Withdraw `shares / total_shares` of both reserves. Shares beyond
+    /// `total_shares` are clamped so the pool can never be overdrawn.
+    #[instruction]
+    pub fn remove_liquidity(
+        state_ctxt: Enc<Mxe, CfmmState>,
+        shares: u64,
+        total_shares: u64,
+    ) -> (Enc<Mxe, CfmmState>, u64, u64) {
+        let state = state_ctxt.to_arcis();
+
+        let shares = if shares > total_shares { total_shares } else { shares };
+        let total = if total_shares > 0 { total_shares as u128 } else { 1 };
+        let yes_out = ((state.yes_reserves as u128) * (shares as u128) / total) as u64;
+        let no_out = ((state.no_reserves as u128) * (shares as u128) / total) as u64;
+
+        let new_state = CfmmState {
+            yes_reserves: state.yes_reserves - yes_out,
+            no_reserves: state.no_reserves - no_out,
+        };
+
+        (
+            state_ctxt.owner.from_arcis(new_state),
+            yes_out.reveal(),
+            no_out.reveal(),
+        )
     }
 
     // ========== Resolve Market Circuit ==========
 
+    /// Resolver slots per market; must match MAX_RESOLVERS in the program
+    const MAX_RESOLVERS: usize = 10;
+
     pub struct Attestation {
         outcome: bool, // true for YES, false for NO
-        weight: u64,
     }
 
+    /// Final outcome codes; must match OUTCOME_NO / OUTCOME_YES /
+    /// OUTCOME_INVALID in the program
+    const OUTCOME_NO: u8 = 0;
+    const OUTCOME_YES: u8 = 1;
+    const OUTCOME_INVALID: u8 = 2;
+
     pub struct ResolutionResult {
-        final_outcome: bool,
+        final_outcome: u8,
         confidence: u64,
     }
 
+    /// Stake-weighted majority over every resolver's encrypted vote. Unused
+    /// slots carry zero stake and so never count. A tie, including no stake
+    /// at all, resolves INVALID rather than favouring either side.
     #[instruction]
     pub fn resolve_market(
-        // Single attestation - in production would aggregate multiple
-        attestation_ctxt: Enc<Shared, Attestation>,
-    ) -> Enc<Shared, ResolutionResult> {
-        let attestation = attestation_ctxt.to_arcis();
+        vote_ctxts: [Enc<Shared, Attestation>; MAX_RESOLVERS],
+        stakes: [u64; MAX_RESOLVERS],
+    ) -> (u8, u64) {
+        // Weighted voting: sum stake behind each outcome
+        let mut yes_weight = 0u64;
+        let mut no_weight = 0u64;
+        for (vote_ctxt, stake) in vote_ctxts.into_iter().zip(stakes) {
+            let vote = vote_ctxt.to_arcis();
+            yes_weight += if vote.outcome { stake } else { 0 };
+            no_weight += if vote.outcome { 0 } else { stake };
+        }
 
-        // In production, this would aggregate weighted votes
-        // For now, single attestation determines outcome
-        let final_outcome = attestation.outcome;
-        let confidence = attestation.weight;
+        let final_outcome = if yes_weight > no_weight {
+            OUTCOME_YES
+        } else if no_weight > yes_weight {
+            OUTCOME_NO
+        } else {
+            OUTCOME_INVALID
+        };
+        let confidence = if yes_weight > no_weight { yes_weight } else { no_weight };
 
         let result = ResolutionResult {
             final_outcome,
             confidence,
         };
 
-        attestation_ctxt.owner.from_arcis(result)
+        // Only the outcome and the stake behind it are revealed, never how
+        // any single resolver voted
+        (result.final_outcome.reveal(), result.confidence.reveal())
     }
 }
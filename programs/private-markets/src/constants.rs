@@ -12,6 +12,9 @@ pub const MIN_FEE_BPS: u16 = 10;
 /// Maximum fee basis points (10%)
 pub const MAX_FEE_BPS: u16 = 1000;
 
+/// Basis point denominator for fee calculations
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
 /// Minimum batch interval in seconds (5 minutes)
 pub const MIN_BATCH_INTERVAL: i64 = 300;
 
@@ -21,15 +24,38 @@ pub const MAX_BATCH_INTERVAL: i64 = 86400;
 /// Minimum resolver quorum
 pub const MIN_QUORUM: u8 = 1;
 
+/// Maximum number of collateral mints the protocol config can allow
+pub const MAX_COLLATERAL_MINTS: usize = 8;
+
 /// Minimum stake required to become a resolver (in lamports or smallest token unit)
 pub const MIN_RESOLVER_STAKE: u64 = 1_000_000; // 1 token with 6 decimals
 
 /// CFMM constant product multiplier (for precision)
 pub const CFMM_PRECISION: u64 = 1_000_000;
 
+/// Minimum LMSR liquidity parameter `b` (1 token with 6 decimals)
+pub const MIN_LMSR_B: u64 = 1_000_000;
+
+/// Share of trading fees paid to liquidity providers, in basis points
+pub const LP_FEE_SHARE_BPS: u64 = 5_000;
+
 /// Initial CFMM reserves for each side
 pub const INITIAL_RESERVES: u64 = 1_000_000 * CFMM_PRECISION;
 
+/// Final outcome value for a market resolved NO
+pub const OUTCOME_NO: u8 = 0;
+
+/// Final outcome value for a market resolved YES
+pub const OUTCOME_YES: u8 = 1;
+
+/// Final outcome value for an invalid market (each side redeems at half
+/// value). Resolution lands here when the resolver stake is tied, including
+/// when no stake backs either side.
+pub const OUTCOME_INVALID: u8 = 2;
+
+/// Seed for the singleton protocol config PDA
+pub const CONFIG_SEED: &[u8] = b"config";
+
 /// Seed for market PDA
 pub const MARKET_SEED: &[u8] = b"market";
 
@@ -45,25 +71,76 @@ pub const YES_MINT_SEED: &[u8] = b"yes_mint";
 /// Seed for no token mint PDA
 pub const NO_MINT_SEED: &[u8] = b"no_mint";
 
+/// Seed for LP share token mint PDA
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+
+/// Seed for encrypted CFMM reserves PDA
+pub const RESERVES_SEED: &[u8] = b"reserves";
+
+/// Seed for encrypted user position PDA
+pub const POSITION_SEED: &[u8] = b"position";
+
 /// Seed for resolver account PDA
 pub const RESOLVER_SEED: &[u8] = b"resolver";
 
+/// Seed for a market's encrypted resolver votes PDA
+pub const VOTES_SEED: &[u8] = b"votes";
+
 /// Seed for batch state PDA
 pub const BATCH_SEED: &[u8] = b"batch";
 
+/// Seed for a batch's encrypted order queue PDA
+pub const ORDER_QUEUE_SEED: &[u8] = b"order_queue";
+
+/// Maximum number of orders a single batch can hold
+pub const MAX_BATCH_ORDERS: usize = 4;
+
+/// Depth of a batch's order commitment tree (2^depth == MAX_BATCH_ORDERS)
+pub const ORDER_TREE_DEPTH: usize = 2;
+
 // Arcium Computation Definition Offsets are now defined in lib.rs
 // using the comp_def_offset() function provided by #[arcium_program] macro
 // These compute hash-based offsets from the computation names
 
 // Account field offsets for Argument::Account() usage
-// Market account structure offsets (see state.rs for field layout)
-/// Offset to cfmm_state_commitment field in Market account (401 bytes from start)
-pub const MARKET_CFMM_COMMITMENT_OFFSET: u32 = 401;
+// EncryptedReserves account structure offsets
+/// Offset to encrypted_reserves ciphertexts in EncryptedReserves account (40 bytes from start)
+pub const RESERVES_CIPHERTEXT_OFFSET: u32 = 40;
+
+/// Length of the encrypted reserves (two 32-byte ciphertexts)
+pub const RESERVES_CIPHERTEXT_LEN: u32 = 64;
+
+// UserPosition account structure offsets
+/// Offset to encrypted_position ciphertexts in UserPosition account (120 bytes from start)
+pub const POSITION_CIPHERTEXT_OFFSET: u32 = 120;
+
+/// Length of the encrypted position (three 32-byte ciphertexts)
+pub const POSITION_CIPHERTEXT_LEN: u32 = 96;
+
+// OrderQueue account structure offsets
+/// Offset to the first queued order in OrderQueue account (41 bytes from start)
+pub const ORDER_QUEUE_ORDERS_OFFSET: u32 = 41;
+
+/// Serialized size of one QueuedOrder
+pub const QUEUED_ORDER_LEN: u32 = 216;
+
+/// Offset of the order ciphertexts within a QueuedOrder
+pub const QUEUED_ORDER_CIPHERTEXT_OFFSET: u32 = 120;
+
+/// Length of an encrypted order (three 32-byte ciphertexts)
+pub const ORDER_CIPHERTEXT_LEN: u32 = 96;
+
+// ResolutionVotes account structure offsets
+/// Offset to the first vote in ResolutionVotes account (41 bytes from start)
+pub const RESOLUTION_VOTES_OFFSET: u32 = 41;
+
+/// Serialized size of one ResolverVote
+pub const RESOLVER_VOTE_LEN: u32 = 120;
 
-/// Offset to yes_reserves field in Market account (433 bytes from start)
-pub const MARKET_YES_RESERVES_OFFSET: u32 = 433;
+/// Offset of the vote ciphertext within a ResolverVote
+pub const RESOLVER_VOTE_CIPHERTEXT_OFFSET: u32 = 88;
 
-/// Offset to no_reserves field in Market account (441 bytes from start)
-pub const MARKET_NO_RESERVES_OFFSET: u32 = 441;
+/// Length of an encrypted vote (one 32-byte ciphertext)
+pub const VOTE_CIPHERTEXT_LEN: u32 = 32;
 
 // Note: SIGN_PDA_SEED is provided by arcium_anchor::prelude
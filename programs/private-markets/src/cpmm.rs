@@ -0,0 +1,113 @@
+//! Plaintext reference for the `private_trade` circuit in `encrypted-ixs`.
+//!
+//! Mirrors the circuit's constant-product pricing, including its guards, so
+//! quotes computed off the MPC path match what the cluster returns.
+
+use crate::constants::BPS_DENOMINATOR;
+
+/// Fixed-point scale for quoted prices (1.0 == PRICE_SCALE)
+pub const PRICE_SCALE: u64 = 1_000_000;
+
+/// Result of pricing a trade against the pool
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpmmQuote {
+    /// Shares of the bought outcome paid out
+    pub shares_out: u64,
+    /// Collateral per share, fee included, in PRICE_SCALE
+    pub effective_price: u64,
+    /// Trading fee taken from the collateral
+    pub fee: u64,
+    /// Pool reserves after the trade, as (yes, no)
+    pub new_reserves: (u64, u64),
+}
+
+/// Price buying `side` (true for YES) with `user_amount` collateral, capped at
+/// `escrow`. Returns `None` when the circuit would reject the trade: an empty
+/// pool, no shares out, or an effective price above `max_price`.
+pub fn cpmm_quote(
+    yes_reserves: u64,
+    no_reserves: u64,
+    side: bool,
+    user_amount: u64,
+    escrow: u64,
+    fee_bps: u64,
+    max_price: u64,
+) -> Option<CpmmQuote> {
+    let user_amount = user_amount.min(escrow);
+    let fee = user_amount * fee_bps / BPS_DENOMINATOR;
+    let amount_in = user_amount - fee;
+
+    let (bought_reserve, other_reserve) = if side {
+        (yes_reserves, no_reserves)
+    } else {
+        (no_reserves, yes_reserves)
+    };
+    let k = bought_reserve as u128 * other_reserve as u128;
+    let new_other = other_reserve + amount_in;
+    let new_bought = (k / new_other.max(1) as u128) as u64;
+    let shares_out = bought_reserve + amount_in - new_bought;
+
+    let effective_price =
+        (user_amount as u128 * PRICE_SCALE as u128 / shares_out.max(1) as u128) as u64;
+
+    let filled = k > 0 && shares_out > 0 && effective_price <= max_price;
+    if !filled {
+        return None;
+    }
+    let new_reserves = if side {
+        (new_bought, new_other)
+    } else {
+        (new_other, new_bought)
+    };
+    Some(CpmmQuote {
+        shares_out,
+        effective_price,
+        fee,
+        new_reserves,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESERVES: u64 = 1_000_000_000;
+
+    #[test]
+    fn fills_within_max_price_and_keeps_k() {
+        let quote = cpmm_quote(RESERVES, RESERVES, true, 1_000_000, 1_000_000, 100, PRICE_SCALE)
+            .expect("trade should fill");
+        assert_eq!(quote.fee, 10_000);
+        assert!(quote.shares_out > 990_000);
+        let (yes, no) = quote.new_reserves;
+        assert!(yes as u128 * no as u128 <= RESERVES as u128 * RESERVES as u128);
+        assert_eq!(no, RESERVES + 990_000);
+    }
+
+    #[test]
+    fn rejects_trades_above_max_price() {
+        let quote = cpmm_quote(RESERVES, RESERVES, false, 1_000_000, 1_000_000, 100, PRICE_SCALE)
+            .unwrap();
+        let limit = quote.effective_price - 1;
+        assert_eq!(
+            cpmm_quote(RESERVES, RESERVES, false, 1_000_000, 1_000_000, 100, limit),
+            None
+        );
+    }
+
+    #[test]
+    fn empty_pool_rejects_without_dividing_by_zero() {
+        assert_eq!(cpmm_quote(0, 0, true, 0, 0, 100, PRICE_SCALE), None);
+        assert_eq!(cpmm_quote(0, 0, true, 1_000_000, 1_000_000, 100, u64::MAX), None);
+        // One drained side must not hand out the other for free
+        assert_eq!(cpmm_quote(RESERVES, 0, true, 0, 0, 0, u64::MAX), None);
+        assert_eq!(cpmm_quote(RESERVES, 0, true, 10, 10, 0, u64::MAX), None);
+    }
+
+    #[test]
+    fn spends_at_most_the_escrow() {
+        let capped = cpmm_quote(RESERVES, RESERVES, true, 5_000_000, 1_000_000, 100, PRICE_SCALE);
+        let exact = cpmm_quote(RESERVES, RESERVES, true, 1_000_000, 1_000_000, 100, PRICE_SCALE);
+        assert_eq!(capped, exact);
+    }
+}
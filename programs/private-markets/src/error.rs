@@ -40,12 +40,39 @@ pub enum MarketError {
     
     #[msg("Resolver already staked")]
     ResolverAlreadyStaked,
+
+    #[msg("Too many resolvers for this market")]
+    TooManyResolvers,
     
     #[msg("Invalid attestation")]
     InvalidAttestation,
+
+    #[msg("Resolver has already attested")]
+    AlreadyAttested,
     
     #[msg("Batch clearing window has not ended")]
     BatchWindowOpen,
+
+    #[msg("Batch is not open for orders")]
+    BatchNotOpen,
+
+    #[msg("Batch has not been closed")]
+    BatchNotClosed,
+
+    #[msg("Batch has already been cleared")]
+    BatchAlreadyCleared,
+
+    #[msg("Batch has not been cleared")]
+    BatchNotCleared,
+
+    #[msg("Batch with queued orders must be cleared by the MPC")]
+    BatchHasOrders,
+
+    #[msg("Batch order queue is full")]
+    BatchFull,
+
+    #[msg("Batch epoch does not match the current window")]
+    WrongEpoch,
     
     #[msg("Invalid state commitment")]
     InvalidStateCommitment,
@@ -76,4 +103,55 @@ pub enum MarketError {
 
     #[msg("Cluster not set")]
     ClusterNotSet,
+
+    #[msg("Withdrawal exceeds accrued fees")]
+    InsufficientFees,
+
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+
+    #[msg("Collateral mint is not allowed by the protocol config")]
+    CollateralMintNotAllowed,
+
+    #[msg("Too many allowed collateral mints")]
+    TooManyCollateralMints,
+
+    #[msg("Invalid LMSR liquidity parameter")]
+    InvalidLiquidityParameter,
+
+    #[msg("Instruction does not match the market's pricing curve")]
+    WrongPricingCurve,
+
+    #[msg("Insufficient pool liquidity")]
+    InsufficientLiquidity,
+
+    #[msg("Market reserves have not been initialized")]
+    ReservesNotInitialized,
+
+    #[msg("User position has not been initialized")]
+    PositionNotInitialized,
+
+    #[msg("Order is not included in the batch")]
+    InvalidOrderProof,
+
+    #[msg("No matching order in the batch")]
+    OrderNotFound,
+
+    #[msg("Batch fill already claimed")]
+    FillAlreadyClaimed,
+
+    #[msg("A computation on this state is still pending")]
+    ComputationPending,
+
+    #[msg("Computation result does not match the pending state")]
+    StaleComputation,
+
+    #[msg("LMSR markets must be funded from the creator's collateral account")]
+    LmsrSubsidyRequired,
+
+    #[msg("Batch is not waiting on a clearing computation")]
+    BatchNotClearing,
+
+    #[msg("Attestations have not reached the resolver quorum")]
+    QuorumNotReached,
 }
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use crate::{AcceptAdmin, AdminTransferred}; // Import account struct and event from crate root
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_admin = config.admin;
+
+    config.admin = ctx.accounts.new_admin.key();
+    config.pending_admin = None;
+
+    msg!("Protocol admin transferred to {}", config.admin);
+
+    emit!(AdminTransferred {
+        previous_admin,
+        new_admin: config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo, Transfer};
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use crate::constants::*;
+use crate::error::MarketError;
+use crate::{AddLiquidity, AddLiquidityCallback, LiquidityAdded}; // Import account structs and event from crate root
+
+pub fn handler(ctx: Context<AddLiquidity>, computation_offset: u64, amount: u64) -> Result<()> {
+    require!(amount > 0, MarketError::InsufficientCollateral);
+
+    let market = &ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp < market.end_time,
+        MarketError::MarketEnded
+    );
+
+    // Escrow the deposit; the callback splits it between the pool, the LP
+    // fee buy-in and the outcome tokens returned to the provider
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.user_collateral.to_account_info(),
+        to: ctx.accounts.collateral_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    let args = vec![
+        Argument::PlaintextU128(ctx.accounts.reserves.nonce),
+        Argument::Account(
+            ctx.accounts.reserves.key(),
+            RESERVES_CIPHERTEXT_OFFSET,
+            RESERVES_CIPHERTEXT_LEN,
+        ),
+        Argument::PlaintextU64(amount),
+        Argument::PlaintextU64(market.total_liquidity),
+        Argument::PlaintextU64(market.lp_fees_accrued),
+    ];
+
+    let callback_accounts = [
+        market.key(),
+        ctx.accounts.reserves.key(),
+        ctx.accounts.collateral_vault.key(),
+        ctx.accounts.fee_vault.key(),
+        ctx.accounts.yes_mint.key(),
+        ctx.accounts.no_mint.key(),
+        ctx.accounts.lp_mint.key(),
+        ctx.accounts.user_collateral.key(),
+        ctx.accounts.user_yes_tokens.key(),
+        ctx.accounts.user_no_tokens.key(),
+        ctx.accounts.user_lp_tokens.key(),
+    ]
+    .into_iter()
+    .map(|pubkey| CallbackAccount {
+        pubkey,
+        is_writable: true,
+    })
+    .chain(std::iter::once(CallbackAccount {
+        pubkey: ctx.accounts.token_program.key(),
+        is_writable: false,
+    }))
+    .collect::<Vec<_>>();
+
+    let market_key = market.key();
+    // Withdrawals also lock the reserves, so total_liquidity cannot change
+    // between this read and the callback minting the new shares
+    ctx.accounts.reserves.begin_computation()?;
+    // Kept so the callback can refund the deposit if the computation fails
+    ctx.accounts.reserves.hold_liquidity(0, amount);
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![AddLiquidityCallback::callback_ix(&callback_accounts)],
+        1,
+    )?;
+
+    msg!("Queued {} liquidity for market {}", amount, market_key);
+
+    Ok(())
+}
+
+/// Apply a completed `add_liquidity` computation: store the new encrypted
+/// reserves, mint LP shares and any unabsorbed outcome tokens, and move the
+/// fee buy-in from the collateral vault to the fee vault
+pub fn callback_handler(
+    ctx: Context<AddLiquidityCallback>,
+    new_state: MXEEncryptedStruct<2>,
+    shares: u64,
+    fee_buy_in: u64,
+    yes_returned: u64,
+    no_returned: u64,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        to: ctx.accounts.user_lp_tokens.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+    token::mint_to(cpi_ctx, shares)?;
+
+    if yes_returned > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.yes_mint.to_account_info(),
+            to: ctx.accounts.user_yes_tokens.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::mint_to(cpi_ctx, yes_returned)?;
+    }
+
+    if no_returned > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.no_mint.to_account_info(),
+            to: ctx.accounts.user_no_tokens.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::mint_to(cpi_ctx, no_returned)?;
+    }
+
+    if fee_buy_in > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, fee_buy_in)?;
+    }
+
+    let commitment = ctx
+        .accounts
+        .reserves
+        .set_state(new_state.nonce, new_state.ciphertexts);
+
+    let market = &mut ctx.accounts.market;
+    market.cfmm_state_commitment = commitment;
+    market.total_liquidity = market
+        .total_liquidity
+        .checked_add(shares)
+        .ok_or(MarketError::Overflow)?;
+    // New shares buy into the LP fees already accrued so earlier providers'
+    // fees are not diluted
+    market.lp_fees_accrued = market
+        .lp_fees_accrued
+        .checked_add(fee_buy_in)
+        .ok_or(MarketError::Overflow)?;
+
+    msg!(
+        "Added liquidity to market {} for {} LP shares",
+        market.key(),
+        shares
+    );
+
+    emit!(LiquidityAdded {
+        market: market.key(),
+        provider: ctx.accounts.user_lp_tokens.owner,
+        shares,
+        fee_buy_in,
+        yes_returned,
+        no_returned,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
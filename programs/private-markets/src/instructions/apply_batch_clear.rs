@@ -9,28 +9,43 @@ pub fn handler(
     uniform_price: u64,
 ) -> Result<()> {
     let market = &mut ctx.accounts.market;
+    let batch = &mut ctx.accounts.batch;
 
     require!(
         market.resolution_state == ResolutionState::Active,
         MarketError::MarketAlreadyResolved
     );
+    require!(
+        batch.state == BatchStateEnum::Closed,
+        MarketError::BatchNotClosed
+    );
+    // Queued orders need per-order fills, which only `batch_clear` produces
+    require!(batch.order_count == 0, MarketError::BatchHasOrders);
 
     // Update CFMM state commitment
     market.cfmm_state_commitment = new_state_commitment;
 
-    // Reset batch counter and update next batch time
+    // Bind the price to this epoch
+    batch.record_clear(uniform_price)?;
     let clock = Clock::get()?;
-    market.batch_order_count = 0;
-    market.next_batch_clear = clock.unix_timestamp + market.batch_interval;
+    market.last_clearing_price = uniform_price;
+    market.current_epoch = market.current_epoch.max(
+        batch
+            .epoch
+            .checked_add(1)
+            .ok_or(MarketError::Overflow)?,
+    );
 
     msg!(
-        "Batch cleared for market {} with uniform price: {}",
+        "Batch {} cleared for market {} with uniform price: {}",
+        batch.epoch,
         market.key(),
         uniform_price
     );
 
     emit!(BatchCleared {
         market: market.key(),
+        epoch: batch.epoch,
         uniform_price,
         timestamp: clock.unix_timestamp,
     });
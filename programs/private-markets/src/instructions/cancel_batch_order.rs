@@ -0,0 +1,79 @@
+use crate::constants::*;
+use crate::error::MarketError;
+use crate::state::*;
+use crate::{BatchOrderCancelled, CancelBatchOrder}; // Import account struct and event from crate root
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+pub fn handler(ctx: Context<CancelBatchOrder>, commitment: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let market = &mut ctx.accounts.market;
+    let batch = &mut ctx.accounts.batch;
+    let order_queue = &mut ctx.accounts.order_queue;
+    let owner = ctx.accounts.owner.key();
+
+    // Orders are locked in once the window ends
+    require!(
+        clock.unix_timestamp < batch.end_time,
+        MarketError::BatchNotOpen
+    );
+
+    let count = batch.order_count as usize;
+    let slot = order_queue.orders[..count]
+        .iter()
+        .position(|queued| queued.owner == owner && queued.order.commitment == commitment)
+        .ok_or(MarketError::OrderNotFound)?;
+    // Nothing was filled, so the escrowed fee comes back with the order
+    let amount = order_queue.orders[slot].order.amount;
+    let refund = amount
+        .checked_add(market.trading_fee(amount)?)
+        .ok_or(MarketError::Overflow)?;
+
+    // Shift later orders down so the slots in use stay contiguous
+    order_queue.orders.copy_within(slot + 1..count, slot);
+    order_queue.orders[count - 1] = QueuedOrder::default();
+    batch.order_count -= 1;
+    batch.rebuild_order_tree(&order_queue.orders[..count - 1]);
+    market.batch_order_root = batch.order_root;
+    market.batch_order_count = batch.order_count;
+
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.collateral_vault.to_account_info(),
+        to: ctx.accounts.owner_collateral.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer),
+        refund,
+    )?;
+
+    msg!(
+        "Batch order {} cancelled for market {} epoch {}",
+        slot,
+        market.key(),
+        batch.epoch
+    );
+
+    emit!(BatchOrderCancelled {
+        market: market.key(),
+        epoch: batch.epoch,
+        owner,
+        commitment,
+        refund,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
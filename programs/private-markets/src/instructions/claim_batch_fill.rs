@@ -0,0 +1,121 @@
+use crate::constants::*;
+use crate::error::MarketError;
+use crate::{BatchFillClaimed, ClaimBatchFill}; // Import account struct and event from crate root
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo, Transfer};
+
+pub fn handler(ctx: Context<ClaimBatchFill>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let batch = &mut ctx.accounts.batch;
+    let order_queue = &ctx.accounts.order_queue;
+    let owner = ctx.accounts.owner.key();
+
+    // Settle every unclaimed order the caller placed in this batch
+    let mut found = false;
+    let mut settled = false;
+    let mut yes_shares: u64 = 0;
+    let mut no_shares: u64 = 0;
+    let mut refund: u64 = 0;
+    let mut fee: u64 = 0;
+    for slot in 0..batch.order_count as usize {
+        if order_queue.orders[slot].owner != owner {
+            continue;
+        }
+        found = true;
+        if batch.is_claimed(slot) {
+            continue;
+        }
+        batch.claimed |= 1 << slot;
+        settled = true;
+
+        let fill = &order_queue.fills[slot];
+        if fill.side {
+            yes_shares = yes_shares
+                .checked_add(fill.shares)
+                .ok_or(MarketError::Overflow)?;
+        } else {
+            no_shares = no_shares
+                .checked_add(fill.shares)
+                .ok_or(MarketError::Overflow)?;
+        }
+
+        // The fee escrowed on submission covered the full amount; only the
+        // part the batch actually spent is charged and the rest is refunded
+        let amount = order_queue.orders[slot].order.amount;
+        let spent = amount.saturating_sub(fill.refund);
+        let max_fee = market.trading_fee(amount)?;
+        let order_fee = market.trading_fee(spent)?;
+        refund = refund
+            .checked_add(fill.refund)
+            .and_then(|r| r.checked_add(max_fee - order_fee))
+            .ok_or(MarketError::Overflow)?;
+        fee = fee.checked_add(order_fee).ok_or(MarketError::Overflow)?;
+    }
+    require!(found, MarketError::OrderNotFound);
+    require!(settled, MarketError::FillAlreadyClaimed);
+    market.accrue_fee(fee)?;
+
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    let mints = [
+        (&ctx.accounts.yes_mint, &ctx.accounts.owner_yes_tokens, yes_shares),
+        (&ctx.accounts.no_mint, &ctx.accounts.owner_no_tokens, no_shares),
+    ];
+    let minted = mints.iter().filter(|(_, _, amount)| *amount > 0);
+    for (mint, to, amount) in minted {
+        let cpi_accounts = MintTo {
+            mint: mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::mint_to(cpi_ctx, *amount)?;
+    }
+
+    let transfers = [
+        (&ctx.accounts.owner_collateral, refund),
+        (&ctx.accounts.fee_vault, fee),
+    ];
+    let paid = transfers.iter().filter(|(_, amount)| *amount > 0);
+    for (to, amount) in paid {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: to.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, *amount)?;
+    }
+
+    msg!(
+        "Claimed batch {} fill for {}: {} YES, {} NO, {} refunded, {} fee",
+        batch.epoch,
+        owner,
+        yes_shares,
+        no_shares,
+        refund,
+        fee
+    );
+
+    emit!(BatchFillClaimed {
+        market: market.key(),
+        epoch: batch.epoch,
+        owner,
+        yes_shares,
+        no_shares,
+        refund,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
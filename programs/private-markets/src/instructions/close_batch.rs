@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::error::MarketError;
+use crate::state::*;
+use crate::{BatchClosed, CloseBatch}; // Import account struct and event from crate root
+
+pub fn handler(ctx: Context<CloseBatch>) -> Result<()> {
+    let batch = &mut ctx.accounts.batch;
+    let clock = Clock::get()?;
+
+    require!(
+        batch.state == BatchStateEnum::Open,
+        MarketError::BatchNotOpen
+    );
+    require!(
+        clock.unix_timestamp >= batch.end_time,
+        MarketError::BatchWindowOpen
+    );
+
+    batch.state = BatchStateEnum::Closed;
+
+    msg!(
+        "Closed batch {} for market {} with {} orders",
+        batch.epoch,
+        batch.market,
+        batch.order_count
+    );
+
+    emit!(BatchClosed {
+        market: batch.market,
+        epoch: batch.epoch,
+        order_count: batch.order_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::constants::*;
+use crate::state::*;
+use crate::{BatchClearCallback, CrankBatchClear};
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+
+// Handler function - account struct is defined in lib.rs for #[arcium_program] macro
+
+pub fn handler(ctx: Context<CrankBatchClear>, computation_offset: u64) -> Result<()> {
+    let batch = &mut ctx.accounts.batch;
+
+    // Only one clear may be in flight; the callback moves the batch on to
+    // `Cleared`, or back to `Closed` if the computation fails
+    batch.state = BatchStateEnum::Clearing;
+    let order_count = batch.order_count;
+    let epoch = batch.epoch;
+
+    // Every slot is passed so the circuit has a fixed shape; the circuit
+    // ignores slots at or beyond `order_count`
+    let queue_key = ctx.accounts.order_queue.key();
+    let mut args = Vec::with_capacity(MAX_BATCH_ORDERS * 4 + 4);
+    for (i, queued) in ctx.accounts.order_queue.orders.iter().enumerate() {
+        args.push(Argument::ArcisPubkey(queued.order.encryption_pubkey));
+        args.push(Argument::PlaintextU128(queued.order.nonce));
+        args.push(Argument::Account(
+            queue_key,
+            ORDER_QUEUE_ORDERS_OFFSET + i as u32 * QUEUED_ORDER_LEN + QUEUED_ORDER_CIPHERTEXT_OFFSET,
+            ORDER_CIPHERTEXT_LEN,
+        ));
+    }
+    // Escrows cap what each order can spend; empty slots escrow nothing
+    for (i, queued) in ctx.accounts.order_queue.orders.iter().enumerate() {
+        let escrow = if i < order_count as usize {
+            queued.order.amount
+        } else {
+            0
+        };
+        args.push(Argument::PlaintextU64(escrow));
+    }
+    args.push(Argument::PlaintextU8(order_count as u8));
+
+    // Residual demand only trades against a constant-product pool
+    let route_to_pool = ctx.accounts.market.pricing_curve == PricingCurve::Cpmm;
+    args.push(Argument::PlaintextBool(route_to_pool));
+
+    // Read the MXE-encrypted CFMM state
+    let reserves_key = ctx.accounts.reserves.key();
+    args.push(Argument::PlaintextU128(ctx.accounts.reserves.nonce));
+    args.push(Argument::Account(
+        reserves_key,
+        RESERVES_CIPHERTEXT_OFFSET,
+        RESERVES_CIPHERTEXT_LEN,
+    ));
+
+    let market_key = ctx.accounts.market.key();
+    let batch_key = ctx.accounts.batch.key();
+    ctx.accounts.reserves.begin_computation()?;
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![BatchClearCallback::callback_ix(&[
+            CallbackAccount {
+                pubkey: market_key,
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: reserves_key,
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: batch_key,
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: queue_key,
+                is_writable: true,
+            },
+        ])],
+        1,
+    )?;
+
+    msg!(
+        "Batch {} for market {} queued for clearing with {} orders",
+        epoch,
+        market_key,
+        order_count
+    );
+
+    Ok(())
+}
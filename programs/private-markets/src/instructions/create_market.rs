@@ -1,29 +1,47 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
-use anchor_spl::token::spl_token;
-use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::{self, Transfer};
 use crate::constants::*;
 use crate::error::MarketError;
+use crate::lmsr::lmsr_subsidy;
 use crate::state::*;
 use crate::CreateMarket; // Import account struct from crate root
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateMarket>,
+    market_id: u64,
     question: String,
     end_time: i64,
     fee_bps: u16,
     batch_interval: i64,
     resolver_quorum: u8,
+    pricing_curve: PricingCurve,
+    lmsr_b: u64,
 ) -> Result<()> {
-    let market = &mut ctx.accounts.market;
     let clock = Clock::get()?;
 
+    validate_market_params(
+        &question,
+        end_time,
+        fee_bps,
+        batch_interval,
+        resolver_quorum,
+        clock.unix_timestamp,
+    )?;
+    if pricing_curve == PricingCurve::Lmsr {
+        require!(lmsr_b >= MIN_LMSR_B, MarketError::InvalidLiquidityParameter);
+    }
+
+    let market = &mut ctx.accounts.market;
+
     market.authority = ctx.accounts.authority.key();
+    market.market_id = market_id;
+    // Vaults and mints are market-PDA-derived and initialized by the account constraints
     market.collateral_vault = ctx.accounts.collateral_vault.key();
     market.fee_vault = ctx.accounts.fee_vault.key();
     market.yes_mint = ctx.accounts.yes_mint.key();
     market.no_mint = ctx.accounts.no_mint.key();
+    market.lp_mint = ctx.accounts.lp_mint.key();
     market.collateral_mint = ctx.accounts.collateral_mint.key();
     market.question = question;
     market.end_time = end_time;
@@ -31,17 +49,80 @@ pub fn handler(
     market.batch_interval = batch_interval;
     market.resolver_quorum = resolver_quorum;
     market.resolution_state = ResolutionState::Active;
-    market.yes_reserves = 0;
-    market.no_reserves = 0;
+    market.pricing_curve = pricing_curve;
+    market.lmsr_b = if pricing_curve == PricingCurve::Lmsr { lmsr_b } else { 0 };
     market.total_liquidity = 0;
+    market.fees_accrued = 0;
+    market.lp_fees_accrued = 0;
     market.next_batch_clear = clock.unix_timestamp + batch_interval;
     market.batch_order_count = 0;
+    market.current_epoch = 0;
+    market.batch_start = clock.unix_timestamp;
     market.resolver_count = 0;
+    // Reserves live in the EncryptedReserves account created by initialize_reserves
     market.cfmm_state_commitment = [0; 32];
+    market.public_price = 0;
     market.authority_bump = ctx.bumps.market;
     market.bump = ctx.bumps.market;
     market.final_outcome = None;
 
+    // An LMSR market maker can lose up to b * ln 2 to traders, so the creator
+    // deposits that up front and the vault always covers the winning side
+    if pricing_curve == PricingCurve::Lmsr {
+        let creator_collateral = ctx
+            .accounts
+            .creator_collateral
+            .as_ref()
+            .ok_or(MarketError::LmsrSubsidyRequired)?;
+        let subsidy = lmsr_subsidy(lmsr_b);
+
+        let cpi_accounts = Transfer {
+            from: creator_collateral.to_account_info(),
+            to: ctx.accounts.collateral_vault.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), subsidy)?;
+
+        msg!("LMSR subsidy of {} deposited", subsidy);
+    }
+
     msg!("Market created: {}", market.key());
     Ok(())
 }
+
+/// Validate market creation parameters against the protocol limits in `constants.rs`
+fn validate_market_params(
+    question: &str,
+    end_time: i64,
+    fee_bps: u16,
+    batch_interval: i64,
+    resolver_quorum: u8,
+    now: i64,
+) -> Result<()> {
+    require!(
+        question.len() <= MAX_QUESTION_LEN,
+        MarketError::QuestionTooLong
+    );
+    require!(
+        (MIN_FEE_BPS..=MAX_FEE_BPS).contains(&fee_bps),
+        MarketError::InvalidFeeBps
+    );
+    require!(
+        (MIN_BATCH_INTERVAL..=MAX_BATCH_INTERVAL).contains(&batch_interval),
+        MarketError::InvalidBatchInterval
+    );
+
+    // The market must stay open long enough for at least one full batch window
+    let earliest_end = now
+        .checked_add(batch_interval)
+        .ok_or(MarketError::Overflow)?;
+    require!(end_time >= earliest_end, MarketError::InvalidEndTime);
+
+    require!(
+        resolver_quorum >= MIN_QUORUM && resolver_quorum as usize <= MAX_RESOLVERS,
+        MarketError::InvalidQuorum
+    );
+
+    Ok(())
+}
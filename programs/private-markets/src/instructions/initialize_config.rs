@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::MarketError;
+use crate::InitializeConfig; // Import account struct from crate root
+
+pub fn handler(
+    ctx: Context<InitializeConfig>,
+    treasury: Pubkey,
+    mpc_authority: Pubkey,
+    protocol_fee_bps: u16,
+    allowed_collateral_mints: Vec<Pubkey>,
+) -> Result<()> {
+    validate_config_params(protocol_fee_bps, &allowed_collateral_mints)?;
+
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = None;
+    config.treasury = treasury;
+    config.mpc_authority = mpc_authority;
+    config.protocol_fee_bps = protocol_fee_bps;
+    config.allowed_collateral_mints = allowed_collateral_mints;
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+
+    msg!("Protocol config initialized with admin {}", config.admin);
+    Ok(())
+}
+
+/// Validate protocol config parameters shared by initialize and update
+pub fn validate_config_params(
+    protocol_fee_bps: u16,
+    allowed_collateral_mints: &[Pubkey],
+) -> Result<()> {
+    require!(
+        protocol_fee_bps as u64 <= BPS_DENOMINATOR,
+        MarketError::InvalidFeeBps
+    );
+    require!(
+        allowed_collateral_mints.len() <= MAX_COLLATERAL_MINTS,
+        MarketError::TooManyCollateralMints
+    );
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::InitCfmmStateCallback;
+use crate::InitializeReserves;
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+
+// Handler function - account struct is defined in lib.rs for #[arcium_program] macro
+
+pub fn handler(
+    ctx: Context<InitializeReserves>,
+    computation_offset: u64,
+    nonce: u128,
+) -> Result<()> {
+    let market_key = ctx.accounts.market.key();
+    let reserves_key = ctx.accounts.reserves.key();
+
+    let reserves = &mut ctx.accounts.reserves;
+    reserves.market = market_key;
+    reserves.initialized = false;
+    reserves.bump = ctx.bumps.reserves;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // The MXE encrypts the empty pool; the callback stores the ciphertexts
+    let args = vec![Argument::PlaintextU128(nonce)];
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![InitCfmmStateCallback::callback_ix(&[
+            CallbackAccount {
+                pubkey: market_key,
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: reserves_key,
+                is_writable: true,
+            },
+        ])],
+        1,
+    )?;
+
+    msg!("Encrypted reserves queued for initialization for market {}", market_key);
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Transfer};
+use crate::constants::MARKET_SEED;
+use crate::error::MarketError;
+use crate::{MergePositions, PositionsMerged}; // Import account struct and event from crate root
+
+pub fn handler(ctx: Context<MergePositions>, amount: u64) -> Result<()> {
+    require!(amount > 0, MarketError::InsufficientCollateral);
+
+    let fee = ctx.accounts.market.trading_fee(amount)?;
+    let payout = amount.checked_sub(fee).ok_or(MarketError::Overflow)?;
+
+    // Burn one YES and one NO per unit of collateral released
+    let cpi_accounts_yes = Burn {
+        mint: ctx.accounts.yes_mint.to_account_info(),
+        from: ctx.accounts.user_yes_tokens.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_yes);
+    token::burn(cpi_ctx, amount)?;
+
+    let cpi_accounts_no = Burn {
+        mint: ctx.accounts.no_mint.to_account_info(),
+        from: ctx.accounts.user_no_tokens.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_no);
+    token::burn(cpi_ctx, amount)?;
+
+    // Release collateral from the vault, signed by the market PDA
+    let market = &ctx.accounts.market;
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts_transfer = Transfer {
+        from: ctx.accounts.collateral_vault.to_account_info(),
+        to: ctx.accounts.user_collateral.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts_transfer, signer);
+    token::transfer(cpi_ctx, payout)?;
+
+    if fee > 0 {
+        let cpi_accounts_fee = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts_fee, signer);
+        token::transfer(cpi_ctx, fee)?;
+    }
+
+    let market = &mut ctx.accounts.market;
+    market.accrue_fee(fee)?;
+
+    msg!(
+        "Merged {} YES/NO pairs into {} collateral (fee {})",
+        amount,
+        payout,
+        fee
+    );
+
+    emit!(PositionsMerged {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+        fee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
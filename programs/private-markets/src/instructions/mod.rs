@@ -1,26 +1,67 @@
+pub mod accept_admin;
+pub mod add_liquidity;
 pub mod apply_batch_clear;
+pub mod cancel_batch_order;
+pub mod claim_batch_fill;
+pub mod close_batch;
+pub mod crank_batch_clear;
 pub mod create_market;
 pub mod deposit_collateral;
-pub mod mint_outcome_tokens;
+pub mod initialize_config;
+pub mod initialize_reserves;
+pub mod merge_positions;
+pub mod open_batch;
+pub mod open_position;
 pub mod redeem_tokens;
+pub mod remove_liquidity;
 pub mod resolve_market;
+pub mod retry_resolution;
+pub mod split_collateral;
 pub mod stake_resolver;
 pub mod submit_attestation;
 pub mod submit_batch_order;
+pub mod submit_lmsr_trade;
 pub mod submit_private_trade;
+pub mod transfer_admin;
 pub mod update_cfmm_state;
+pub mod update_config;
+pub mod verify_batch_order;
+pub mod withdraw_fees;
 
 // Re-export only handlers (account structs and events are in lib.rs at crate root)
+pub use accept_admin::{handler as accept_admin_handler};
 pub use apply_batch_clear::{handler as apply_batch_clear_handler};
+pub use cancel_batch_order::{handler as cancel_batch_order_handler};
+pub use claim_batch_fill::{handler as claim_batch_fill_handler};
+pub use close_batch::{handler as close_batch_handler};
 pub use create_market::{handler as create_market_handler};
 pub use deposit_collateral::{handler as deposit_collateral_handler};
-pub use mint_outcome_tokens::{handler as mint_outcome_tokens_handler};
+pub use initialize_config::{handler as initialize_config_handler};
+pub use merge_positions::{handler as merge_positions_handler};
+pub use open_batch::{handler as open_batch_handler};
 pub use redeem_tokens::{handler as redeem_tokens_handler};
 pub use resolve_market::{handler as resolve_market_handler};
+pub use split_collateral::{handler as split_collateral_handler};
 pub use stake_resolver::{handler as stake_resolver_handler};
+pub use submit_batch_order::{handler as submit_batch_order_handler};
+pub use transfer_admin::{handler as transfer_admin_handler};
 pub use update_cfmm_state::{handler as update_cfmm_state_handler};
+pub use update_config::{handler as update_config_handler};
+pub use verify_batch_order::{handler as verify_batch_order_handler};
+pub use withdraw_fees::{handler as withdraw_fees_handler};
 
 // Export only handlers for Arcium instructions (structs, callbacks, and events are in lib.rs)
+pub use add_liquidity::{
+    callback_handler as add_liquidity_callback_handler, handler as add_liquidity_handler,
+};
+pub use crank_batch_clear::{handler as crank_batch_clear_handler};
+pub use initialize_reserves::{handler as initialize_reserves_handler};
+pub use open_position::{handler as open_position_handler};
+pub use remove_liquidity::{
+    callback_handler as remove_liquidity_callback_handler,
+    failure_handler as remove_liquidity_failure_handler, handler as remove_liquidity_handler,
+};
+pub use retry_resolution::{handler as retry_resolution_handler};
 pub use submit_attestation::{handler as submit_attestation_handler};
-pub use submit_batch_order::{handler as submit_batch_order_handler};
+pub use submit_lmsr_trade::{handler as submit_lmsr_trade_handler};
 pub use submit_private_trade::{handler as submit_private_trade_handler};
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::MarketError;
+use crate::merkle;
+use crate::state::*;
+use crate::{BatchOpened, OpenBatch}; // Import account struct and event from crate root
+
+pub fn handler(ctx: Context<OpenBatch>, epoch: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < ctx.accounts.market.end_time,
+        MarketError::MarketEnded
+    );
+
+    open_epoch(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.batch,
+        &mut ctx.accounts.order_queue,
+        epoch,
+        ctx.bumps.batch,
+        ctx.bumps.order_queue,
+        clock.unix_timestamp,
+    )
+}
+
+/// Initialize the batch and order queue for `epoch`, which must be the
+/// epoch whose window contains `now`. Windows follow from the market's
+/// `batch_start`, so they keep rolling whether or not earlier batches cleared.
+pub fn open_epoch(
+    market: &mut Account<Market>,
+    batch: &mut Account<BatchState>,
+    order_queue: &mut Account<OrderQueue>,
+    epoch: u64,
+    batch_bump: u8,
+    order_queue_bump: u8,
+    now: i64,
+) -> Result<()> {
+    require!(epoch == market.epoch_at(now), MarketError::WrongEpoch);
+    let (start_time, end_time) = market.epoch_window(epoch)?;
+
+    batch.market = market.key();
+    batch.epoch = epoch;
+    batch.start_time = start_time;
+    batch.end_time = end_time;
+    batch.order_root = merkle::empty_root();
+    batch.order_frontier = [[0; 32]; ORDER_TREE_DEPTH];
+    batch.order_count = 0;
+    batch.state = BatchStateEnum::Open;
+    batch.clearing_price = 0;
+    batch.claimed = 0;
+    batch.bump = batch_bump;
+
+    order_queue.batch = batch.key();
+    order_queue.bump = order_queue_bump;
+
+    market.next_batch_clear = batch.end_time;
+    market.batch_order_root = batch.order_root;
+    market.batch_order_count = 0;
+
+    msg!(
+        "Opened batch {} for market {} until {}",
+        batch.epoch,
+        market.key(),
+        batch.end_time
+    );
+
+    emit!(BatchOpened {
+        market: market.key(),
+        epoch: batch.epoch,
+        start_time: batch.start_time,
+        end_time: batch.end_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::InitPositionCallback;
+use crate::OpenPosition;
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+
+// Handler function - account struct is defined in lib.rs for #[arcium_program] macro
+
+pub fn handler(
+    ctx: Context<OpenPosition>,
+    computation_offset: u64,
+    encryption_pubkey: [u8; 32],
+    nonce: u128,
+) -> Result<()> {
+    let market_key = ctx.accounts.market.key();
+    let payer_key = ctx.accounts.payer.key();
+    let position_key = ctx.accounts.position.key();
+
+    let position = &mut ctx.accounts.position;
+    position.authority = payer_key;
+    position.market = market_key;
+    position.encryption_pubkey = encryption_pubkey;
+    position.initialized = false;
+    position.bump = ctx.bumps.position;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // The empty position is encrypted to the trader's key so only they can
+    // decrypt it; later trades re-encrypt it to the same key
+    let args = vec![
+        Argument::ArcisPubkey(encryption_pubkey),
+        Argument::PlaintextU128(nonce),
+    ];
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![InitPositionCallback::callback_ix(&[CallbackAccount {
+            pubkey: position_key,
+            is_writable: true,
+        }])],
+        1,
+    )?;
+
+    msg!(
+        "Encrypted position queued for initialization for {} in market {}",
+        payer_key,
+        market_key
+    );
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use crate::error::MarketError;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
-use crate::RedeemTokens; // Import account struct from crate root
+use crate::{RedeemTokens, TokensRedeemed}; // Import account struct and event from crate root
 
 pub fn handler(ctx: Context<RedeemTokens>, amount: u64) -> Result<()> {
     let market = &ctx.accounts.market;
@@ -14,7 +14,10 @@ pub fn handler(ctx: Context<RedeemTokens>, amount: u64) -> Result<()> {
         MarketError::MarketNotResolved
     );
 
-    // Burn outcome tokens
+    let outcome_mint_key = ctx.accounts.outcome_mint.key();
+    let payout = market.redemption_payout(&outcome_mint_key, amount)?;
+
+    // Burn outcome tokens (losing tokens are burned for nothing)
     let cpi_accounts_burn = Burn {
         mint: ctx.accounts.outcome_mint.to_account_info(),
         from: ctx.accounts.user_outcome_tokens.to_account_info(),
@@ -24,24 +27,41 @@ pub fn handler(ctx: Context<RedeemTokens>, amount: u64) -> Result<()> {
     let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_burn);
     token::burn(cpi_ctx, amount)?;
 
-    // Transfer collateral to user (simplified - should calculate payout based on winning outcome)
-    let authority_bump = market.authority_bump;
-    let authority_key = market.authority.key();
-    let seeds = &[
-        MARKET_SEED,
-        authority_key.as_ref(),
-        &[authority_bump],
-    ];
-    let signer = &[&seeds[..]];
-
-    let cpi_accounts_transfer = Transfer {
-        from: ctx.accounts.collateral_vault.to_account_info(),
-        to: ctx.accounts.user_collateral_account.to_account_info(),
-        authority: market.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts_transfer, signer);
-    token::transfer(cpi_ctx, amount)?;
+    if payout > 0 {
+        let authority_bump = market.authority_bump;
+        let authority_key = market.authority.key();
+        let market_id_bytes = market.market_id.to_le_bytes();
+        let seeds = &[
+            MARKET_SEED,
+            authority_key.as_ref(),
+            market_id_bytes.as_ref(),
+            &[authority_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts_transfer = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.user_collateral_account.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts_transfer, signer);
+        token::transfer(cpi_ctx, payout)?;
+    }
+
+    msg!(
+        "Redeemed {} outcome tokens for {} collateral",
+        amount,
+        payout
+    );
+
+    emit!(TokensRedeemed {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        outcome_mint: outcome_mint_key,
+        amount,
+        payout,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
-    msg!("Redeemed {} outcome tokens for collateral", amount);
     Ok(())
 }
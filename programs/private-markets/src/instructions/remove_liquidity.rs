@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, MintTo, Transfer};
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+use crate::constants::*;
+use crate::error::MarketError;
+use crate::state::*;
+use crate::{LiquidityRemoved, RemoveLiquidity, RemoveLiquidityCallback}; // Import account structs and event from crate root
+
+pub fn handler(ctx: Context<RemoveLiquidity>, computation_offset: u64, shares: u64) -> Result<()> {
+    require!(shares > 0, MarketError::InsufficientLiquidity);
+
+    let market = &ctx.accounts.market;
+    require!(
+        shares <= market.total_liquidity,
+        MarketError::InsufficientLiquidity
+    );
+    let total_shares = market.total_liquidity;
+    let fee_amount = market.lp_claim(market.lp_fees_accrued, shares)?;
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        from: ctx.accounts.user_lp_tokens.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::burn(CpiContext::new(cpi_program, cpi_accounts), shares)?;
+
+    let args = vec![
+        Argument::PlaintextU128(ctx.accounts.reserves.nonce),
+        Argument::Account(
+            ctx.accounts.reserves.key(),
+            RESERVES_CIPHERTEXT_OFFSET,
+            RESERVES_CIPHERTEXT_LEN,
+        ),
+        Argument::PlaintextU64(shares),
+        Argument::PlaintextU64(total_shares),
+    ];
+
+    let callback_accounts = [
+        market.key(),
+        ctx.accounts.reserves.key(),
+        ctx.accounts.collateral_vault.key(),
+        ctx.accounts.fee_vault.key(),
+        ctx.accounts.yes_mint.key(),
+        ctx.accounts.no_mint.key(),
+        ctx.accounts.lp_mint.key(),
+        ctx.accounts.user_collateral.key(),
+        ctx.accounts.user_yes_tokens.key(),
+        ctx.accounts.user_no_tokens.key(),
+        ctx.accounts.user_lp_tokens.key(),
+    ]
+    .into_iter()
+    .map(|pubkey| CallbackAccount {
+        pubkey,
+        is_writable: true,
+    })
+    .chain(std::iter::once(CallbackAccount {
+        pubkey: ctx.accounts.token_program.key(),
+        is_writable: false,
+    }))
+    .collect::<Vec<_>>();
+
+    // The withdrawn shares and their fee claim leave the books now so later
+    // withdrawals price against the remaining providers; the failure path in
+    // the callback puts both back
+    let market = &mut ctx.accounts.market;
+    market.total_liquidity -= shares;
+    market.lp_fees_accrued -= fee_amount;
+
+    msg!(
+        "Queued removal of {} LP shares from market {}, fees={}",
+        shares,
+        market.key(),
+        fee_amount
+    );
+
+    // Hold the reserves until the callback lands so no other computation
+    // prices against the pool before this withdrawal leaves it
+    ctx.accounts.reserves.begin_computation()?;
+    ctx.accounts.reserves.hold_liquidity(shares, fee_amount);
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![RemoveLiquidityCallback::callback_ix(&callback_accounts)],
+        1,
+    )?;
+
+    Ok(())
+}
+
+/// Apply a completed `remove_liquidity` computation: store the new encrypted
+/// reserves and pay out the provider's share of the pool and the LP fees
+pub fn callback_handler(
+    ctx: Context<RemoveLiquidityCallback>,
+    new_state: MXEEncryptedStruct<2>,
+    yes_amount: u64,
+    no_amount: u64,
+    fee_amount: u64,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let resolved = market.resolution_state == ResolutionState::Resolved;
+
+    // Before resolution the provider receives their share of the reserves as
+    // outcome tokens; afterwards the residual pool is redeemed for collateral
+    let collateral_amount = if resolved {
+        market
+            .redemption_payout(&market.yes_mint, yes_amount)?
+            .checked_add(market.redemption_payout(&market.no_mint, no_amount)?)
+            .ok_or(MarketError::Overflow)?
+    } else {
+        0
+    };
+
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    if fee_amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.fee_vault.to_account_info(),
+            to: ctx.accounts.user_collateral.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, fee_amount)?;
+    }
+
+    if !resolved {
+        if yes_amount > 0 {
+            let cpi_accounts = MintTo {
+                mint: ctx.accounts.yes_mint.to_account_info(),
+                to: ctx.accounts.user_yes_tokens.to_account_info(),
+                authority: market.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+            token::mint_to(cpi_ctx, yes_amount)?;
+        }
+
+        if no_amount > 0 {
+            let cpi_accounts = MintTo {
+                mint: ctx.accounts.no_mint.to_account_info(),
+                to: ctx.accounts.user_no_tokens.to_account_info(),
+                authority: market.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::mint_to(cpi_ctx, no_amount)?;
+        }
+    } else if collateral_amount > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.user_collateral.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, collateral_amount)?;
+    }
+
+    let commitment = ctx
+        .accounts
+        .reserves
+        .set_state(new_state.nonce, new_state.ciphertexts);
+
+    let market = &mut ctx.accounts.market;
+    market.cfmm_state_commitment = commitment;
+
+    msg!(
+        "Removed liquidity: YES={}, NO={}, collateral={}, fees={}",
+        yes_amount,
+        no_amount,
+        collateral_amount,
+        fee_amount
+    );
+
+    emit!(LiquidityRemoved {
+        market: market.key(),
+        provider: ctx.accounts.user_collateral.owner,
+        yes_amount,
+        no_amount,
+        collateral_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Undo a failed `remove_liquidity`: re-mint the burned LP shares and put
+/// them and their fee claim back on the market
+pub fn failure_handler(
+    ctx: Context<RemoveLiquidityCallback>,
+    shares: u64,
+    fee_amount: u64,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if shares > 0 {
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            to: ctx.accounts.user_lp_tokens.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::mint_to(cpi_ctx, shares)?;
+    }
+
+    let market = &mut ctx.accounts.market;
+    market.total_liquidity = market
+        .total_liquidity
+        .checked_add(shares)
+        .ok_or(MarketError::Overflow)?;
+    market.lp_fees_accrued = market
+        .lp_fees_accrued
+        .checked_add(fee_amount)
+        .ok_or(MarketError::Overflow)?;
+
+    msg!("Restored {} LP shares to market {}", shares, market.key());
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::OUTCOME_INVALID;
 use crate::error::MarketError;
 use crate::state::*;
 use crate::{ResolveMarket, MarketResolved}; // Import account struct and event from crate root
@@ -14,6 +15,7 @@ pub fn handler(
         market.resolution_state == ResolutionState::Computing,
         MarketError::MarketNotResolved
     );
+    require!(final_outcome <= OUTCOME_INVALID, MarketError::InvalidOutcome);
 
     // Store resolution result
     market.resolution_state = ResolutionState::Resolved;
@@ -0,0 +1,44 @@
+use crate::error::MarketError;
+use crate::instructions::submit_attestation::resolution_args;
+use crate::state::*;
+use crate::{ResolveMarketCallback, RetryResolution};
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+
+// Handler function - account struct is defined in lib.rs for #[arcium_program] macro
+
+pub fn handler(ctx: Context<RetryResolution>, computation_offset: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    // The votes are already stored, so anyone may queue the tally again after
+    // a failed `resolve_market` returned the market to AwaitingAttestation
+    require!(
+        market.attestation_count >= market.resolver_quorum,
+        MarketError::QuorumNotReached
+    );
+    market.resolution_state = ResolutionState::Computing;
+    let market_key = market.key();
+
+    let args = resolution_args(&ctx.accounts.votes);
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        None,
+        vec![ResolveMarketCallback::callback_ix(&[CallbackAccount {
+            pubkey: market_key,
+            is_writable: true,
+        }])],
+        1,
+    )?;
+
+    msg!(
+        "Market resolution re-queued to Arcium MPC for market {}",
+        market_key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo, Transfer};
+use crate::constants::MARKET_SEED;
+use crate::error::MarketError;
+use crate::{CollateralSplit, SplitCollateral}; // Import account struct and event from crate root
+
+pub fn handler(ctx: Context<SplitCollateral>, amount: u64) -> Result<()> {
+    require!(amount > 0, MarketError::InsufficientCollateral);
+
+    let market = &ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp < market.end_time,
+        MarketError::MarketEnded
+    );
+
+    // Transfer collateral from user to vault
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.user_collateral.to_account_info(),
+        to: ctx.accounts.collateral_vault.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    // Mint one YES and one NO per unit of collateral, signed by the market PDA
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts_yes = MintTo {
+        mint: ctx.accounts.yes_mint.to_account_info(),
+        to: ctx.accounts.user_yes_tokens.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts_yes, signer);
+    token::mint_to(cpi_ctx, amount)?;
+
+    let cpi_accounts_no = MintTo {
+        mint: ctx.accounts.no_mint.to_account_info(),
+        to: ctx.accounts.user_no_tokens.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts_no, signer);
+    token::mint_to(cpi_ctx, amount)?;
+
+    msg!("Split {} collateral into YES and NO tokens", amount);
+
+    emit!(CollateralSplit {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
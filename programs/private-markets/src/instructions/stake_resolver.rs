@@ -27,7 +27,6 @@ pub fn handler(ctx: Context<StakeResolver>, amount: u64) -> Result<()> {
     resolver.stake = amount;
     resolver.has_attested = false;
     resolver.attestation_commitment = [0; 32];
-    resolver.count = 0;
     resolver.bump = ctx.bumps.resolver;
 
     market.resolver_count = market.resolver_count.checked_add(1).unwrap();
@@ -11,6 +11,8 @@ use arcium_client::idl::arcium::types::CallbackAccount; // Import from crate roo
 pub fn handler(
     ctx: Context<SubmitAttestation>,
     computation_offset: u64,
+    encryption_pubkey: [u8; 32],
+    nonce: u128,
     attestation: [u8; 32],
 ) -> Result<()> {
     let clock = Clock::get()?;
@@ -19,10 +21,10 @@ pub fn handler(
     let market_key = ctx.accounts.market.key();
     let resolver_quorum = ctx.accounts.market.resolver_quorum;
     let resolver_key = ctx.accounts.resolver.key();
-    let resolver_count = ctx.accounts.resolver.count;
 
     let market = &mut ctx.accounts.market;
     let resolver = &mut ctx.accounts.resolver;
+    let votes = &mut ctx.accounts.votes;
 
     // Check market has ended
     require!(
@@ -30,12 +32,28 @@ pub fn handler(
         MarketError::MarketNotEnded
     );
 
+    // Each resolver votes once, so quorum needs that many distinct resolvers
+    require!(!resolver.has_attested, MarketError::AlreadyAttested);
+
     // Update market state to awaiting attestation if needed
     if market.resolution_state == ResolutionState::Active {
         market.resolution_state = ResolutionState::AwaitingAttestation;
     }
 
-    let first_attestation = !resolver.has_attested;
+    votes.market = market_key;
+    votes.bump = ctx.bumps.votes;
+
+    let slot = market.attestation_count as usize;
+    require!(slot < MAX_RESOLVERS, MarketError::TooManyResolvers);
+    market.attestation_count += 1;
+    let attestation_count = market.attestation_count;
+    votes.votes[slot] = ResolverVote {
+        resolver: resolver_key,
+        stake: resolver.stake,
+        encryption_pubkey,
+        nonce,
+        ciphertext: attestation,
+    };
 
     // Store attestation commitment
     resolver.attestation_commitment = attestation;
@@ -43,19 +61,10 @@ pub fn handler(
     resolver.attestation_timestamp = clock.unix_timestamp;
 
     // Check if we have enough attestations to trigger resolution
-    if resolver.count + 1 >= resolver_quorum {
-        // Drop mutable borrows before calling queue_computation
-        drop(market);
-        drop(resolver);
-
-        // Queue the resolution computation to Arcium
-        let args = vec![
-            Argument::EncryptedU32(attestation),
-            // Read current market state
-            Argument::Account(market_key, MARKET_YES_RESERVES_OFFSET, 8),
-            Argument::Account(market_key, MARKET_NO_RESERVES_OFFSET, 8),
-        ];
+    if attestation_count >= resolver_quorum {
+        market.resolution_state = ResolutionState::Computing;
 
+        let args = resolution_args(votes);
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
         queue_computation(
@@ -80,9 +89,29 @@ pub fn handler(
         market: market_key,
         resolver: resolver_key,
         timestamp: clock.unix_timestamp,
-        count: resolver_count,
+        count: attestation_count,
         quorum: resolver_quorum,
     });
 
     Ok(())
 }
+
+/// Arguments for `resolve_market` over every stored vote. Every slot is
+/// passed so the circuit has a fixed shape; unused slots have zero stake.
+pub fn resolution_args(votes: &Account<ResolutionVotes>) -> Vec<Argument> {
+    let votes_key = votes.key();
+    let mut args = Vec::with_capacity(MAX_RESOLVERS * 4);
+    for (i, vote) in votes.votes.iter().enumerate() {
+        args.push(Argument::ArcisPubkey(vote.encryption_pubkey));
+        args.push(Argument::PlaintextU128(vote.nonce));
+        args.push(Argument::Account(
+            votes_key,
+            RESOLUTION_VOTES_OFFSET + i as u32 * RESOLVER_VOTE_LEN + RESOLVER_VOTE_CIPHERTEXT_OFFSET,
+            VOTE_CIPHERTEXT_LEN,
+        ));
+    }
+    for vote in votes.votes.iter() {
+        args.push(Argument::PlaintextU64(vote.stake));
+    }
+    args
+}
@@ -1,69 +1,96 @@
 use crate::constants::*;
 use crate::error::MarketError;
+use crate::instructions::open_batch::open_epoch;
 use crate::state::*;
-use crate::{BatchClearCallback, SubmitBatchOrder};
+use crate::{BatchOrderQueued, SubmitBatchOrder}; // Import account struct and event from crate root
 use anchor_lang::prelude::*;
-use arcium_anchor::prelude::*;
-use arcium_client::idl::arcium::types::CallbackAccount; // Import from crate root
+use anchor_spl::token::{self, Transfer};
 
-// Handler function - account struct is defined in lib.rs for #[arcium_program] macro
+pub fn handler(ctx: Context<SubmitBatchOrder>, epoch: u64, order: BatchOrderData) -> Result<()> {
+    require!(order.amount > 0, MarketError::InsufficientCollateral);
 
-pub fn handler(
-    ctx: Context<SubmitBatchOrder>,
-    computation_offset: u64,
-    batch_orders: Vec<BatchOrderData>,
-) -> Result<()> {
     let clock = Clock::get()?;
 
-    // Get key before mutable borrow
-    let market_key = ctx.accounts.market.key();
-
-    let market = &mut ctx.accounts.market;
-
     // Check market hasn't ended
     require!(
-        clock.unix_timestamp < market.end_time,
+        clock.unix_timestamp < ctx.accounts.market.end_time,
         MarketError::MarketEnded
     );
 
-    // Check we're within a batch window
+    // Orders only go into the epoch whose window contains now. An order
+    // signed for a window that has since ended is rejected rather than
+    // moved; the client re-reads the epoch (`getEpochAt` in the SDK) and
+    // resubmits.
     require!(
-        clock.unix_timestamp < market.next_batch_clear,
-        MarketError::BatchWindowOpen
+        epoch == ctx.accounts.market.epoch_at(clock.unix_timestamp),
+        MarketError::WrongEpoch
     );
 
-    // Increment batch order count
-    market.batch_order_count = market
-        .batch_order_count
-        .checked_add(batch_orders.len() as u32)
-        .ok_or(MarketError::Overflow)?;
+    // The first order of an epoch opens its batch
+    if ctx.accounts.batch.market == Pubkey::default() {
+        open_epoch(
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.batch,
+            &mut ctx.accounts.order_queue,
+            epoch,
+            ctx.bumps.batch,
+            ctx.bumps.order_queue,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    let market = &mut ctx.accounts.market;
+    let batch = &mut ctx.accounts.batch;
 
-    // Drop mutable borrow before calling queue_computation
-    drop(market);
+    // Check we're within the batch's window
+    require!(
+        clock.unix_timestamp < batch.end_time,
+        MarketError::BatchNotOpen
+    );
+
+    let slot = batch.order_count as usize;
+    require!(slot < MAX_BATCH_ORDERS, MarketError::BatchFull);
 
-    // Build arguments for batch clearing computation
-    let args = vec![
-        // Read current CFMM state
-        Argument::Account(market_key, MARKET_YES_RESERVES_OFFSET, 8),
-        Argument::Account(market_key, MARKET_NO_RESERVES_OFFSET, 8),
-    ];
+    // Escrow the order together with the most it can owe in fees. The fee
+    // is only charged on the filled spend when the fill is claimed; the
+    // rest comes back with the refund.
+    let max_fee = market.trading_fee(order.amount)?;
+    let escrow = order
+        .amount
+        .checked_add(max_fee)
+        .ok_or(MarketError::Overflow)?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.payer_collateral.to_account_info(),
+        to: ctx.accounts.collateral_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), escrow)?;
+
+    ctx.accounts.order_queue.orders[slot] = QueuedOrder {
+        owner: ctx.accounts.payer.key(),
+        order,
+    };
 
-    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+    // Commit to the order so the trader can later prove it was in the batch
+    market.batch_order_root = batch.append_order(&order.commitment);
+    batch.order_count += 1;
+    market.batch_order_count = batch.order_count;
 
-    // Queue batch clear computation
-    queue_computation(
-        ctx.accounts,
-        computation_offset,
-        args,
-        None,
-        vec![BatchClearCallback::callback_ix(&[CallbackAccount {
-            pubkey: market_key,
-            is_writable: true,
-        }])],
-        1,
-    )?;
+    msg!(
+        "Batch order {} queued for market {} epoch {}",
+        slot,
+        market.key(),
+        batch.epoch
+    );
 
-    msg!("Batch orders queued for market {}", market_key);
+    emit!(BatchOrderQueued {
+        market: market.key(),
+        epoch: batch.epoch,
+        owner: ctx.accounts.payer.key(),
+        commitment: order.commitment,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -3,11 +3,13 @@ use crate::error::MarketError;
 use crate::state::*;
 use crate::{PrivateTradeCallback, PrivateTradeQueued, SubmitPrivateTrade};
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
 use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::CallbackAccount; // Import from crate root
 
 // Handler function - account struct is defined in lib.rs for #[arcium_program] macro
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<SubmitPrivateTrade>,
     computation_offset: u64,
@@ -16,6 +18,7 @@ pub fn handler(
     ciphertext_max_price: [u8; 32],
     nonce: u128,
     client_pubkey: [u8; 32],
+    escrow_amount: u64,
 ) -> Result<()> {
     let market = &ctx.accounts.market;
     let clock = Clock::get()?;
@@ -25,6 +28,17 @@ pub fn handler(
         clock.unix_timestamp < market.end_time,
         MarketError::MarketEnded
     );
+    require!(escrow_amount > 0, MarketError::InsufficientCollateral);
+
+    // Escrow collateral up front; the callback spends at most this much and
+    // refunds the remainder
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.user_collateral.to_account_info(),
+        to: ctx.accounts.collateral_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), escrow_amount)?;
 
     // Build arguments for the encrypted instruction
     // Following blackjack pattern: use Argument::Account() to read on-chain data
@@ -34,15 +48,28 @@ pub fn handler(
         Argument::EncryptedU64(ciphertext_amount),
         Argument::EncryptedBool(ciphertext_side),
         Argument::EncryptedU64(ciphertext_max_price),
-        Argument::Account(ctx.accounts.market.key(), MARKET_YES_RESERVES_OFFSET, 8),
-        Argument::Account(ctx.accounts.market.key(), MARKET_NO_RESERVES_OFFSET, 8),
+        Argument::PlaintextU128(ctx.accounts.reserves.nonce),
+        Argument::Account(
+            ctx.accounts.reserves.key(),
+            RESERVES_CIPHERTEXT_OFFSET,
+            RESERVES_CIPHERTEXT_LEN,
+        ),
+        Argument::ArcisPubkey(ctx.accounts.position.encryption_pubkey),
+        Argument::PlaintextU128(ctx.accounts.position.nonce),
         Argument::Account(
-            ctx.accounts.market.key(),
-            MARKET_CFMM_COMMITMENT_OFFSET,
-            32,
+            ctx.accounts.position.key(),
+            POSITION_CIPHERTEXT_OFFSET,
+            POSITION_CIPHERTEXT_LEN,
         ),
+        Argument::PlaintextU64(escrow_amount),
+        Argument::PlaintextU64(market.fee_bps as u64),
     ];
 
+    // Hold the pool and position until the callback lands so no other
+    // computation works from the same ciphertexts
+    ctx.accounts.reserves.begin_computation()?;
+    ctx.accounts.position.begin_computation(escrow_amount)?;
+
     // Set the sign PDA bump (required by Arcium)
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
@@ -57,10 +84,52 @@ pub fn handler(
         computation_offset,
         args,
         None, // No additional callback accounts needed
-        vec![PrivateTradeCallback::callback_ix(&[CallbackAccount {
-            pubkey: ctx.accounts.market.key(),
-            is_writable: true,
-        }])], // Callback instruction with market account
+        vec![PrivateTradeCallback::callback_ix(&[
+            CallbackAccount {
+                pubkey: ctx.accounts.market.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.reserves.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.position.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.collateral_vault.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.fee_vault.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.yes_mint.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.no_mint.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.user_collateral.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.user_yes_tokens.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.user_no_tokens.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.token_program.key(),
+                is_writable: false,
+            },
+        ])], // Callback instruction with the accounts the fill settles against
         1,
     )?;
 
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use crate::TransferAdmin; // Import account struct from crate root
+
+pub fn handler(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    // Nominate only; the new admin must accept before control moves
+    config.pending_admin = Some(new_admin);
+
+    msg!("Admin transfer to {} pending acceptance", new_admin);
+    Ok(())
+}
@@ -1,14 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, MintTo, Token};
 use crate::error::MarketError;
 use crate::state::*;
 use crate::UpdateCfmmState; // Import account struct from crate root
 
 pub fn handler(
     ctx: Context<UpdateCfmmState>,
-    new_state_commitment: [u8; 32],
-    reserve_delta_yes: i64,
-    reserve_delta_no: i64,
+    new_nonce: u128,
+    new_encrypted_reserves: [[u8; 32]; 2],
 ) -> Result<()> {
     let market = &mut ctx.accounts.market;
 
@@ -17,28 +15,13 @@ pub fn handler(
         MarketError::MarketAlreadyResolved
     );
 
-    // Update CFMM state commitment
-    market.cfmm_state_commitment = new_state_commitment;
+    // Replace the MXE-encrypted reserves and recommit to them
+    market.cfmm_state_commitment = ctx
+        .accounts
+        .reserves
+        .set_state(new_nonce, new_encrypted_reserves);
 
-    // Update reserves (add/subtract deltas)
-    if reserve_delta_yes >= 0 {
-        market.yes_reserves = market.yes_reserves.checked_add(reserve_delta_yes as u64).unwrap();
-    } else {
-        market.yes_reserves = market.yes_reserves.checked_sub((-reserve_delta_yes) as u64).unwrap();
-    }
-
-    if reserve_delta_no >= 0 {
-        market.no_reserves = market.no_reserves.checked_add(reserve_delta_no as u64).unwrap();
-    } else {
-        market.no_reserves = market.no_reserves.checked_sub((-reserve_delta_no) as u64).unwrap();
-    }
-
-    msg!(
-        "CFMM state updated for market {}. New reserves: YES={}, NO={}",
-        market.key(),
-        market.yes_reserves,
-        market.no_reserves
-    );
+    msg!("CFMM state updated for market {}", market.key());
 
     Ok(())
 }
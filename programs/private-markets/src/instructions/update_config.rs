@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::instructions::initialize_config::validate_config_params;
+use crate::{ConfigUpdated, UpdateConfig}; // Import account struct and event from crate root
+
+pub fn handler(
+    ctx: Context<UpdateConfig>,
+    treasury: Pubkey,
+    mpc_authority: Pubkey,
+    protocol_fee_bps: u16,
+    allowed_collateral_mints: Vec<Pubkey>,
+    paused: bool,
+) -> Result<()> {
+    validate_config_params(protocol_fee_bps, &allowed_collateral_mints)?;
+
+    let config = &mut ctx.accounts.config;
+    config.treasury = treasury;
+    config.mpc_authority = mpc_authority;
+    config.protocol_fee_bps = protocol_fee_bps;
+    config.allowed_collateral_mints = allowed_collateral_mints;
+    config.paused = paused;
+
+    msg!("Protocol config updated (paused: {})", paused);
+
+    emit!(ConfigUpdated {
+        treasury,
+        mpc_authority,
+        protocol_fee_bps,
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::MarketError;
+use crate::merkle;
+use crate::VerifyBatchOrder; // Import account struct from crate root
+
+pub fn handler(
+    ctx: Context<VerifyBatchOrder>,
+    commitment: [u8; 32],
+    index: u32,
+    proof: [[u8; 32]; ORDER_TREE_DEPTH],
+) -> Result<()> {
+    let batch = &ctx.accounts.batch;
+
+    require!(index < batch.order_count, MarketError::InvalidOrderProof);
+    require!(
+        merkle::verify_proof(&batch.order_root, &commitment, index, &proof),
+        MarketError::InvalidOrderProof
+    );
+
+    msg!(
+        "Order {} is included in batch {} for market {}",
+        index,
+        batch.epoch,
+        batch.market
+    );
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::constants::MARKET_SEED;
+use crate::error::MarketError;
+use crate::{FeesWithdrawn, WithdrawFees}; // Import account struct and event from crate root
+
+pub fn handler(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    require!(amount > 0, MarketError::InsufficientFees);
+    require!(
+        amount <= ctx.accounts.market.fees_accrued,
+        MarketError::InsufficientFees
+    );
+
+    // Split the withdrawal between the protocol treasury and the market authority
+    let protocol_share = ctx.accounts.config.protocol_share(amount)?;
+    let creator_share = amount - protocol_share;
+
+    let market = &ctx.accounts.market;
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    if creator_share > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.fee_vault.to_account_info(),
+            to: ctx.accounts.recipient.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, creator_share)?;
+    }
+
+    if protocol_share > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.fee_vault.to_account_info(),
+            to: ctx.accounts.treasury_token_account.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, protocol_share)?;
+    }
+
+    let market = &mut ctx.accounts.market;
+    market.fees_accrued -= amount;
+
+    msg!(
+        "Withdrew {} fees from market {} ({} to treasury)",
+        amount,
+        market.key(),
+        protocol_share
+    );
+
+    emit!(FeesWithdrawn {
+        market: market.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        protocol_share,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
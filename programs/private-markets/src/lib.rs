@@ -1,16 +1,23 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
 use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::CallbackAccount;
 
 pub mod constants;
+pub mod cpmm;
 pub mod error;
 pub mod instructions;
+pub mod lmsr;
+pub mod merkle;
 pub mod state;
 
 // Import error and state types for use in account structs
 use error::MarketError;
-use state::{BatchOrderData, Market, ResolutionState, Resolver};
+use state::{
+    BatchFill, BatchOrderData, BatchState, BatchStateEnum, EncryptedReserves, Market,
+    OrderQueue, PricingCurve, ProtocolConfig, ResolutionState, ResolutionVotes, Resolver,
+    UserPosition,
+};
 
 // Import and re-export all instruction handlers
 use instructions::*;
@@ -23,6 +30,11 @@ use constants::*;
 const COMP_DEF_OFFSET_PRIVATE_TRADE: u32 = comp_def_offset("private_trade");
 const COMP_DEF_OFFSET_BATCH_CLEAR: u32 = comp_def_offset("batch_clear");
 const COMP_DEF_OFFSET_RESOLVE_MARKET: u32 = comp_def_offset("resolve_market");
+const COMP_DEF_OFFSET_LMSR_TRADE: u32 = comp_def_offset("lmsr_trade");
+const COMP_DEF_OFFSET_INIT_CFMM_STATE: u32 = comp_def_offset("init_cfmm_state");
+const COMP_DEF_OFFSET_ADD_LIQUIDITY: u32 = comp_def_offset("add_liquidity");
+const COMP_DEF_OFFSET_REMOVE_LIQUIDITY: u32 = comp_def_offset("remove_liquidity");
+const COMP_DEF_OFFSET_INIT_POSITION: u32 = comp_def_offset("init_position");
 
 declare_id!("FxUZ9r65C8RJDSuHSmiryVWUx9ffeWAX9392iuHCxKr7");
 
@@ -96,15 +108,68 @@ pub struct SubmitPrivateTrade<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
         mut,
-        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
+        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved,
+        constraint = market.pricing_curve == PricingCurve::Cpmm @ MarketError::WrongPricingCurve
     )]
     pub market: Account<'info, Market>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ MarketError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+    #[account(
+        mut,
+        seeds = [RESERVES_SEED, market.key().as_ref()],
+        bump = reserves.bump,
+        constraint = reserves.initialized @ MarketError::ReservesNotInitialized
+    )]
+    pub reserves: Account<'info, EncryptedReserves>,
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, market.key().as_ref(), payer.key().as_ref()],
+        bump = position.bump,
+        constraint = position.initialized @ MarketError::PositionNotInitialized
+    )]
+    pub position: Account<'info, UserPosition>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = user_collateral.owner == payer.key() @ MarketError::Unauthorized,
+        constraint = user_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_yes_tokens.mint == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub user_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_no_tokens.mint == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub user_no_tokens: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
 }
 
-#[queue_computation_accounts("batch_clear", payer)]
+#[queue_computation_accounts("lmsr_trade", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64)]
-pub struct SubmitBatchOrder<'info> {
+pub struct SubmitLmsrTrade<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -139,7 +204,7 @@ pub struct SubmitBatchOrder<'info> {
     /// CHECK: computation_account, checked by the arcium program
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_BATCH_CLEAR)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_LMSR_TRADE)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -160,15 +225,68 @@ pub struct SubmitBatchOrder<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
         mut,
-        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
+        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved,
+        constraint = market.pricing_curve == PricingCurve::Lmsr @ MarketError::WrongPricingCurve
     )]
     pub market: Account<'info, Market>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ MarketError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+    #[account(
+        mut,
+        seeds = [RESERVES_SEED, market.key().as_ref()],
+        bump = reserves.bump,
+        constraint = reserves.initialized @ MarketError::ReservesNotInitialized
+    )]
+    pub reserves: Account<'info, EncryptedReserves>,
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, market.key().as_ref(), payer.key().as_ref()],
+        bump = position.bump,
+        constraint = position.initialized @ MarketError::PositionNotInitialized
+    )]
+    pub position: Account<'info, UserPosition>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = user_collateral.owner == payer.key() @ MarketError::Unauthorized,
+        constraint = user_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_yes_tokens.mint == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub user_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_no_tokens.mint == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub user_no_tokens: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
 }
 
-#[queue_computation_accounts("resolve_market", payer)]
+#[queue_computation_accounts("batch_clear", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64)]
-pub struct SubmitAttestation<'info> {
+pub struct CrankBatchClear<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -203,7 +321,7 @@ pub struct SubmitAttestation<'info> {
     /// CHECK: computation_account, checked by the arcium program
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_MARKET)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_BATCH_CLEAR)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -222,106 +340,1060 @@ pub struct SubmitAttestation<'info> {
     pub clock_account: Account<'info, ClockAccount>,
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
+    // Orders can only be submitted before the market ends, so a closed batch
+    // may still be cleared once resolution has started; otherwise its
+    // escrows would have no way out
+    #[account(mut)]
+    pub market: Account<'info, Market>,
     #[account(
         mut,
-        constraint = matches!(
-            market.resolution_state,
-            ResolutionState::Active | ResolutionState::AwaitingAttestation
-        ) @ MarketError::MarketAlreadyResolved
+        seeds = [RESERVES_SEED, market.key().as_ref()],
+        bump = reserves.bump,
+        constraint = reserves.initialized @ MarketError::ReservesNotInitialized
     )]
-    pub market: Box<Account<'info, Market>>,
+    pub reserves: Account<'info, EncryptedReserves>,
     #[account(
         mut,
-        seeds = [RESOLVER_SEED, market.key().as_ref(), authority.key().as_ref()],
-        bump = resolver.bump,
-        constraint = resolver.market == market.key() @ MarketError::Unauthorized,
+        seeds = [BATCH_SEED, market.key().as_ref(), &batch.epoch.to_le_bytes()],
+        bump = batch.bump,
+        constraint = batch.state == BatchStateEnum::Closed @ MarketError::BatchNotClosed
     )]
-    pub resolver: Box<Account<'info, Resolver>>,
-    pub authority: Signer<'info>,
-}
-
-// Callback structs must also be at crate root
-#[callback_accounts("private_trade")]
-#[derive(Accounts)]
-pub struct PrivateTradeCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+    pub batch: Box<Account<'info, BatchState>>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_PRIVATE_TRADE)
+        seeds = [ORDER_QUEUE_SEED, batch.key().as_ref()],
+        bump = order_queue.bump
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-    #[account(mut)]
-    pub market: Account<'info, Market>,
+    pub order_queue: Box<Account<'info, OrderQueue>>,
 }
 
-#[callback_accounts("batch_clear")]
+#[queue_computation_accounts("init_cfmm_state", payer)]
 #[derive(Accounts)]
-pub struct BatchClearCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+#[instruction(computation_offset: u64)]
+pub struct InitializeReserves<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_BATCH_CLEAR)
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_CFMM_STATE)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
     pub market: Account<'info, Market>,
+    #[account(
+        init,
+        payer = payer,
+        space = EncryptedReserves::LEN,
+        seeds = [RESERVES_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub reserves: Account<'info, EncryptedReserves>,
 }
 
-#[callback_accounts("resolve_market")]
+#[queue_computation_accounts("init_position", payer)]
 #[derive(Accounts)]
-pub struct ResolveMarketCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+#[instruction(computation_offset: u64)]
+pub struct OpenPosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_MARKET)
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_POSITION)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
     pub market: Account<'info, Market>,
-}
-
-// Non-Arcium account structs (regular Anchor instructions)
-#[derive(Accounts)]
-pub struct CreateMarket<'info> {
     #[account(
         init,
-        payer = authority,
-        space = Market::LEN,
-        seeds = [MARKET_SEED, authority.key().as_ref()],
+        payer = payer,
+        space = UserPosition::LEN,
+        seeds = [POSITION_SEED, market.key().as_ref(), payer.key().as_ref()],
         bump
     )]
-    pub market: Box<Account<'info, Market>>,
-    /// CHECK: created via CPI; verified against PDA inside handler
-    #[account(mut)]
-    pub collateral_vault: UncheckedAccount<'info>,
-    /// CHECK: created via CPI; verified against PDA inside handler
-    #[account(mut)]
-    pub fee_vault: UncheckedAccount<'info>,
-    /// CHECK: created via CPI; verified against PDA inside handler
-    #[account(mut)]
-    pub yes_mint: UncheckedAccount<'info>,
-    /// CHECK: created via CPI; verified against PDA inside handler
-    #[account(mut)]
-    pub no_mint: UncheckedAccount<'info>,
-    /// CHECK: verified by token program during CPI
-    pub collateral_mint: AccountInfo<'info>,
+    pub position: Account<'info, UserPosition>,
+}
+
+#[queue_computation_accounts("add_liquidity", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct AddLiquidity<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub rent: Sysvar<'info, Rent>,
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_LIQUIDITY)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved,
+        constraint = market.pricing_curve == PricingCurve::Cpmm @ MarketError::WrongPricingCurve
+    )]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ MarketError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+    #[account(
+        mut,
+        seeds = [RESERVES_SEED, market.key().as_ref()],
+        bump = reserves.bump,
+        constraint = reserves.initialized @ MarketError::ReservesNotInitialized
+    )]
+    pub reserves: Box<Account<'info, EncryptedReserves>>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(
+        constraint = lp_mint.key() == market.lp_mint @ MarketError::Unauthorized
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = user_collateral.owner == payer.key() @ MarketError::Unauthorized,
+        constraint = user_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_yes_tokens.mint == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub user_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_no_tokens.mint == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub user_no_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_lp_tokens.mint == market.lp_mint @ MarketError::Unauthorized
+    )]
+    pub user_lp_tokens: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[queue_computation_accounts("remove_liquidity", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REMOVE_LIQUIDITY)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        seeds = [RESERVES_SEED, market.key().as_ref()],
+        bump = reserves.bump,
+        constraint = reserves.initialized @ MarketError::ReservesNotInitialized
+    )]
+    pub reserves: Box<Account<'info, EncryptedReserves>>,
+    #[account(
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = lp_mint.key() == market.lp_mint @ MarketError::Unauthorized
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = user_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_yes_tokens.mint == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub user_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = user_no_tokens.mint == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub user_no_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = user_lp_tokens.mint == market.lp_mint @ MarketError::Unauthorized,
+        constraint = user_lp_tokens.owner == payer.key() @ MarketError::Unauthorized
+    )]
+    pub user_lp_tokens: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[queue_computation_accounts("resolve_market", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SubmitAttestation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_MARKET)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = matches!(
+            market.resolution_state,
+            ResolutionState::Active | ResolutionState::AwaitingAttestation
+        ) @ MarketError::MarketAlreadyResolved
+    )]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        seeds = [RESOLVER_SEED, market.key().as_ref(), authority.key().as_ref()],
+        bump = resolver.bump,
+        constraint = resolver.market == market.key() @ MarketError::Unauthorized,
+    )]
+    pub resolver: Box<Account<'info, Resolver>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ResolutionVotes::LEN,
+        seeds = [VOTES_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub votes: Box<Account<'info, ResolutionVotes>>,
+    pub authority: Signer<'info>,
+}
+
+#[queue_computation_accounts("resolve_market", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RetryResolution<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_MARKET)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = market.resolution_state == ResolutionState::AwaitingAttestation @ MarketError::InvalidResolutionState
+    )]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        seeds = [VOTES_SEED, market.key().as_ref()],
+        bump = votes.bump,
+        constraint = votes.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub votes: Box<Account<'info, ResolutionVotes>>,
+}
+
+// Callback structs must also be at crate root
+#[callback_accounts("private_trade")]
+#[derive(Accounts)]
+pub struct PrivateTradeCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_PRIVATE_TRADE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        constraint = reserves.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub reserves: Account<'info, EncryptedReserves>,
+    #[account(
+        mut,
+        constraint = position.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub position: Account<'info, UserPosition>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_no_tokens: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[callback_accounts("lmsr_trade")]
+#[derive(Accounts)]
+pub struct LmsrTradeCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_LMSR_TRADE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        constraint = reserves.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub reserves: Account<'info, EncryptedReserves>,
+    #[account(
+        mut,
+        constraint = position.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub position: Account<'info, UserPosition>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_no_tokens: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[callback_accounts("init_cfmm_state")]
+#[derive(Accounts)]
+pub struct InitCfmmStateCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_CFMM_STATE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        constraint = reserves.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub reserves: Account<'info, EncryptedReserves>,
+}
+
+#[callback_accounts("init_position")]
+#[derive(Accounts)]
+pub struct InitPositionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_POSITION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub position: Account<'info, UserPosition>,
+}
+
+#[callback_accounts("add_liquidity")]
+#[derive(Accounts)]
+pub struct AddLiquidityCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_LIQUIDITY)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        constraint = reserves.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub reserves: Box<Account<'info, EncryptedReserves>>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = lp_mint.key() == market.lp_mint @ MarketError::Unauthorized
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_no_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_lp_tokens: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[callback_accounts("remove_liquidity")]
+#[derive(Accounts)]
+pub struct RemoveLiquidityCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REMOVE_LIQUIDITY)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        constraint = reserves.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub reserves: Box<Account<'info, EncryptedReserves>>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = lp_mint.key() == market.lp_mint @ MarketError::Unauthorized
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_no_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub user_lp_tokens: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[callback_accounts("batch_clear")]
+#[derive(Accounts)]
+pub struct BatchClearCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_BATCH_CLEAR)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        constraint = reserves.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub reserves: Account<'info, EncryptedReserves>,
+    #[account(
+        mut,
+        constraint = batch.market == market.key() @ MarketError::Unauthorized
+    )]
+    pub batch: Account<'info, BatchState>,
+    #[account(
+        mut,
+        constraint = order_queue.batch == batch.key() @ MarketError::Unauthorized
+    )]
+    pub order_queue: Box<Account<'info, OrderQueue>>,
+}
+
+#[callback_accounts("resolve_market")]
+#[derive(Accounts)]
+pub struct ResolveMarketCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_MARKET)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::LEN,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    /// Only the program's upgrade authority may create the config, so it
+    /// cannot be front-run after deployment
+    #[account(
+        mut,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ MarketError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ MarketError::Unauthorized
+    )]
+    pub program: Program<'info, crate::program::PrivateMarkets>,
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ MarketError::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ MarketError::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.pending_admin == Some(new_admin.key()) @ MarketError::Unauthorized,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub new_admin: Signer<'info>,
+}
+
+// Non-Arcium account structs (regular Anchor instructions)
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct CreateMarket<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Market::LEN,
+        seeds = [MARKET_SEED, authority.key().as_ref(), &market_id.to_le_bytes()],
+        bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump,
+        token::mint = collateral_mint,
+        token::authority = market,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [FEE_VAULT_SEED, market.key().as_ref()],
+        bump,
+        token::mint = collateral_mint,
+        token::authority = market,
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [YES_MINT_SEED, market.key().as_ref()],
+        bump,
+        mint::decimals = collateral_mint.decimals,
+        mint::authority = market,
+    )]
+    pub yes_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [NO_MINT_SEED, market.key().as_ref()],
+        bump,
+        mint::decimals = collateral_mint.decimals,
+        mint::authority = market,
+    )]
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [LP_MINT_SEED, market.key().as_ref()],
+        bump,
+        mint::decimals = collateral_mint.decimals,
+        mint::authority = market,
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(
+        constraint = config.allowed_collateral_mints.contains(&collateral_mint.key())
+            @ MarketError::CollateralMintNotAllowed
+    )]
+    pub collateral_mint: Box<Account<'info, Mint>>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ MarketError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// Funds the LMSR subsidy; only needed for LMSR markets
+    #[account(
+        mut,
+        constraint = creator_collateral.owner == authority.key() @ MarketError::Unauthorized,
+        constraint = creator_collateral.mint == collateral_mint.key() @ MarketError::Unauthorized
+    )]
+    pub creator_collateral: Option<Box<Account<'info, TokenAccount>>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct DepositCollateral<'info> {
+    #[account(
+        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
+    )]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = user_collateral.owner == user.key() @ MarketError::Unauthorized
+    )]
+    pub user_collateral: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SplitCollateral<'info> {
+    #[account(
+        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
+    )]
+    pub market: Account<'info, Market>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ MarketError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = user_collateral.owner == user.key() @ MarketError::Unauthorized,
+        constraint = user_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
+    pub user_collateral: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = user_yes_tokens.mint == yes_mint.key() @ MarketError::Unauthorized
+    )]
+    pub user_yes_tokens: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = user_no_tokens.mint == no_mint.key() @ MarketError::Unauthorized
+    )]
+    pub user_no_tokens: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct DepositCollateral<'info> {
+pub struct MergePositions<'info> {
     #[account(
-        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
+        mut,
+        constraint = matches!(
+            market.resolution_state,
+            ResolutionState::Active | ResolutionState::AwaitingAttestation
+        ) @ MarketError::MarketAlreadyResolved
     )]
     pub market: Account<'info, Market>,
     #[account(
@@ -331,32 +1403,68 @@ pub struct DepositCollateral<'info> {
     pub collateral_vault: Account<'info, TokenAccount>,
     #[account(
         mut,
-        constraint = user_collateral.owner == user.key() @ MarketError::Unauthorized
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub yes_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub no_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = user_collateral.owner == user.key() @ MarketError::Unauthorized,
+        constraint = user_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
     )]
     pub user_collateral: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = user_yes_tokens.mint == yes_mint.key() @ MarketError::Unauthorized,
+        constraint = user_yes_tokens.owner == user.key() @ MarketError::Unauthorized
+    )]
+    pub user_yes_tokens: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = user_no_tokens.mint == no_mint.key() @ MarketError::Unauthorized,
+        constraint = user_no_tokens.owner == user.key() @ MarketError::Unauthorized
+    )]
+    pub user_no_tokens: Account<'info, TokenAccount>,
     #[account(mut)]
     pub user: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct MintOutcomeTokens<'info> {
+pub struct WithdrawFees<'info> {
     #[account(
         mut,
         has_one = authority @ MarketError::Unauthorized,
+        has_one = fee_vault @ MarketError::Unauthorized,
     )]
     pub market: Account<'info, Market>,
+    #[account(mut)]
+    pub fee_vault: Account<'info, TokenAccount>,
     #[account(
         mut,
-        constraint = outcome_mint.key() == market.yes_mint || outcome_mint.key() == market.no_mint
-            @ MarketError::Unauthorized,
+        constraint = recipient.mint == market.collateral_mint @ MarketError::Unauthorized
     )]
-    pub outcome_mint: Account<'info, Mint>,
+    pub recipient: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
     #[account(
         mut,
-        constraint = recipient_token_account.mint == outcome_mint.key() @ MarketError::Unauthorized,
+        constraint = treasury_token_account.owner == config.treasury @ MarketError::Unauthorized,
+        constraint = treasury_token_account.mint == market.collateral_mint @ MarketError::Unauthorized
     )]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub treasury_token_account: Account<'info, TokenAccount>,
     pub authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
@@ -384,7 +1492,10 @@ pub struct RedeemTokens<'info> {
         constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
     )]
     pub collateral_vault: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = user_collateral_account.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
     pub user_collateral_account: Account<'info, TokenAccount>,
     #[account(mut)]
     pub user: Signer<'info>,
@@ -393,13 +1504,21 @@ pub struct RedeemTokens<'info> {
 
 #[derive(Accounts)]
 pub struct ResolveMarket<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    /// Audited MPC result authority configured in `ProtocolConfig`
+    #[account(
+        constraint = arcium_authority.key() == config.mpc_authority @ MarketError::Unauthorized
+    )]
+    pub arcium_authority: Signer<'info>,
     #[account(
         mut,
         constraint = market.resolution_state == ResolutionState::Computing @ MarketError::MarketNotResolved
     )]
     pub market: Account<'info, Market>,
-    /// Arcium MPC authority (in production, verify signature)
-    pub arcium_authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -433,37 +1552,242 @@ pub struct StakeResolver<'info> {
 
 #[derive(Accounts)]
 pub struct ApplyBatchClear<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    /// Audited MPC result authority configured in `ProtocolConfig`
+    #[account(
+        constraint = arcium_authority.key() == config.mpc_authority @ MarketError::Unauthorized
+    )]
+    pub arcium_authority: Signer<'info>,
     #[account(
         mut,
         constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
     )]
     pub market: Account<'info, Market>,
-    /// Arcium MPC authority (in production, verify signature)
-    pub arcium_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BATCH_SEED, market.key().as_ref(), &batch.epoch.to_le_bytes()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, BatchState>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateCfmmState<'info> {
+#[instruction(epoch: u64)]
+pub struct SubmitBatchOrder<'info> {
     #[account(
         mut,
         constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
     )]
     pub market: Account<'info, Market>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ MarketError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+    /// Opened by the first order of the epoch
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BatchState::LEN,
+        seeds = [BATCH_SEED, market.key().as_ref(), &epoch.to_le_bytes()],
+        bump,
+        constraint = batch.state == BatchStateEnum::Open @ MarketError::BatchNotOpen
+    )]
+    pub batch: Account<'info, BatchState>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OrderQueue::LEN,
+        seeds = [ORDER_QUEUE_SEED, batch.key().as_ref()],
+        bump
+    )]
+    pub order_queue: Box<Account<'info, OrderQueue>>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = payer_collateral.owner == payer.key() @ MarketError::Unauthorized,
+        constraint = payer_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
+    pub payer_collateral: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBatchOrder<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        seeds = [BATCH_SEED, market.key().as_ref(), &batch.epoch.to_le_bytes()],
+        bump = batch.bump,
+        constraint = batch.state == BatchStateEnum::Open @ MarketError::BatchNotOpen
+    )]
+    pub batch: Account<'info, BatchState>,
+    #[account(
+        mut,
+        seeds = [ORDER_QUEUE_SEED, batch.key().as_ref()],
+        bump = order_queue.bump
+    )]
+    pub order_queue: Box<Account<'info, OrderQueue>>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = owner_collateral.owner == owner.key() @ MarketError::Unauthorized,
+        constraint = owner_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
+    pub owner_collateral: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimBatchFill<'info> {
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        seeds = [BATCH_SEED, market.key().as_ref(), &batch.epoch.to_le_bytes()],
+        bump = batch.bump,
+        constraint = batch.state == BatchStateEnum::Cleared @ MarketError::BatchNotCleared
+    )]
+    pub batch: Box<Account<'info, BatchState>>,
+    #[account(
+        seeds = [ORDER_QUEUE_SEED, batch.key().as_ref()],
+        bump = order_queue.bump
+    )]
+    pub order_queue: Box<Account<'info, OrderQueue>>,
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == market.collateral_vault @ MarketError::Unauthorized
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = fee_vault.key() == market.fee_vault @ MarketError::Unauthorized
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
     #[account(
         mut,
         constraint = yes_mint.key() == market.yes_mint @ MarketError::Unauthorized
     )]
-    pub yes_mint: Account<'info, Mint>,
+    pub yes_mint: Box<Account<'info, Mint>>,
     #[account(
         mut,
         constraint = no_mint.key() == market.no_mint @ MarketError::Unauthorized
     )]
-    pub no_mint: Account<'info, Mint>,
-    /// Arcium MPC authority (in production, verify signature)
-    pub arcium_authority: Signer<'info>,
+    pub no_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint = owner_collateral.owner == owner.key() @ MarketError::Unauthorized,
+        constraint = owner_collateral.mint == market.collateral_mint @ MarketError::Unauthorized
+    )]
+    pub owner_collateral: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = owner_yes_tokens.mint == market.yes_mint @ MarketError::Unauthorized
+    )]
+    pub owner_yes_tokens: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = owner_no_tokens.mint == market.no_mint @ MarketError::Unauthorized
+    )]
+    pub owner_no_tokens: Box<Account<'info, TokenAccount>>,
+    pub owner: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
 
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct OpenBatch<'info> {
+    #[account(
+        mut,
+        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
+    )]
+    pub market: Account<'info, Market>,
+    #[account(
+        init,
+        payer = payer,
+        space = BatchState::LEN,
+        seeds = [BATCH_SEED, market.key().as_ref(), &epoch.to_le_bytes()],
+        bump
+    )]
+    pub batch: Account<'info, BatchState>,
+    #[account(
+        init,
+        payer = payer,
+        space = OrderQueue::LEN,
+        seeds = [ORDER_QUEUE_SEED, batch.key().as_ref()],
+        bump
+    )]
+    pub order_queue: Box<Account<'info, OrderQueue>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyBatchOrder<'info> {
+    pub market: Account<'info, Market>,
+    #[account(
+        seeds = [BATCH_SEED, market.key().as_ref(), &batch.epoch.to_le_bytes()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, BatchState>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBatch<'info> {
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        seeds = [BATCH_SEED, market.key().as_ref(), &batch.epoch.to_le_bytes()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, BatchState>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCfmmState<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    /// Audited MPC result authority configured in `ProtocolConfig`
+    #[account(
+        constraint = arcium_authority.key() == config.mpc_authority @ MarketError::Unauthorized
+    )]
+    pub arcium_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = market.resolution_state == ResolutionState::Active @ MarketError::MarketAlreadyResolved
+    )]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        seeds = [RESERVES_SEED, market.key().as_ref()],
+        bump = reserves.bump,
+        constraint = !reserves.pending @ MarketError::ComputationPending
+    )]
+    pub reserves: Account<'info, EncryptedReserves>,
+}
+
 #[arcium_program]
 pub mod private_markets {
     use super::*;
@@ -483,47 +1807,217 @@ pub mod private_markets {
         Ok(())
     }
 
+    pub fn init_lmsr_trade_comp_def(ctx: Context<InitLmsrTradeCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_cfmm_state_comp_def(ctx: Context<InitCfmmStateCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_add_liquidity_comp_def(ctx: Context<InitAddLiquidityCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_remove_liquidity_comp_def(ctx: Context<InitRemoveLiquidityCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_position_comp_def(ctx: Context<InitPositionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, 0, None, None)?;
+        Ok(())
+    }
+
     // Forward to instruction handlers
+
+    /// Create the protocol config. Only the program's upgrade authority may
+    /// call this.
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        treasury: Pubkey,
+        mpc_authority: Pubkey,
+        protocol_fee_bps: u16,
+        allowed_collateral_mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        initialize_config_handler(
+            ctx,
+            treasury,
+            mpc_authority,
+            protocol_fee_bps,
+            allowed_collateral_mints,
+        )
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        treasury: Pubkey,
+        mpc_authority: Pubkey,
+        protocol_fee_bps: u16,
+        allowed_collateral_mints: Vec<Pubkey>,
+        paused: bool,
+    ) -> Result<()> {
+        update_config_handler(
+            ctx,
+            treasury,
+            mpc_authority,
+            protocol_fee_bps,
+            allowed_collateral_mints,
+            paused,
+        )
+    }
+
+    /// Nominate a new protocol admin (takes effect once accepted)
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        transfer_admin_handler(ctx, new_admin)
+    }
+
+    /// Accept a pending protocol admin nomination
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin_handler(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create_market(
         ctx: Context<CreateMarket>,
+        market_id: u64,
         question: String,
         end_time: i64,
         fee_bps: u16,
         batch_interval: i64,
         resolver_quorum: u8,
+        pricing_curve: PricingCurve,
+        lmsr_b: u64,
     ) -> Result<()> {
         create_market_handler(
             ctx,
+            market_id,
             question,
             end_time,
             fee_bps,
             batch_interval,
             resolver_quorum,
+            pricing_curve,
+            lmsr_b,
         )
     }
 
+    /// Create the market's encrypted reserves account and have the MXE
+    /// write its initial (empty) state
+    pub fn initialize_reserves(
+        ctx: Context<InitializeReserves>,
+        computation_offset: u64,
+        nonce: u128,
+    ) -> Result<()> {
+        initialize_reserves_handler(ctx, computation_offset, nonce)
+    }
+
+    /// Create the caller's encrypted position for a market, encrypted to
+    /// their x25519 key
+    pub fn open_position(
+        ctx: Context<OpenPosition>,
+        computation_offset: u64,
+        encryption_pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        open_position_handler(ctx, computation_offset, encryption_pubkey, nonce)
+    }
+
     pub fn deposit_collateral(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
         deposit_collateral_handler(ctx, amount)
     }
 
-    pub fn mint_outcome_tokens(ctx: Context<MintOutcomeTokens>, amount: u64) -> Result<()> {
-        mint_outcome_tokens_handler(ctx, amount)
+    /// Deposit collateral and receive an equal amount of YES and NO tokens
+    pub fn split_collateral(ctx: Context<SplitCollateral>, amount: u64) -> Result<()> {
+        split_collateral_handler(ctx, amount)
+    }
+
+    /// Burn equal YES and NO amounts and withdraw the matching collateral
+    pub fn merge_positions(ctx: Context<MergePositions>, amount: u64) -> Result<()> {
+        merge_positions_handler(ctx, amount)
     }
 
-    pub fn redeem_tokens(ctx: Context<RedeemTokens>, amount: u64) -> Result<()> {
-        redeem_tokens_handler(ctx, amount)
+    /// Deposit collateral into the encrypted pool reserves for LP shares
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        computation_offset: u64,
+        amount: u64,
+    ) -> Result<()> {
+        add_liquidity_handler(ctx, computation_offset, amount)
+    }
+
+    /// Burn LP shares for a pro-rata share of the pool and LP fees
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        computation_offset: u64,
+        shares: u64,
+    ) -> Result<()> {
+        remove_liquidity_handler(ctx, computation_offset, shares)
+    }
+
+    /// Withdraw accrued trading fees to the market authority
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        withdraw_fees_handler(ctx, amount)
+    }
+
+    pub fn redeem_tokens(ctx: Context<RedeemTokens>, amount: u64) -> Result<()> {
+        redeem_tokens_handler(ctx, amount)
+    }
+
+    pub fn resolve_market(
+        ctx: Context<ResolveMarket>,
+        final_outcome: u8,
+        resolution_proof: Vec<u8>,
+    ) -> Result<()> {
+        resolve_market_handler(ctx, final_outcome, resolution_proof)
+    }
+
+    pub fn stake_resolver(ctx: Context<StakeResolver>, amount: u64) -> Result<()> {
+        stake_resolver_handler(ctx, amount)
+    }
+
+    #[arcium_callback(encrypted_ix = "init_cfmm_state")]
+    pub fn init_cfmm_state_callback(
+        ctx: Context<InitCfmmStateCallback>,
+        output: ComputationOutputs<InitCfmmStateOutput>,
+    ) -> Result<()> {
+        let state = match output {
+            ComputationOutputs::Success(InitCfmmStateOutput { field_0 }) => field_0,
+            _ => return Err(MarketError::ComputationFailed.into()),
+        };
+
+        let market = &mut ctx.accounts.market;
+        market.cfmm_state_commitment = ctx
+            .accounts
+            .reserves
+            .set_state(state.nonce, state.ciphertexts);
+
+        msg!("Encrypted reserves initialized for market {}", market.key());
+        Ok(())
     }
 
-    pub fn resolve_market(
-        ctx: Context<ResolveMarket>,
-        final_outcome: u8,
-        resolution_proof: Vec<u8>,
+    #[arcium_callback(encrypted_ix = "init_position")]
+    pub fn init_position_callback(
+        ctx: Context<InitPositionCallback>,
+        output: ComputationOutputs<InitPositionOutput>,
     ) -> Result<()> {
-        resolve_market_handler(ctx, final_outcome, resolution_proof)
-    }
+        let position_state = match output {
+            ComputationOutputs::Success(InitPositionOutput { field_0 }) => field_0,
+            _ => return Err(MarketError::ComputationFailed.into()),
+        };
 
-    pub fn stake_resolver(ctx: Context<StakeResolver>, amount: u64) -> Result<()> {
-        stake_resolver_handler(ctx, amount)
+        let position = &mut ctx.accounts.position;
+        position.set_state(position_state.nonce, position_state.ciphertexts);
+
+        msg!(
+            "Encrypted position initialized for {} in market {}",
+            position.authority,
+            position.market
+        );
+        Ok(())
     }
 
     #[arcium_callback(encrypted_ix = "private_trade")]
@@ -531,23 +2025,212 @@ pub mod private_markets {
         ctx: Context<PrivateTradeCallback>,
         output: ComputationOutputs<PrivateTradeOutput>,
     ) -> Result<()> {
-        let cfmm_state = match output {
-            ComputationOutputs::Success(PrivateTradeOutput { field_0 }) => field_0,
-            _ => return Err(MarketError::ComputationFailed.into()),
+        // Release the lock taken when the computation was queued, whether or
+        // not it succeeded
+        ctx.accounts.reserves.end_computation()?;
+        let escrow = ctx.accounts.position.end_computation()?;
+
+        let (receipt, new_state, new_position, fill) = match output {
+            ComputationOutputs::Success(PrivateTradeOutput {
+                field_0:
+                    PrivateTradeOutputStruct0 {
+                        field_0,
+                        field_1,
+                        field_2,
+                        field_3,
+                        field_4,
+                        field_5,
+                        field_6,
+                    },
+            }) => (
+                field_0,
+                field_1,
+                field_2,
+                TradeFill {
+                    side: field_3,
+                    shares_out: field_4,
+                    spent: field_5,
+                    refund: field_6,
+                },
+            ),
+            _ => {
+                msg!(
+                    "private_trade computation failed for market {}, escrow refunded",
+                    ctx.accounts.market.key()
+                );
+                let accounts = ctx.accounts;
+                return refund_from_vault(
+                    &accounts.market,
+                    &accounts.collateral_vault,
+                    &accounts.user_collateral,
+                    &accounts.token_program,
+                    escrow,
+                );
+            }
         };
 
-        let market = &mut ctx.accounts.market;
-        msg!(
-            "Private trade executed successfully for market {}",
-            market.key()
-        );
+        let accounts = ctx.accounts;
+        apply_trade_result(
+            TradeSettlement {
+                market: &mut accounts.market,
+                reserves: &mut accounts.reserves,
+                position: &mut accounts.position,
+                collateral_vault: &accounts.collateral_vault,
+                fee_vault: &accounts.fee_vault,
+                yes_mint: &accounts.yes_mint,
+                no_mint: &accounts.no_mint,
+                user_collateral: &accounts.user_collateral,
+                user_yes_tokens: &accounts.user_yes_tokens,
+                user_no_tokens: &accounts.user_no_tokens,
+                token_program: &accounts.token_program,
+            },
+            receipt,
+            new_state,
+            new_position,
+            fill,
+        )
+    }
 
-        emit!(PrivateTradeExecuted {
-            market: market.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+    #[arcium_callback(encrypted_ix = "lmsr_trade")]
+    pub fn lmsr_trade_callback(
+        ctx: Context<LmsrTradeCallback>,
+        output: ComputationOutputs<LmsrTradeOutput>,
+    ) -> Result<()> {
+        // Release the lock taken when the computation was queued, whether or
+        // not it succeeded
+        ctx.accounts.reserves.end_computation()?;
+        let escrow = ctx.accounts.position.end_computation()?;
 
-        Ok(())
+        let (receipt, new_state, new_position, fill) = match output {
+            ComputationOutputs::Success(LmsrTradeOutput {
+                field_0:
+                    LmsrTradeOutputStruct0 {
+                        field_0,
+                        field_1,
+                        field_2,
+                        field_3,
+                        field_4,
+                        field_5,
+                        field_6,
+                    },
+            }) => (
+                field_0,
+                field_1,
+                field_2,
+                TradeFill {
+                    side: field_3,
+                    shares_out: field_4,
+                    spent: field_5,
+                    refund: field_6,
+                },
+            ),
+            _ => {
+                msg!(
+                    "lmsr_trade computation failed for market {}, escrow refunded",
+                    ctx.accounts.market.key()
+                );
+                let accounts = ctx.accounts;
+                return refund_from_vault(
+                    &accounts.market,
+                    &accounts.collateral_vault,
+                    &accounts.user_collateral,
+                    &accounts.token_program,
+                    escrow,
+                );
+            }
+        };
+
+        let accounts = ctx.accounts;
+        apply_trade_result(
+            TradeSettlement {
+                market: &mut accounts.market,
+                reserves: &mut accounts.reserves,
+                position: &mut accounts.position,
+                collateral_vault: &accounts.collateral_vault,
+                fee_vault: &accounts.fee_vault,
+                yes_mint: &accounts.yes_mint,
+                no_mint: &accounts.no_mint,
+                user_collateral: &accounts.user_collateral,
+                user_yes_tokens: &accounts.user_yes_tokens,
+                user_no_tokens: &accounts.user_no_tokens,
+                token_program: &accounts.token_program,
+            },
+            receipt,
+            new_state,
+            new_position,
+            fill,
+        )
+    }
+
+    #[arcium_callback(encrypted_ix = "add_liquidity")]
+    pub fn add_liquidity_callback(
+        ctx: Context<AddLiquidityCallback>,
+        output: ComputationOutputs<AddLiquidityOutput>,
+    ) -> Result<()> {
+        // Release the lock taken when the computation was queued, whether or
+        // not it succeeded
+        ctx.accounts.reserves.end_computation()?;
+        let (_, deposit) = ctx.accounts.reserves.take_held_liquidity();
+
+        let (new_state, shares, fee_buy_in, yes_returned, no_returned) = match output {
+            ComputationOutputs::Success(AddLiquidityOutput {
+                field_0:
+                    AddLiquidityOutputStruct0 {
+                        field_0,
+                        field_1,
+                        field_2,
+                        field_3,
+                        field_4,
+                    },
+            }) => (field_0, field_1, field_2, field_3, field_4),
+            _ => {
+                msg!(
+                    "add_liquidity computation failed for market {}, deposit refunded",
+                    ctx.accounts.market.key()
+                );
+                let accounts = ctx.accounts;
+                return refund_from_vault(
+                    &accounts.market,
+                    &accounts.collateral_vault,
+                    &accounts.user_collateral,
+                    &accounts.token_program,
+                    deposit,
+                );
+            }
+        };
+
+        add_liquidity_callback_handler(ctx, new_state, shares, fee_buy_in, yes_returned, no_returned)
+    }
+
+    #[arcium_callback(encrypted_ix = "remove_liquidity")]
+    pub fn remove_liquidity_callback(
+        ctx: Context<RemoveLiquidityCallback>,
+        output: ComputationOutputs<RemoveLiquidityOutput>,
+    ) -> Result<()> {
+        // Release the lock taken when the computation was queued, whether or
+        // not it succeeded
+        ctx.accounts.reserves.end_computation()?;
+        let (shares, fee_amount) = ctx.accounts.reserves.take_held_liquidity();
+
+        let (new_state, yes_amount, no_amount) = match output {
+            ComputationOutputs::Success(RemoveLiquidityOutput {
+                field_0:
+                    RemoveLiquidityOutputStruct0 {
+                        field_0,
+                        field_1,
+                        field_2,
+                    },
+            }) => (field_0, field_1, field_2),
+            _ => {
+                msg!(
+                    "remove_liquidity computation failed for market {}",
+                    ctx.accounts.market.key()
+                );
+                return remove_liquidity_failure_handler(ctx, shares, fee_amount);
+            }
+        };
+
+        remove_liquidity_callback_handler(ctx, new_state, yes_amount, no_amount, fee_amount)
     }
 
     #[arcium_callback(encrypted_ix = "batch_clear")]
@@ -555,22 +2238,108 @@ pub mod private_markets {
         ctx: Context<BatchClearCallback>,
         output: ComputationOutputs<BatchClearOutput>,
     ) -> Result<()> {
-        let batch_result = match output {
-            ComputationOutputs::Success(BatchClearOutput { field_0 }) => field_0,
-            _ => return Err(MarketError::ComputationFailed.into()),
+        // Release the lock taken when the computation was queued, whether or
+        // not it succeeded
+        ctx.accounts.reserves.end_computation()?;
+
+        let (new_state, public_price, clearing_price, fills, sides, shares, refunds) = match output {
+            ComputationOutputs::Success(BatchClearOutput {
+                field_0:
+                    BatchClearOutputStruct0 {
+                        field_0,
+                        field_1,
+                        field_2,
+                        field_3,
+                        field_4,
+                        field_5,
+                        field_6,
+                        field_7,
+                        field_8,
+                        field_9,
+                    },
+            }) => (
+                field_0,
+                field_1,
+                field_2,
+                [field_3, field_4, field_5, field_6],
+                field_7,
+                field_8,
+                field_9,
+            ),
+            _ => {
+                // Reopen the batch to the crank so it can be cleared again
+                ctx.accounts.batch.state = BatchStateEnum::Closed;
+                msg!(
+                    "batch_clear computation failed for market {}",
+                    ctx.accounts.market.key()
+                );
+                return Ok(());
+            }
         };
 
         let market = &mut ctx.accounts.market;
+        let batch = &mut ctx.accounts.batch;
+        let order_queue = &mut ctx.accounts.order_queue;
         let clock = Clock::get()?;
 
-        market.next_batch_clear = clock.unix_timestamp + market.batch_interval;
-        market.batch_order_count = 0;
+        require!(
+            batch.state == BatchStateEnum::Clearing,
+            MarketError::BatchNotClearing
+        );
+        batch.record_clear(clearing_price)?;
+
+        // Keep each order's settlement for the trader to claim
+        let mut total_yes_filled: u64 = 0;
+        let mut total_no_filled: u64 = 0;
+        for (i, fill) in fills.iter().enumerate().take(batch.order_count as usize) {
+            order_queue.fills[i] = BatchFill {
+                side: sides[i],
+                shares: shares[i],
+                refund: refunds[i],
+                nonce: fill.nonce,
+                ciphertexts: fill.ciphertexts,
+            };
+            if sides[i] {
+                total_yes_filled = total_yes_filled
+                    .checked_add(shares[i])
+                    .ok_or(MarketError::Overflow)?;
+            } else {
+                total_no_filled = total_no_filled
+                    .checked_add(shares[i])
+                    .ok_or(MarketError::Overflow)?;
+            }
+        }
+
+        market.cfmm_state_commitment = ctx
+            .accounts
+            .reserves
+            .set_state(new_state.nonce, new_state.ciphertexts);
+        market.public_price = public_price;
+        market.last_clearing_price = clearing_price;
+        market.total_volume = market
+            .total_volume
+            .checked_add(total_yes_filled)
+            .and_then(|v| v.checked_add(total_no_filled))
+            .ok_or(MarketError::Overflow)?;
+        market.current_epoch = market.current_epoch.max(
+            batch
+                .epoch
+                .checked_add(1)
+                .ok_or(MarketError::Overflow)?,
+        );
 
-        msg!("Batch cleared for market {}", market.key());
+        msg!(
+            "Batch cleared for market {} at price {} (YES filled {}, NO filled {})",
+            market.key(),
+            clearing_price,
+            total_yes_filled,
+            total_no_filled
+        );
 
         emit!(BatchCleared {
             market: market.key(),
-            uniform_price: 0, // Placeholder - actual price computed in MPC
+            epoch: batch.epoch,
+            uniform_price: clearing_price,
             timestamp: clock.unix_timestamp,
         });
 
@@ -582,29 +2351,55 @@ pub mod private_markets {
         ctx: Context<ResolveMarketCallback>,
         output: ComputationOutputs<ResolveMarketOutput>,
     ) -> Result<()> {
-        let _resolution_result = match output {
-            ComputationOutputs::Success(ResolveMarketOutput { field_0 }) => field_0,
-            _ => return Err(MarketError::ComputationFailed.into()),
+        // The circuit resolves a stake-weighted tie as OUTCOME_INVALID
+        let (final_outcome, confidence) = match output {
+            ComputationOutputs::Success(ResolveMarketOutput {
+                field_0: ResolveMarketOutputStruct0 { field_0, field_1 },
+            }) => (field_0, field_1),
+            _ => {
+                // The votes stay stored; `retry_resolution` can queue the
+                // tally again
+                let market = &mut ctx.accounts.market;
+                market.resolution_state = ResolutionState::AwaitingAttestation;
+                msg!("resolve_market computation failed for market {}", market.key());
+                return Ok(());
+            }
         };
+        require!(final_outcome <= OUTCOME_INVALID, MarketError::InvalidOutcome);
 
         let market = &mut ctx.accounts.market;
         market.resolution_state = ResolutionState::Resolved;
-        // Note: actual outcome is encrypted in MPC, would need decryption to get real value
-        market.final_outcome = Some(0); // Placeholder - actual outcome stored encrypted
+        market.final_outcome = Some(final_outcome);
 
         let clock = Clock::get()?;
 
-        msg!("Market {} resolved via Arcium MPC", market.key());
+        msg!(
+            "Market {} resolved via Arcium MPC with outcome {} (confidence {})",
+            market.key(),
+            final_outcome,
+            confidence
+        );
 
         emit!(MarketResolved {
             market: market.key(),
-            outcome: 0, // Placeholder - actual outcome is encrypted
+            outcome: final_outcome,
             timestamp: clock.unix_timestamp,
         });
 
         Ok(())
     }
 
+    /// Open the batch auction for the epoch whose window contains the
+    /// current time. Optional: the epoch's first order opens it too.
+    pub fn open_batch(ctx: Context<OpenBatch>, epoch: u64) -> Result<()> {
+        open_batch_handler(ctx, epoch)
+    }
+
+    /// Stop accepting orders for a batch once its window has ended
+    pub fn close_batch(ctx: Context<CloseBatch>) -> Result<()> {
+        close_batch_handler(ctx)
+    }
+
     /// Apply batch clear results from Arcium MPC
     pub fn apply_batch_clear(
         ctx: Context<ApplyBatchClear>,
@@ -614,25 +2409,91 @@ pub mod private_markets {
         apply_batch_clear_handler(ctx, new_state_commitment, uniform_price)
     }
 
-    /// Submit encrypted attestation for market resolution
+    /// Submit an encrypted vote on the market outcome. The vote that reaches
+    /// quorum queues `resolve_market` over every stored vote.
     pub fn submit_attestation(
         ctx: Context<SubmitAttestation>,
         computation_offset: u64,
+        encryption_pubkey: [u8; 32],
+        nonce: u128,
         attestation: [u8; 32],
     ) -> Result<()> {
-        submit_attestation_handler(ctx, computation_offset, attestation)
+        submit_attestation_handler(ctx, computation_offset, encryption_pubkey, nonce, attestation)
+    }
+
+    /// Queue `resolve_market` again after a failed computation, once the
+    /// stored votes have reached quorum. Permissionless.
+    pub fn retry_resolution(ctx: Context<RetryResolution>, computation_offset: u64) -> Result<()> {
+        retry_resolution_handler(ctx, computation_offset)
     }
 
-    /// Submit a batch order
+    /// Queue an encrypted order into the batch for the current epoch,
+    /// escrowing its collateral plus the trading fee on the full amount.
+    /// Any other epoch fails with `WrongEpoch`.
     pub fn submit_batch_order(
         ctx: Context<SubmitBatchOrder>,
+        epoch: u64,
+        order: BatchOrderData,
+    ) -> Result<()> {
+        submit_batch_order_handler(ctx, epoch, order)
+    }
+
+    /// Withdraw a queued order from the open batch and refund its escrow,
+    /// including the escrowed trading fee
+    pub fn cancel_batch_order(ctx: Context<CancelBatchOrder>, commitment: [u8; 32]) -> Result<()> {
+        cancel_batch_order_handler(ctx, commitment)
+    }
+
+    /// Collect the outcome tokens and refunds for all of the caller's orders
+    /// in a cleared batch. The trading fee is charged on the filled spend
+    /// only.
+    pub fn claim_batch_fill(ctx: Context<ClaimBatchFill>) -> Result<()> {
+        claim_batch_fill_handler(ctx)
+    }
+
+    /// Prove that an order commitment was included in a batch
+    pub fn verify_batch_order(
+        ctx: Context<VerifyBatchOrder>,
+        commitment: [u8; 32],
+        index: u32,
+        proof: [[u8; 32]; ORDER_TREE_DEPTH],
+    ) -> Result<()> {
+        verify_batch_order_handler(ctx, commitment, index, proof)
+    }
+
+    /// Clear all of a closed batch's queued orders in one `batch_clear`
+    /// computation. Permissionless; the batch stays `Clearing` until the
+    /// callback lands, so it cannot be cranked twice.
+    pub fn crank_batch_clear(ctx: Context<CrankBatchClear>, computation_offset: u64) -> Result<()> {
+        crank_batch_clear_handler(ctx, computation_offset)
+    }
+
+    /// Submit a private trade against an LMSR market
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_lmsr_trade(
+        ctx: Context<SubmitLmsrTrade>,
         computation_offset: u64,
-        batch_orders: Vec<BatchOrderData>,
+        ciphertext_amount: [u8; 32],
+        ciphertext_side: [u8; 32],
+        ciphertext_max_price: [u8; 32],
+        nonce: u128,
+        client_pubkey: [u8; 32],
+        escrow_amount: u64,
     ) -> Result<()> {
-        submit_batch_order_handler(ctx, computation_offset, batch_orders)
+        submit_lmsr_trade_handler(
+            ctx,
+            computation_offset,
+            ciphertext_amount,
+            ciphertext_side,
+            ciphertext_max_price,
+            nonce,
+            client_pubkey,
+            escrow_amount,
+        )
     }
 
     /// Submit a private trade
+    #[allow(clippy::too_many_arguments)]
     pub fn submit_private_trade(
         ctx: Context<SubmitPrivateTrade>,
         computation_offset: u64,
@@ -641,6 +2502,7 @@ pub mod private_markets {
         ciphertext_max_price: [u8; 32],
         nonce: u128,
         client_pubkey: [u8; 32],
+        escrow_amount: u64,
     ) -> Result<()> {
         submit_private_trade_handler(
             ctx,
@@ -650,23 +2512,184 @@ pub mod private_markets {
             ciphertext_max_price,
             nonce,
             client_pubkey,
+            escrow_amount,
         )
     }
 
     /// Update CFMM state from private trade computation
     pub fn update_cfmm_state(
         ctx: Context<UpdateCfmmState>,
-        new_state_commitment: [u8; 32],
-        reserve_delta_yes: i64,
-        reserve_delta_no: i64,
+        new_nonce: u128,
+        new_encrypted_reserves: [[u8; 32]; 2],
     ) -> Result<()> {
-        update_cfmm_state_handler(
-            ctx,
-            new_state_commitment,
-            reserve_delta_yes,
-            reserve_delta_no,
-        )
+        update_cfmm_state_handler(ctx, new_nonce, new_encrypted_reserves)
+    }
+}
+
+/// Revealed token movements for a private trade
+struct TradeFill {
+    /// true if YES shares were bought
+    side: bool,
+    shares_out: u64,
+    /// Collateral spent, including the fee (0 for a rejected trade)
+    spent: u64,
+    /// Unspent escrow returned to the trader
+    refund: u64,
+}
+
+/// Return `amount` of escrowed collateral from the market vault, used when a
+/// computation fails after the deposit was taken
+fn refund_from_vault<'info>(
+    market: &Account<'info, Market>,
+    collateral_vault: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: collateral_vault.to_account_info(),
+        to: to.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    let cpi_program = token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)
+}
+
+/// Accounts a private trade callback settles against
+struct TradeSettlement<'a, 'info> {
+    market: &'a mut Account<'info, Market>,
+    reserves: &'a mut Account<'info, EncryptedReserves>,
+    position: &'a mut Account<'info, UserPosition>,
+    collateral_vault: &'a Account<'info, TokenAccount>,
+    fee_vault: &'a Account<'info, TokenAccount>,
+    yes_mint: &'a Account<'info, Mint>,
+    no_mint: &'a Account<'info, Mint>,
+    user_collateral: &'a Account<'info, TokenAccount>,
+    user_yes_tokens: &'a Account<'info, TokenAccount>,
+    user_no_tokens: &'a Account<'info, TokenAccount>,
+    token_program: &'a Program<'info, Token>,
+}
+
+/// Persist a trade's encrypted pool state and position, settle the escrowed
+/// collateral into outcome tokens (or refund it), and emit the trader's
+/// encrypted receipt
+fn apply_trade_result(
+    accounts: TradeSettlement,
+    receipt: SharedEncryptedStruct<4>,
+    new_state: MXEEncryptedStruct<2>,
+    new_position: SharedEncryptedStruct<3>,
+    fill: TradeFill,
+) -> Result<()> {
+    let TradeSettlement {
+        market,
+        reserves,
+        position,
+        collateral_vault,
+        fee_vault,
+        yes_mint,
+        no_mint,
+        user_collateral,
+        user_yes_tokens,
+        user_no_tokens,
+        token_program,
+    } = accounts;
+
+    // The circuit leaves the pool and position unchanged for trades outside
+    // the trader's max_price, so storing the re-encrypted state is always safe
+    market.cfmm_state_commitment = reserves.set_state(new_state.nonce, new_state.ciphertexts);
+    position.set_state(new_position.nonce, new_position.ciphertexts);
+
+    let filled = fill.spent > 0;
+    let fee = if filled { market.trading_fee(fill.spent)? } else { 0 };
+
+    let authority_bump = market.authority_bump;
+    let authority_key = market.authority.key();
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let seeds = &[
+        MARKET_SEED,
+        authority_key.as_ref(),
+        market_id_bytes.as_ref(),
+        &[authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_program = token_program.to_account_info();
+
+    // The net spend stays in the vault backing the minted shares and the pool
+    if fill.shares_out > 0 {
+        let (mint, to) = if fill.side {
+            (yes_mint, user_yes_tokens)
+        } else {
+            (no_mint, user_no_tokens)
+        };
+        let cpi_accounts = MintTo {
+            mint: mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::mint_to(cpi_ctx, fill.shares_out)?;
+    }
+
+    if fee > 0 {
+        let cpi_accounts = Transfer {
+            from: collateral_vault.to_account_info(),
+            to: fee_vault.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, fee)?;
+    }
+
+    if fill.refund > 0 {
+        let cpi_accounts = Transfer {
+            from: collateral_vault.to_account_info(),
+            to: user_collateral.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, fill.refund)?;
+    }
+
+    market.accrue_fee(fee)?;
+    market.total_volume = market
+        .total_volume
+        .checked_add(fill.spent)
+        .ok_or(MarketError::Overflow)?;
+
+    if filled {
+        msg!("Private trade executed for market {}", market.key());
+    } else {
+        // A funded pool only rejects a trade priced above the trader's
+        // max_price. Returning the error would revert the refund, so it is
+        // logged with its code instead.
+        Error::from(MarketError::SlippageExceeded).log();
+        msg!("Private trade rejected for market {}, escrow refunded", market.key());
     }
+
+    emit!(PrivateTradeExecuted {
+        market: market.key(),
+        state_commitment: market.cfmm_state_commitment,
+        filled,
+        refund: fill.refund,
+        receipt_nonce: receipt.nonce,
+        receipt_ciphertexts: receipt.ciphertexts.to_vec(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
 }
 
 // Accounts for initializing computation definitions (required by Arcium macros)
@@ -721,16 +2744,155 @@ pub struct InitResolveMarketCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[init_computation_definition_accounts("lmsr_trade", payer)]
+#[derive(Accounts)]
+pub struct InitLmsrTradeCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program (not initialized yet)
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("init_cfmm_state", payer)]
+#[derive(Accounts)]
+pub struct InitCfmmStateCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program (not initialized yet)
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("add_liquidity", payer)]
+#[derive(Accounts)]
+pub struct InitAddLiquidityCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program (not initialized yet)
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("remove_liquidity", payer)]
+#[derive(Accounts)]
+pub struct InitRemoveLiquidityCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program (not initialized yet)
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("init_position", payer)]
+#[derive(Accounts)]
+pub struct InitPositionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program (not initialized yet)
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
 // Event definitions
 #[event]
 pub struct PrivateTradeExecuted {
     pub market: Pubkey,
+    pub state_commitment: [u8; 32],
+    /// false if the trade was rejected and its escrow refunded
+    pub filled: bool,
+    pub refund: u64,
+    /// Trader-encrypted receipt (shares out, effective price, fee, filled)
+    pub receipt_nonce: u128,
+    pub receipt_ciphertexts: Vec<[u8; 32]>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchOrderQueued {
+    pub market: Pubkey,
+    pub epoch: u64,
+    pub owner: Pubkey,
+    pub commitment: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchFillClaimed {
+    pub market: Pubkey,
+    pub epoch: u64,
+    pub owner: Pubkey,
+    pub yes_shares: u64,
+    pub no_shares: u64,
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchOrderCancelled {
+    pub market: Pubkey,
+    pub epoch: u64,
+    pub owner: Pubkey,
+    pub commitment: [u8; 32],
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchOpened {
+    pub market: Pubkey,
+    pub epoch: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct BatchClosed {
+    pub market: Pubkey,
+    pub epoch: u64,
+    pub order_count: u32,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct BatchCleared {
     pub market: Pubkey,
+    pub epoch: u64,
     pub uniform_price: u64,
     pub timestamp: i64,
 }
@@ -751,6 +2913,79 @@ pub struct AttestationSubmitted {
     pub quorum: u8,
 }
 
+#[event]
+pub struct CollateralSplit {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionsMerged {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub market: Pubkey,
+    pub provider: Pubkey,
+    pub shares: u64,
+    pub fee_buy_in: u64,
+    pub yes_returned: u64,
+    pub no_returned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub market: Pubkey,
+    pub provider: Pubkey,
+    pub yes_amount: u64,
+    pub no_amount: u64,
+    pub collateral_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub market: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub protocol_share: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub treasury: Pubkey,
+    pub mpc_authority: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensRedeemed {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub outcome_mint: Pubkey,
+    pub amount: u64,
+    pub payout: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct PrivateTradeQueued {
     pub market: Pubkey,
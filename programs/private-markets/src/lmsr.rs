@@ -0,0 +1,233 @@
+//! Plaintext reference for the `lmsr_trade` circuit in `encrypted-ixs`.
+//!
+//! The arithmetic here mirrors the circuit step for step (same fixed-point
+//! scale, series lengths and range limits) so quotes computed off the MPC
+//! path match what the cluster returns.
+
+/// Fixed-point scale for quoted prices (1.0 == PRICE_SCALE)
+pub const PRICE_SCALE: u128 = 1_000_000;
+
+/// Fixed-point scale for the exp/ln internals (1.0 == FIXED_ONE)
+pub const FIXED_ONE: u128 = 1_000_000_000_000;
+
+/// Largest exponent argument `exp_fixed` takes (e^15), keeps products
+/// within u128
+pub const MAX_EXP_ARG: u128 = 15 * FIXED_ONE;
+
+/// ln(2) in FIXED_ONE fixed point
+pub const LN_2: u128 = 693_147_180_560;
+
+/// e^x for 0 <= x <= MAX_EXP_ARG in FIXED_ONE fixed point
+pub fn exp_fixed(x: u128) -> u128 {
+    debug_assert!(x <= MAX_EXP_ARG, "exp_fixed argument out of range");
+    let one = FIXED_ONE;
+    let r = x / 32;
+
+    let mut term = one;
+    let mut sum = one;
+    for i in 1..9u128 {
+        term = term * r / (one * i);
+        sum += term;
+    }
+    for _ in 0..5 {
+        sum = sum * sum / one;
+    }
+    sum
+}
+
+/// ln(y) for y >= 1 in FIXED_ONE fixed point (values below 1 clamp to 0)
+pub fn ln_fixed(y: u128) -> u128 {
+    let one = FIXED_ONE;
+    let mut y = y.max(one);
+    let mut halvings = 0u128;
+    for _ in 0..64 {
+        if y >= 2 * one {
+            y /= 2;
+            halvings += 1;
+        }
+    }
+
+    let z = (y - one) * one / (y + one);
+    let z2 = z * z / one;
+    let mut term = z;
+    let mut sum = z;
+    for k in [3u128, 5, 7, 9, 11] {
+        term = term * z2 / one;
+        sum += term / k;
+    }
+    halvings * LN_2 + 2 * sum
+}
+
+/// e^d for d = (q_other - q_outcome) / b in FIXED_ONE fixed point. Past
+/// MAX_EXP_ARG either way it saturates at e^15 or 0, which prices the
+/// trailing outcome no higher than the exact curve.
+fn exp_diff(q_outcome: u64, q_other: u64, b: u64) -> u128 {
+    let one = FIXED_ONE;
+    let d_abs = q_other.abs_diff(q_outcome) as u128 * one / b as u128;
+    let e_d_abs = exp_fixed(d_abs.min(MAX_EXP_ARG));
+    if q_other >= q_outcome {
+        e_d_abs
+    } else if d_abs > MAX_EXP_ARG {
+        0
+    } else {
+        one * one / e_d_abs
+    }
+}
+
+/// Shares of one outcome bought for `amount_in` collateral, given the
+/// outstanding quantities of the bought and other outcome and liquidity `b`.
+/// Zero, so the trade is rejected, when `amount_in / b` is past MAX_EXP_ARG.
+pub fn lmsr_shares_out(q_bought: u64, q_other: u64, b: u64, amount_in: u64) -> u64 {
+    let one = FIXED_ONE;
+    let x = amount_in as u128 * one / b as u128;
+    if x > MAX_EXP_ARG {
+        return 0;
+    }
+    let e_x = exp_fixed(x);
+    let e_d = exp_diff(q_bought, q_other, b);
+
+    let inner = e_x * (one + e_d) / one - e_d;
+    (b as u128 * ln_fixed(inner) / one) as u64
+}
+
+/// Collateral the creator of an LMSR market deposits up front: the market
+/// maker's worst-case loss `b * ln 2`, rounded up
+pub fn lmsr_subsidy(b: u64) -> u64 {
+    (b as u128 * LN_2).div_ceil(FIXED_ONE) as u64
+}
+
+/// Instantaneous price of the outcome with quantity `q_outcome`, in PRICE_SCALE
+pub fn lmsr_price(q_outcome: u64, q_other: u64, b: u64) -> u64 {
+    let one = FIXED_ONE;
+    let e_d = exp_diff(q_outcome, q_other, b);
+    (PRICE_SCALE * one / (one + e_d)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_f64(x: u128) -> f64 {
+        x as f64 / FIXED_ONE as f64
+    }
+
+    fn to_fixed(x: f64) -> u128 {
+        (x * FIXED_ONE as f64) as u128
+    }
+
+    fn lmsr_shares_out_f64(q_bought: f64, q_other: f64, b: f64, amount_in: f64) -> f64 {
+        let d = (q_other - q_bought) / b;
+        b * ((amount_in / b).exp() * (1.0 + d.exp()) - d.exp()).ln()
+    }
+
+    #[test]
+    fn exp_matches_f64() {
+        for x in [0.0, 0.001, 0.1, 0.5, 1.0, 2.5, 7.0, 15.0] {
+            let fixed = to_f64(exp_fixed(to_fixed(x)));
+            let expected = f64::exp(x);
+            assert!(
+                (fixed - expected).abs() / expected < 1e-6,
+                "exp({x}): {fixed} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn ln_matches_f64() {
+        for y in [1.0, 1.001, 1.5, 2.0, 3.0, 10.0, 1_000.0, 1e9] {
+            let fixed = to_f64(ln_fixed(to_fixed(y)));
+            let expected = f64::ln(y);
+            assert!((fixed - expected).abs() < 1e-6, "ln({y}): {fixed} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn shares_out_matches_closed_form() {
+        let b = 1_000_000_000u64; // 1,000 tokens of liquidity at 6 decimals
+        let cases = [
+            (0u64, 0u64, 10_000_000u64),
+            (0, 0, 500_000_000),
+            (300_000_000, 100_000_000, 50_000_000),
+            (100_000_000, 2_000_000_000, 50_000_000),
+        ];
+        for (q_bought, q_other, amount_in) in cases {
+            let fixed = lmsr_shares_out(q_bought, q_other, b, amount_in) as f64;
+            let expected =
+                lmsr_shares_out_f64(q_bought as f64, q_other as f64, b as f64, amount_in as f64);
+            assert!(
+                (fixed - expected).abs() / expected < 1e-5,
+                "shares_out({q_bought}, {q_other}, {amount_in}): {fixed} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_spends_past_the_exponent_range() {
+        let b = 1_000_000u64;
+        let at_limit = 15 * b;
+        assert!(lmsr_shares_out(0, 0, b, at_limit) > 0);
+        assert_eq!(lmsr_shares_out(0, 0, b, at_limit + 1), 0);
+        assert_eq!(lmsr_shares_out(0, 0, b, u64::MAX / 2), 0);
+    }
+
+    #[test]
+    fn saturated_spreads_never_overpay() {
+        let b = 1_000_000u64;
+        let amount_in = 1_000_000u64;
+        // Either side 20 b ahead, past the exponent range
+        for (q_bought, q_other) in [(0u64, 20 * b), (20 * b, 0)] {
+            let fixed = lmsr_shares_out(q_bought, q_other, b, amount_in) as f64;
+            let exact =
+                lmsr_shares_out_f64(q_bought as f64, q_other as f64, b as f64, amount_in as f64);
+            assert!(fixed > 0.0 && fixed <= exact, "{fixed} vs {exact}");
+        }
+        assert_eq!(lmsr_price(20 * b, 0, b), PRICE_SCALE as u64);
+    }
+
+    #[test]
+    fn subsidy_covers_worst_case_payout() {
+        let b = 100_000_000u64;
+        let subsidy = lmsr_subsidy(b);
+        assert_eq!(subsidy, 69_314_719);
+
+        // Whatever the trades, the vault (subsidy plus the net collateral
+        // traders paid in) covers every share of the winning outcome
+        let trades = [
+            (true, 1_000_000u64),
+            (true, 50_000_000),
+            (false, 20_000_000),
+            (true, 300_000_000),
+            (true, 1_000_000_000),
+            (false, 5_000_000_000),
+            (true, 1),
+        ];
+        let (mut q_yes, mut q_no, mut vault) = (0u64, 0u64, subsidy);
+        for (buy_yes, amount_in) in trades {
+            let shares = if buy_yes {
+                lmsr_shares_out(q_yes, q_no, b, amount_in)
+            } else {
+                lmsr_shares_out(q_no, q_yes, b, amount_in)
+            };
+            // A rejected trade is refunded and leaves the vault as it was
+            if shares == 0 {
+                continue;
+            }
+            if buy_yes {
+                q_yes += shares;
+            } else {
+                q_no += shares;
+            }
+            vault += amount_in;
+            assert!(vault >= q_yes.max(q_no), "vault {vault} < payout ({q_yes}, {q_no})");
+        }
+    }
+
+    #[test]
+    fn prices_sum_to_one() {
+        let b = 1_000_000_000u64;
+        for (q_yes, q_no) in [(0u64, 0u64), (250_000_000, 0), (0, 4_000_000_000)] {
+            let total = lmsr_price(q_yes, q_no, b) + lmsr_price(q_no, q_yes, b);
+            assert!(total.abs_diff(PRICE_SCALE as u64) <= 2);
+        }
+    }
+}
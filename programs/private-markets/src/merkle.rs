@@ -0,0 +1,170 @@
+//! Incremental Merkle tree over a batch's order commitments.
+//!
+//! Each batch holds at most `MAX_BATCH_ORDERS` orders, so the tree has a
+//! fixed depth of `ORDER_TREE_DEPTH` and unused leaves are zero. Only the
+//! left frontier is kept on chain; clients rebuild the full tree from the
+//! `BatchOrderQueued` events to produce inclusion proofs.
+
+use crate::constants::ORDER_TREE_DEPTH;
+use solana_keccak_hasher::hashv;
+
+/// Domain separators so a leaf can never be passed off as an inner node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf for an order commitment
+pub fn leaf_hash(commitment: &[u8; 32]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, commitment]).to_bytes()
+}
+
+/// Parent of two nodes
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Root of an empty subtree at each level (level 0 is an empty leaf)
+pub fn zero_hashes() -> [[u8; 32]; ORDER_TREE_DEPTH + 1] {
+    let mut zeros = [[0u8; 32]; ORDER_TREE_DEPTH + 1];
+    for level in 0..ORDER_TREE_DEPTH {
+        zeros[level + 1] = node_hash(&zeros[level], &zeros[level]);
+    }
+    zeros
+}
+
+/// Root of a batch with no orders
+pub fn empty_root() -> [u8; 32] {
+    zero_hashes()[ORDER_TREE_DEPTH]
+}
+
+/// Append the leaf for `commitment` at `index` and return the new root.
+/// Leaves must be inserted in order starting from 0.
+pub fn insert(
+    frontier: &mut [[u8; 32]; ORDER_TREE_DEPTH],
+    index: u32,
+    commitment: &[u8; 32],
+) -> [u8; 32] {
+    let zeros = zero_hashes();
+    let mut node = leaf_hash(commitment);
+    let mut index = index;
+    for (left, zero) in frontier.iter_mut().zip(zeros.iter()) {
+        if index.is_multiple_of(2) {
+            *left = node;
+            node = node_hash(&node, zero);
+        } else {
+            node = node_hash(left, &node);
+        }
+        index /= 2;
+    }
+    node
+}
+
+/// Check that `commitment` sits at `index` under `root`. `proof` lists the
+/// sibling at each level, starting from the leaf.
+pub fn verify_proof(
+    root: &[u8; 32],
+    commitment: &[u8; 32],
+    index: u32,
+    proof: &[[u8; 32]; ORDER_TREE_DEPTH],
+) -> bool {
+    if index as usize >= 1 << ORDER_TREE_DEPTH {
+        return false;
+    }
+    let mut node = leaf_hash(commitment);
+    let mut index = index;
+    for sibling in proof {
+        node = if index.is_multiple_of(2) {
+            node_hash(&node, sibling)
+        } else {
+            node_hash(sibling, &node)
+        };
+        index /= 2;
+    }
+    node == *root
+}
+
+/// Inclusion proof for the leaf at `index` given every commitment in the
+/// batch, in insertion order
+pub fn build_proof(commitments: &[[u8; 32]], index: u32) -> [[u8; 32]; ORDER_TREE_DEPTH] {
+    let mut level_nodes = [[0u8; 32]; 1 << ORDER_TREE_DEPTH];
+    for (node, commitment) in level_nodes.iter_mut().zip(commitments) {
+        *node = leaf_hash(commitment);
+    }
+
+    let mut proof = [[0u8; 32]; ORDER_TREE_DEPTH];
+    let mut index = index as usize;
+    let mut width = level_nodes.len();
+    for sibling in proof.iter_mut() {
+        *sibling = level_nodes[index ^ 1];
+        for i in 0..width / 2 {
+            level_nodes[i] = node_hash(&level_nodes[2 * i], &level_nodes[2 * i + 1]);
+        }
+        width /= 2;
+        index /= 2;
+    }
+    proof
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MAX_BATCH_ORDERS;
+
+    fn commitment(i: u8) -> [u8; 32] {
+        [i + 1; 32]
+    }
+
+    fn root_of(commitments: &[[u8; 32]]) -> [u8; 32] {
+        let mut frontier = [[0u8; 32]; ORDER_TREE_DEPTH];
+        let mut root = empty_root();
+        for (i, c) in commitments.iter().enumerate() {
+            root = insert(&mut frontier, i as u32, c);
+        }
+        root
+    }
+
+    #[test]
+    fn depth_covers_batch() {
+        assert_eq!(1 << ORDER_TREE_DEPTH, MAX_BATCH_ORDERS);
+    }
+
+    #[test]
+    fn incremental_root_matches_full_tree() {
+        let commitments: Vec<[u8; 32]> = (0..MAX_BATCH_ORDERS as u8).map(commitment).collect();
+        let full = node_hash(
+            &node_hash(&leaf_hash(&commitments[0]), &leaf_hash(&commitments[1])),
+            &node_hash(&leaf_hash(&commitments[2]), &leaf_hash(&commitments[3])),
+        );
+        assert_eq!(root_of(&commitments), full);
+
+        // A partial batch pads with empty leaves
+        let partial = node_hash(
+            &node_hash(&leaf_hash(&commitments[0]), &[0u8; 32]),
+            &zero_hashes()[1],
+        );
+        assert_eq!(root_of(&commitments[..1]), partial);
+    }
+
+    #[test]
+    fn proofs_verify_for_every_leaf() {
+        for count in 1..=MAX_BATCH_ORDERS {
+            let commitments: Vec<[u8; 32]> = (0..count as u8).map(commitment).collect();
+            let root = root_of(&commitments);
+            for (i, c) in commitments.iter().enumerate() {
+                let proof = build_proof(&commitments, i as u32);
+                assert!(verify_proof(&root, c, i as u32, &proof), "leaf {i} of {count}");
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_leaf_index_or_root() {
+        let commitments: Vec<[u8; 32]> = (0..3).map(commitment).collect();
+        let root = root_of(&commitments);
+        let proof = build_proof(&commitments, 1);
+
+        assert!(!verify_proof(&root, &commitment(9), 1, &proof));
+        assert!(!verify_proof(&root, &commitments[1], 0, &proof));
+        assert!(!verify_proof(&root, &commitments[1], 5, &proof));
+        assert!(!verify_proof(&empty_root(), &commitments[1], 1, &proof));
+    }
+}
@@ -1,11 +1,66 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::error::MarketError;
+use crate::merkle;
+
+/// Protocol-wide configuration (singleton PDA)
+#[account]
+pub struct ProtocolConfig {
+    /// Protocol admin
+    pub admin: Pubkey,
+
+    /// Admin nominated by `transfer_admin`, pending acceptance
+    pub pending_admin: Option<Pubkey>,
+
+    /// Owner of the token accounts that receive the protocol fee share
+    pub treasury: Pubkey,
+
+    /// Audited authority allowed to apply MPC results outside the Arcium
+    /// callbacks (`Pubkey::default()` leaves only the callback path)
+    pub mpc_authority: Pubkey,
+
+    /// Share of withdrawn market fees sent to the treasury, in basis points
+    pub protocol_fee_bps: u16,
+
+    /// Collateral mints markets may be created with
+    pub allowed_collateral_mints: Vec<Pubkey>,
+
+    /// Global pause flag
+    pub paused: bool,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        1 + 32 + // pending_admin (Option<Pubkey>)
+        32 + // treasury
+        32 + // mpc_authority
+        2 + // protocol_fee_bps
+        4 + 32 * MAX_COLLATERAL_MINTS + // allowed_collateral_mints
+        1 + // paused
+        1; // bump
+
+    /// Protocol share of `amount` withdrawn market fees
+    pub fn protocol_share(&self, amount: u64) -> Result<u64> {
+        let share = (amount as u128)
+            .checked_mul(self.protocol_fee_bps as u128)
+            .ok_or(MarketError::Overflow)?
+            / BPS_DENOMINATOR as u128;
+        Ok(share as u64)
+    }
+}
 
 /// Market state for a prediction market
 #[account]
 pub struct Market {
     /// Market authority (creator)
     pub authority: Pubkey,
+
+    /// Creator-scoped market identifier (part of the market PDA seeds)
+    pub market_id: u64,
     
     /// Market question
     pub question: String,
@@ -46,31 +101,55 @@ pub struct Market {
     /// Collateral mint
     pub collateral_mint: Pubkey,
     
-    /// CFMM state commitment (hash of encrypted reserves)
+    /// CFMM state commitment (hash of the encrypted reserves and their nonce)
     pub cfmm_state_commitment: [u8; 32],
     
-    /// Current YES reserves (public aggregate)
-    pub yes_reserves: u64,
-    
-    /// Current NO reserves (public aggregate)
-    pub no_reserves: u64,
+    /// Pricing curve used for private trades
+    pub pricing_curve: PricingCurve,
+
+    /// LMSR liquidity parameter `b` (unused for CPMM markets)
+    pub lmsr_b: u64,
     
-    /// Total liquidity
+    /// Outstanding LP shares (supply of `lp_mint`)
     pub total_liquidity: u64,
+
+    /// LP share token mint
+    pub lp_mint: Pubkey,
     
     /// Total volume traded
     pub total_volume: u64,
+
+    /// Trading fees collected into the fee vault and not yet withdrawn
+    pub fees_accrued: u64,
+
+    /// Liquidity providers' share of trading fees held in the fee vault
+    pub lp_fees_accrued: u64,
     
     /// Batch order commitment root
     pub batch_order_root: [u8; 32],
     
     /// Number of orders in current batch
     pub batch_order_count: u32,
+
+    /// Epoch after the most recently cleared batch
+    pub current_epoch: u64,
+
+    /// Start of batch epoch 0; epoch k accepts orders during
+    /// [batch_start + k * batch_interval, batch_start + (k + 1) * batch_interval)
+    pub batch_start: i64,
+
+    /// Uniform clearing price of the most recent batch
+    pub last_clearing_price: u64,
+
+    /// YES price implied by the encrypted reserves, coarsened to 1% ticks
+    /// (PRICE_SCALE fixed point) and revealed at each batch clear; 0 until
+    /// the first clear
+    pub public_price: u64,
     
     /// Market resolution state
     pub resolution_state: ResolutionState,
     
-    /// Final outcome (None if unresolved, Some(0) = NO, Some(1) = YES)
+    /// Final outcome (None if unresolved, Some(0) = NO, Some(1) = YES, Some(2) = invalid)
     pub final_outcome: Option<u8>,
     
     /// Authority bump seed for PDA
@@ -83,6 +162,7 @@ pub struct Market {
 impl Market {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        8 + // market_id
         4 + MAX_QUESTION_LEN + // question (string)
         8 + // end_time
         2 + // fee_bps
@@ -97,16 +177,219 @@ impl Market {
         32 + // no_mint
         32 + // collateral_mint
         32 + // cfmm_state_commitment
-        8 + // yes_reserves
-        8 + // no_reserves
+        1 + // pricing_curve
+        8 + // lmsr_b
         8 + // total_liquidity
+        32 + // lp_mint
         8 + // total_volume
+        8 + // fees_accrued
+        8 + // lp_fees_accrued
         32 + // batch_order_root
         4 + // batch_order_count
+        8 + // current_epoch
+        8 + // batch_start
+        8 + // last_clearing_price
+        8 + // public_price
         1 + // resolution_state
         1 + 1 + // final_outcome (Option<u8>)
         1 + // authority_bump
         1; // bump
+
+    /// Batch epoch whose window contains `timestamp`
+    pub fn epoch_at(&self, timestamp: i64) -> u64 {
+        (timestamp.saturating_sub(self.batch_start).max(0) / self.batch_interval) as u64
+    }
+
+    /// Start and end of the order window for `epoch`
+    pub fn epoch_window(&self, epoch: u64) -> Result<(i64, i64)> {
+        let start = i64::try_from(epoch)
+            .ok()
+            .and_then(|e| e.checked_mul(self.batch_interval))
+            .and_then(|offset| self.batch_start.checked_add(offset))
+            .ok_or(MarketError::Overflow)?;
+        let end = start
+            .checked_add(self.batch_interval)
+            .ok_or(MarketError::Overflow)?;
+        Ok((start, end))
+    }
+
+    /// Trading fee charged on `amount` at this market's `fee_bps`
+    pub fn trading_fee(&self, amount: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(self.fee_bps as u128)
+            .ok_or(MarketError::Overflow)?
+            / BPS_DENOMINATOR as u128;
+        Ok(fee as u64)
+    }
+
+    /// Record a collected trading fee. Liquidity providers earn
+    /// `LP_FEE_SHARE_BPS` of it while any LP shares are outstanding; the rest
+    /// accrues to the market creator.
+    pub fn accrue_fee(&mut self, fee: u64) -> Result<()> {
+        let lp_fee = if self.total_liquidity > 0 {
+            mul_div(fee, LP_FEE_SHARE_BPS, BPS_DENOMINATOR)?
+        } else {
+            0
+        };
+        self.lp_fees_accrued = self
+            .lp_fees_accrued
+            .checked_add(lp_fee)
+            .ok_or(MarketError::Overflow)?;
+        self.fees_accrued = self
+            .fees_accrued
+            .checked_add(fee - lp_fee)
+            .ok_or(MarketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Pro-rata claim of `shares` LP shares on `amount`
+    pub fn lp_claim(&self, amount: u64, shares: u64) -> Result<u64> {
+        require!(
+            shares <= self.total_liquidity && self.total_liquidity > 0,
+            MarketError::InsufficientLiquidity
+        );
+        mul_div(amount, shares, self.total_liquidity)
+    }
+
+    /// Collateral paid out for redeeming `amount` tokens of `outcome_mint`
+    /// after resolution. See [`outcome_payout`].
+    pub fn redemption_payout(&self, outcome_mint: &Pubkey, amount: u64) -> Result<u64> {
+        let is_yes = *outcome_mint == self.yes_mint;
+        require!(
+            is_yes || *outcome_mint == self.no_mint,
+            MarketError::Unauthorized
+        );
+        outcome_payout(self.final_outcome, is_yes, amount)
+    }
+}
+
+/// Collateral owed for `amount` YES (or NO) tokens under `final_outcome`:
+/// 1:1 for the winning side, nothing for the losing side, and half value
+/// for both sides of an invalid market. The half is rounded down, so an odd
+/// amount leaves one unit in the vault; a complete set still redeems for at
+/// most the one unit of collateral that backs it. A single token on an
+/// invalid market therefore pays nothing, and redeeming it only burns it.
+pub fn outcome_payout(final_outcome: Option<u8>, is_yes: bool, amount: u64) -> Result<u64> {
+    match final_outcome {
+        Some(OUTCOME_YES) => Ok(if is_yes { amount } else { 0 }),
+        Some(OUTCOME_NO) => Ok(if is_yes { 0 } else { amount }),
+        Some(OUTCOME_INVALID) => Ok(amount / 2),
+        Some(_) => err!(MarketError::InvalidOutcome),
+        None => err!(MarketError::MarketNotResolved),
+    }
+}
+
+/// `a * b / c` rounded down, computed in u128
+pub fn mul_div(a: u64, b: u64, c: u64) -> Result<u64> {
+    require!(c > 0, MarketError::Overflow);
+    let result = (a as u128)
+        .checked_mul(b as u128)
+        .ok_or(MarketError::Overflow)?
+        / c as u128;
+    u64::try_from(result).map_err(|_| MarketError::Overflow.into())
+}
+
+/// MXE-encrypted CFMM reserves (`Enc<Mxe, CfmmState>`) for a market.
+/// Only the MPC circuits can read or update them.
+#[account]
+pub struct EncryptedReserves {
+    /// Market these reserves belong to
+    pub market: Pubkey,
+
+    /// Encrypted [yes_reserves, no_reserves] (q_yes / q_no for LMSR markets)
+    pub encrypted_reserves: [[u8; 32]; 2],
+
+    /// Nonce of the current ciphertexts
+    pub nonce: u128,
+
+    /// Set once the MXE has written the initial state
+    pub initialized: bool,
+
+    /// Bump seed
+    pub bump: u8,
+
+    /// Set while a queued computation is working from these reserves. No
+    /// other computation may be queued until its callback clears it.
+    pub pending: bool,
+
+    /// Nonce of the ciphertexts the pending computation read
+    pub pending_nonce: u128,
+
+    /// LP shares burned by a pending `remove_liquidity`, re-minted if it fails
+    pub pending_lp_shares: u64,
+
+    /// Collateral a pending liquidity computation holds for the provider: the
+    /// `add_liquidity` deposit, or the LP fees a `remove_liquidity` pays out
+    pub pending_collateral: u64,
+}
+
+impl EncryptedReserves {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // market
+        32 * 2 + // encrypted_reserves
+        16 + // nonce
+        1 + // initialized
+        1 + // bump
+        1 + // pending
+        16 + // pending_nonce
+        8 + // pending_lp_shares
+        8; // pending_collateral
+
+    /// Lock the reserves for a computation about to be queued
+    pub fn begin_computation(&mut self) -> Result<()> {
+        require!(!self.pending, MarketError::ComputationPending);
+        self.pending = true;
+        self.pending_nonce = self.nonce;
+        Ok(())
+    }
+
+    /// Release the lock from `begin_computation` once its callback lands,
+    /// checking the reserves were not replaced in between
+    pub fn end_computation(&mut self) -> Result<()> {
+        require!(
+            self.pending && self.pending_nonce == self.nonce,
+            MarketError::StaleComputation
+        );
+        self.pending = false;
+        Ok(())
+    }
+
+    /// Record the LP shares and collateral a liquidity computation holds
+    /// until its callback lands
+    pub fn hold_liquidity(&mut self, lp_shares: u64, collateral: u64) {
+        self.pending_lp_shares = lp_shares;
+        self.pending_collateral = collateral;
+    }
+
+    /// Take back what `hold_liquidity` recorded, as (lp_shares, collateral)
+    pub fn take_held_liquidity(&mut self) -> (u64, u64) {
+        (
+            std::mem::take(&mut self.pending_lp_shares),
+            std::mem::take(&mut self.pending_collateral),
+        )
+    }
+
+    /// Store a new encrypted state and return the commitment to it
+    pub fn set_state(&mut self, nonce: u128, ciphertexts: [[u8; 32]; 2]) -> [u8; 32] {
+        self.nonce = nonce;
+        self.encrypted_reserves = ciphertexts;
+        self.initialized = true;
+        solana_sha256_hasher::hashv(&[
+            &nonce.to_le_bytes()[..],
+            &ciphertexts[0],
+            &ciphertexts[1],
+        ])
+        .to_bytes()
+    }
+}
+
+/// Pricing curve used by a market's private trades
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PricingCurve {
+    /// Constant product (x * y = k) over the YES/NO reserves
+    Cpmm,
+    /// Logarithmic market scoring rule with liquidity parameter `lmsr_b`
+    Lmsr,
 }
 
 /// Resolution state enum
@@ -122,15 +405,88 @@ pub enum ResolutionState {
     Resolved,
 }
 
-/// Batch order data for submission
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+/// Batch order data for submission. Amount, side and limit price are only
+/// sent encrypted (`Enc<Shared, BatchOrder>`); the escrow is an upper bound on
+/// the encrypted amount.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
 pub struct BatchOrderData {
     /// Encrypted order commitment
     pub commitment: [u8; 32],
-    /// Order amount
+    /// Collateral escrowed with the order
     pub amount: u64,
-    /// True for buy, false for sell
-    pub is_buy: bool,
+    /// Trader's x25519 public key the order is encrypted with
+    pub encryption_pubkey: [u8; 32],
+    /// Encryption nonce
+    pub nonce: u128,
+    /// Encrypted [amount, side, limit_price]
+    pub ciphertexts: [[u8; 32]; 3],
+}
+
+/// An order waiting in a batch's queue
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct QueuedOrder {
+    /// Trader who submitted the order
+    pub owner: Pubkey,
+    pub order: BatchOrderData,
+}
+
+impl QueuedOrder {
+    pub const LEN: usize = 32 + // owner
+        32 + // commitment
+        8 + // amount
+        32 + // encryption_pubkey
+        16 + // nonce
+        32 * 3; // ciphertexts
+}
+
+/// An order's result from the batch clear. The settlement amounts are
+/// public; the fill is also encrypted to the trader.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct BatchFill {
+    /// Whether the filled shares are YES
+    pub side: bool,
+    /// Outcome shares owed to the trader
+    pub shares: u64,
+    /// Escrowed collateral returned to the trader
+    pub refund: u64,
+    /// Encryption nonce of the trader's fill
+    pub nonce: u128,
+    /// Encrypted [shares, spent] for the trader
+    pub ciphertexts: [[u8; 32]; 2],
+}
+
+impl BatchFill {
+    pub const LEN: usize = 1 + // side
+        8 + // shares
+        8 + // refund
+        16 + // nonce
+        32 * 2; // ciphertexts
+}
+
+/// Encrypted orders collected during one batch epoch, fed together into a
+/// single `batch_clear` computation. The first `BatchState::order_count`
+/// slots are in use.
+#[account]
+pub struct OrderQueue {
+    /// Batch these orders belong to
+    pub batch: Pubkey,
+
+    /// Bump seed
+    pub bump: u8,
+
+    /// Queued orders (fixed size so the MPC can read them by offset)
+    pub orders: [QueuedOrder; MAX_BATCH_ORDERS],
+
+    /// Per-order results, written when the batch clears
+    pub fills: [BatchFill; MAX_BATCH_ORDERS],
+}
+
+impl OrderQueue {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // batch
+        1 + // bump
+        QueuedOrder::LEN * MAX_BATCH_ORDERS + // orders
+        BatchFill::LEN * MAX_BATCH_ORDERS; // fills
 }
 
 /// Resolver account
@@ -154,9 +510,6 @@ pub struct Resolver {
     /// Timestamp of attestation
     pub attestation_timestamp: i64,
     
-    /// Count of attestations (for tracking)
-    pub count: u8,
-    
     /// Bump seed
     pub bump: u8,
 }
@@ -169,11 +522,57 @@ impl Resolver {
         1 + // has_attested
         32 + // attestation_commitment
         8 + // attestation_timestamp
-        1 + // count
         1; // bump
 }
 
-/// Batch state for batch auctions
+/// A resolver's encrypted vote (`Enc<Shared, Attestation>`) and the stake
+/// it is weighted by
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ResolverVote {
+    /// Resolver account that cast the vote
+    pub resolver: Pubkey,
+    /// Resolver's stake at the time of the vote
+    pub stake: u64,
+    /// Resolver's x25519 public key the vote is encrypted with
+    pub encryption_pubkey: [u8; 32],
+    /// Encryption nonce
+    pub nonce: u128,
+    /// Encrypted outcome
+    pub ciphertext: [u8; 32],
+}
+
+impl ResolverVote {
+    pub const LEN: usize = 32 + // resolver
+        8 + // stake
+        32 + // encryption_pubkey
+        16 + // nonce
+        32; // ciphertext
+}
+
+/// Every resolver vote for a market, fed together into a single
+/// `resolve_market` computation once quorum is reached. The first
+/// `Market::attestation_count` slots are in use.
+#[account]
+pub struct ResolutionVotes {
+    /// Market being resolved
+    pub market: Pubkey,
+
+    /// Bump seed
+    pub bump: u8,
+
+    /// Votes (fixed size so the MPC can read them by offset)
+    pub votes: [ResolverVote; MAX_RESOLVERS],
+}
+
+impl ResolutionVotes {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // market
+        1 + // bump
+        ResolverVote::LEN * MAX_RESOLVERS; // votes
+}
+
+/// Batch state for one epoch of a market's batch auctions, seeded by
+/// `[BATCH_SEED, market, epoch]` so past clearing prices stay queryable
 #[account]
 pub struct BatchState {
     /// Market this batch belongs to
@@ -190,15 +589,21 @@ pub struct BatchState {
     
     /// Order commitments merkle root
     pub order_root: [u8; 32],
+
+    /// Left frontier of the commitment tree, for appending the next order
+    pub order_frontier: [[u8; 32]; ORDER_TREE_DEPTH],
     
     /// Number of orders
     pub order_count: u32,
     
-    /// Batch state (open, closed, cleared)
+    /// Batch state (open, closed, clearing, cleared)
     pub state: BatchStateEnum,
     
     /// Uniform clearing price (after clearing)
     pub clearing_price: u64,
+
+    /// Bit i is set once the fill for order slot i has been claimed
+    pub claimed: u32,
     
     /// Bump seed
     pub bump: u8,
@@ -211,10 +616,44 @@ impl BatchState {
         8 + // start_time
         8 + // end_time
         32 + // order_root
+        32 * ORDER_TREE_DEPTH + // order_frontier
         4 + // order_count
         1 + // state
         8 + // clearing_price
+        4 + // claimed
         1; // bump
+
+    /// Whether the fill for order slot `slot` has been claimed
+    pub fn is_claimed(&self, slot: usize) -> bool {
+        self.claimed & (1 << slot) != 0
+    }
+
+    /// Append an order commitment to the batch's tree and update its root
+    pub fn append_order(&mut self, commitment: &[u8; 32]) -> [u8; 32] {
+        self.order_root = merkle::insert(&mut self.order_frontier, self.order_count, commitment);
+        self.order_root
+    }
+
+    /// Recompute the commitment tree from the batch's remaining orders
+    pub fn rebuild_order_tree(&mut self, orders: &[QueuedOrder]) {
+        self.order_frontier = [[0; 32]; ORDER_TREE_DEPTH];
+        self.order_root = merkle::empty_root();
+        for (i, queued) in orders.iter().enumerate() {
+            self.order_root =
+                merkle::insert(&mut self.order_frontier, i as u32, &queued.order.commitment);
+        }
+    }
+
+    /// Record the batch's uniform clearing price and mark it cleared
+    pub fn record_clear(&mut self, clearing_price: u64) -> Result<()> {
+        require!(
+            self.state != BatchStateEnum::Cleared,
+            MarketError::BatchAlreadyCleared
+        );
+        self.state = BatchStateEnum::Cleared;
+        self.clearing_price = clearing_price;
+        Ok(())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -222,36 +661,121 @@ pub enum BatchStateEnum {
     Open,
     Closed,
     Cleared,
+    /// A `batch_clear` computation is in flight
+    Clearing,
 }
 
-/// User position tracking (optional, for easier UX)
+/// A trader's encrypted holdings in one market (`Enc<Shared, PositionState>`).
+/// Updated inside MPC on every fill; only the trader can decrypt it.
 #[account]
 pub struct UserPosition {
     /// User pubkey
     pub authority: Pubkey,
-    
+
     /// Market
     pub market: Pubkey,
-    
-    /// YES tokens held
-    pub yes_amount: u64,
-    
-    /// NO tokens held
-    pub no_amount: u64,
-    
-    /// Total collateral deposited
-    pub collateral_deposited: u64,
-    
+
+    /// Trader's x25519 public key the position is encrypted to
+    pub encryption_pubkey: [u8; 32],
+
+    /// Nonce of the current ciphertexts
+    pub nonce: u128,
+
+    /// Encrypted [yes_shares, no_shares, cost_basis]
+    pub encrypted_position: [[u8; 32]; 3],
+
+    /// Set once the MXE has written the initial position
+    pub initialized: bool,
+
     /// Bump seed
     pub bump: u8,
+
+    /// Set while a queued trade is working from this position
+    pub pending: bool,
+
+    /// Nonce of the ciphertexts the pending trade read
+    pub pending_nonce: u128,
+
+    /// Collateral escrowed in the vault for the pending trade
+    pub pending_escrow: u64,
 }
 
 impl UserPosition {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
         32 + // market
-        8 + // yes_amount
-        8 + // no_amount
-        8 + // collateral_deposited
-        1; // bump
+        32 + // encryption_pubkey
+        16 + // nonce
+        32 * 3 + // encrypted_position
+        1 + // initialized
+        1 + // bump
+        1 + // pending
+        16 + // pending_nonce
+        8; // pending_escrow
+
+    /// Lock the position for a trade about to be queued, recording the
+    /// collateral it escrowed
+    pub fn begin_computation(&mut self, escrow: u64) -> Result<()> {
+        require!(!self.pending, MarketError::ComputationPending);
+        self.pending = true;
+        self.pending_nonce = self.nonce;
+        self.pending_escrow = escrow;
+        Ok(())
+    }
+
+    /// Release the lock from `begin_computation` once its callback lands,
+    /// returning the escrow recorded for the trade
+    pub fn end_computation(&mut self) -> Result<u64> {
+        require!(
+            self.pending && self.pending_nonce == self.nonce,
+            MarketError::StaleComputation
+        );
+        self.pending = false;
+        Ok(std::mem::take(&mut self.pending_escrow))
+    }
+
+    /// Store a new encrypted position
+    pub fn set_state(&mut self, nonce: u128, ciphertexts: [[u8; 32]; 3]) {
+        self.nonce = nonce;
+        self.encrypted_position = ciphertexts;
+        self.initialized = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn winning_side_redeems_in_full() {
+        assert_eq!(outcome_payout(Some(OUTCOME_YES), true, 7).unwrap(), 7);
+        assert_eq!(outcome_payout(Some(OUTCOME_YES), false, 7).unwrap(), 0);
+        assert_eq!(outcome_payout(Some(OUTCOME_NO), true, 7).unwrap(), 0);
+        assert_eq!(outcome_payout(Some(OUTCOME_NO), false, 7).unwrap(), 7);
+    }
+
+    #[test]
+    fn invalid_market_redeems_half_rounded_down() {
+        assert_eq!(outcome_payout(Some(OUTCOME_INVALID), true, 8).unwrap(), 4);
+        assert_eq!(outcome_payout(Some(OUTCOME_INVALID), false, 8).unwrap(), 4);
+
+        // An odd amount rounds down on each side, so a complete set of 7
+        // pays 6 and the vault keeps the odd unit
+        let yes = outcome_payout(Some(OUTCOME_INVALID), true, 7).unwrap();
+        let no = outcome_payout(Some(OUTCOME_INVALID), false, 7).unwrap();
+        assert_eq!((yes, no), (3, 3));
+        assert!(yes + no <= 7);
+    }
+
+    #[test]
+    fn invalid_market_single_token_pays_nothing() {
+        assert_eq!(outcome_payout(Some(OUTCOME_INVALID), true, 1).unwrap(), 0);
+        assert_eq!(outcome_payout(Some(OUTCOME_INVALID), false, 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn rejects_unresolved_or_unknown_outcome() {
+        assert!(outcome_payout(None, true, 1).is_err());
+        assert!(outcome_payout(Some(OUTCOME_INVALID + 1), true, 1).is_err());
+    }
 }
@@ -12,6 +12,18 @@ import {
   getAssociatedTokenAddress,
   createAssociatedTokenAccountInstruction,
 } from "@solana/spl-token";
+import {
+  getArciumAccountBaseSeed,
+  getArciumProgAddress,
+  getClockAccAddress,
+  getClusterAccAddress,
+  getCompDefAccOffset,
+  getComputationAccAddress,
+  getExecutingPoolAccAddress,
+  getFeePoolAccAddress,
+  getMempoolAccAddress,
+  getMXEAccAddress,
+} from "@arcium-hq/client";
 
 // Constants
 const MARKET_SEED = Buffer.from("market");
@@ -19,21 +31,52 @@ const VAULT_SEED = Buffer.from("vault");
 const FEE_VAULT_SEED = Buffer.from("fee_vault");
 const YES_MINT_SEED = Buffer.from("yes_mint");
 const NO_MINT_SEED = Buffer.from("no_mint");
+const LP_MINT_SEED = Buffer.from("lp_mint");
+const POSITION_SEED = Buffer.from("position");
 const RESOLVER_SEED = Buffer.from("resolver");
 const BATCH_SEED = Buffer.from("batch");
+const ORDER_QUEUE_SEED = Buffer.from("order_queue");
+const RESERVES_SEED = Buffer.from("reserves");
+const VOTES_SEED = Buffer.from("votes");
+const CONFIG_SEED = Buffer.from("config");
+const SIGN_PDA_SEED = Buffer.from("SignerAccount");
 
 export interface MarketConfig {
+  marketId: number;
   question: string;
   endTime: Date;
   feeBps: number;
   batchInterval: number; // seconds
   resolverQuorum: number;
+  pricingCurve?: "CPMM" | "LMSR"; // defaults to CPMM
+  lmsrB?: number; // LMSR liquidity parameter, required for LMSR markets
+}
+
+/**
+ * A private trade encrypted to the MXE with Arcium's RescueCipher. The three
+ * ciphertexts are, in order, the amount, the side (1 for YES) and the max
+ * price (scaled by 1e6).
+ */
+export interface EncryptedTrade {
+  ciphertexts: [number[], number[], number[]];
+  nonce: BN;
+  clientPubkey: number[];
+}
+
+/**
+ * A sealed batch order. `ciphertexts` encrypt the amount, side and limit
+ * price to the MXE; `amount` is the public escrow.
+ */
+export interface BatchOrderData {
+  commitment: number[];
+  amount: BN;
+  encryptionPubkey: number[];
+  nonce: BN;
+  ciphertexts: number[][];
 }
 
-export interface TradeOrder {
-  side: "YES" | "NO";
-  amount: number;
-  slippage: number;
+export interface ClientOptions {
+  arciumClusterOffset?: number; // Arcium cluster the program's MXE runs on
 }
 
 export interface MarketState {
@@ -43,28 +86,29 @@ export interface MarketState {
   feeBps: number;
   batchInterval: BN;
   nextBatchClear: BN;
+  batchStart: BN;
   resolverQuorum: number;
   resolverCount: number;
-  yesReserves: BN;
-  noReserves: BN;
+  publicPrice: BN;
   totalVolume: BN;
   resolutionState: any;
-  finalOutcome: number;
+  finalOutcome: number | null;
 }
 
 export class PrivateMarketsClient {
   constructor(
     private connection: Connection,
     private program: Program,
-    private wallet: any
+    private wallet: any,
+    private options: ClientOptions = {}
   ) {}
 
   /**
-   * Derive market PDA
+   * Derive market PDA (scoped by creator and market id)
    */
-  getMarketPDA(authority: PublicKey): [PublicKey, number] {
+  getMarketPDA(authority: PublicKey, marketId: number | BN): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
-      [MARKET_SEED, authority.toBuffer()],
+      [MARKET_SEED, authority.toBuffer(), new BN(marketId).toArrayLike(Buffer, "le", 8)],
       this.program.programId
     );
   }
@@ -77,6 +121,7 @@ export class PrivateMarketsClient {
     feeVault: [PublicKey, number];
     yesMint: [PublicKey, number];
     noMint: [PublicKey, number];
+    lpMint: [PublicKey, number];
   } {
     return {
       collateralVault: PublicKey.findProgramAddressSync(
@@ -95,17 +140,128 @@ export class PrivateMarketsClient {
         [NO_MINT_SEED, marketPubkey.toBuffer()],
         this.program.programId
       ),
+      lpMint: PublicKey.findProgramAddressSync(
+        [LP_MINT_SEED, marketPubkey.toBuffer()],
+        this.program.programId
+      ),
+    };
+  }
+
+  /**
+   * Derive a trader's encrypted position PDA for a market
+   */
+  getPositionPDA(marketPubkey: PublicKey, owner: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [POSITION_SEED, marketPubkey.toBuffer(), owner.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Derive the batch auction PDA for a market epoch
+   */
+  getBatchPDA(marketPubkey: PublicKey, epoch: number | BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [BATCH_SEED, marketPubkey.toBuffer(), new BN(epoch).toArrayLike(Buffer, "le", 8)],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Derive the encrypted order queue PDA for a batch
+   */
+  getOrderQueuePDA(batchPubkey: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [ORDER_QUEUE_SEED, batchPubkey.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Derive the encrypted pool reserves PDA for a market
+   */
+  getReservesPDA(marketPubkey: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [RESERVES_SEED, marketPubkey.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Derive the encrypted resolver votes PDA for a market
+   */
+  getVotesPDA(marketPubkey: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [VOTES_SEED, marketPubkey.toBuffer()],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Derive the protocol config PDA
+   */
+  getConfigPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync([CONFIG_SEED], this.program.programId);
+  }
+
+  /**
+   * Accounts every instruction that queues an Arcium computation needs
+   */
+  getArciumAccounts(computationDefinition: string, computationOffset: BN) {
+    if (this.options.arciumClusterOffset === undefined) {
+      throw new Error("arciumClusterOffset must be set to queue computations");
+    }
+    const programId = this.program.programId;
+    const arciumProgram = getArciumProgAddress();
+    return {
+      signPdaAccount: PublicKey.findProgramAddressSync([SIGN_PDA_SEED], programId)[0],
+      mxeAccount: getMXEAccAddress(programId),
+      mempoolAccount: getMempoolAccAddress(programId),
+      executingPool: getExecutingPoolAccAddress(programId),
+      computationAccount: getComputationAccAddress(programId, computationOffset),
+      compDefAccount: PublicKey.findProgramAddressSync(
+        [
+          getArciumAccountBaseSeed("ComputationDefinitionAccount"),
+          programId.toBuffer(),
+          getCompDefAccOffset(computationDefinition),
+        ],
+        arciumProgram
+      )[0],
+      clusterAccount: getClusterAccAddress(this.options.arciumClusterOffset),
+      poolAccount: getFeePoolAccAddress(),
+      clockAccount: getClockAccAddress(),
+      systemProgram: SystemProgram.programId,
+      arciumProgram,
     };
   }
 
   /**
-   * Create a new prediction market
+   * Get the clearing state of a past or current batch epoch
+   */
+  async getBatchState(marketPubkey: PublicKey, epoch: number | BN) {
+    const [batchPda] = this.getBatchPDA(marketPubkey, epoch);
+    return this.program.account.batchState.fetch(batchPda);
+  }
+
+  /**
+   * Batch epoch accepting orders at `now` (unix seconds). Epoch k covers
+   * [batchStart + k * batchInterval, batchStart + (k + 1) * batchInterval).
+   */
+  getEpochAt(market: MarketState, now: number = Math.floor(Date.now() / 1000)): BN {
+    const elapsed = Math.max(0, now - market.batchStart.toNumber());
+    return new BN(Math.floor(elapsed / market.batchInterval.toNumber()));
+  }
+
+  /**
+   * Create a new prediction market. LMSR markets are funded with the
+   * `b * ln 2` subsidy from `creatorCollateral`.
    */
   async createMarket(
     config: MarketConfig,
-    collateralMint: PublicKey
+    collateralMint: PublicKey,
+    creatorCollateral?: PublicKey
   ): Promise<{ signature: string; marketPubkey: PublicKey }> {
-    const [marketPubkey] = this.getMarketPDA(this.wallet.publicKey);
+    const [marketPubkey] = this.getMarketPDA(this.wallet.publicKey, config.marketId);
     const pdas = this.getVaultPDAs(marketPubkey);
 
     const endTime = new BN(Math.floor(config.endTime.getTime() / 1000));
@@ -113,11 +269,14 @@ export class PrivateMarketsClient {
 
     const tx = await this.program.methods
       .createMarket(
+        new BN(config.marketId),
         config.question,
         endTime,
         config.feeBps,
         batchInterval,
-        config.resolverQuorum
+        config.resolverQuorum,
+        config.pricingCurve === "LMSR" ? { lmsr: {} } : { cpmm: {} },
+        new BN(config.lmsrB ?? 0)
       )
       .accounts({
         market: marketPubkey,
@@ -125,8 +284,11 @@ export class PrivateMarketsClient {
         feeVault: pdas.feeVault[0],
         yesMint: pdas.yesMint[0],
         noMint: pdas.noMint[0],
+        lpMint: pdas.lpMint[0],
         collateralMint,
+        config: this.getConfigPDA()[0],
         authority: this.wallet.publicKey,
+        creatorCollateral: creatorCollateral ?? null,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
         rent: SYSVAR_RENT_PUBKEY,
@@ -165,21 +327,92 @@ export class PrivateMarketsClient {
   }
 
   /**
-   * Submit a private trade order
-   * Note: In production, this would encrypt the order using Arcium SDK
+   * Create a market's encrypted pool reserves. Required once, by anyone,
+   * before the market accepts private trades, liquidity or batch clears.
+   */
+  async initializeReserves(
+    marketPubkey: PublicKey,
+    computationOffset: BN,
+    nonce: BN
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .initializeReserves(computationOffset, nonce)
+      .accounts({
+        payer: this.wallet.publicKey,
+        ...this.getArciumAccounts("init_cfmm_state", computationOffset),
+        market: marketPubkey,
+        reserves: this.getReservesPDA(marketPubkey)[0],
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Open the caller's encrypted position for a market, encrypted to their
+   * x25519 key. Required once before their first private trade.
+   */
+  async openPosition(
+    marketPubkey: PublicKey,
+    computationOffset: BN,
+    encryptionPubkey: number[],
+    nonce: BN
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .openPosition(computationOffset, encryptionPubkey, nonce)
+      .accounts({
+        payer: this.wallet.publicKey,
+        ...this.getArciumAccounts("init_position", computationOffset),
+        market: marketPubkey,
+        position: this.getPositionPDA(marketPubkey, this.wallet.publicKey)[0],
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Submit an encrypted trade against a CPMM market's pool. `escrowAmount`
+   * collateral is escrowed until the callback mints the shares and refunds
+   * the rest. The trader's position must already be open.
    */
   async submitPrivateTrade(
     marketPubkey: PublicKey,
-    order: TradeOrder
+    computationOffset: BN,
+    trade: EncryptedTrade,
+    escrowAmount: BN,
+    tokenAccounts: {
+      userCollateral: PublicKey;
+      userYesTokens: PublicKey;
+      userNoTokens: PublicKey;
+    }
   ): Promise<string> {
-    // Mock encryption - in production, use Arcium encryption
-    const encryptedOrder = Buffer.from(JSON.stringify(order));
+    const pdas = this.getVaultPDAs(marketPubkey);
+    const [amount, side, maxPrice] = trade.ciphertexts;
 
     const tx = await this.program.methods
-      .submitPrivateTrade(encryptedOrder)
+      .submitPrivateTrade(
+        computationOffset,
+        amount,
+        side,
+        maxPrice,
+        trade.nonce,
+        trade.clientPubkey,
+        escrowAmount
+      )
       .accounts({
+        payer: this.wallet.publicKey,
+        ...this.getArciumAccounts("private_trade", computationOffset),
         market: marketPubkey,
-        user: this.wallet.publicKey,
+        config: this.getConfigPDA()[0],
+        reserves: this.getReservesPDA(marketPubkey)[0],
+        position: this.getPositionPDA(marketPubkey, this.wallet.publicKey)[0],
+        collateralVault: pdas.collateralVault[0],
+        feeVault: pdas.feeVault[0],
+        yesMint: pdas.yesMint[0],
+        noMint: pdas.noMint[0],
+        ...tokenAccounts,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc();
 
@@ -187,17 +420,32 @@ export class PrivateMarketsClient {
   }
 
   /**
-   * Submit a batch order
+   * Queue a sealed order into the batch for `epoch`, escrowing its amount
+   * plus the trading fee. `epoch` must be the current one (see
+   * `getEpochAt`); an order that lands after its window ends fails with
+   * `WrongEpoch` and should be resubmitted for the new epoch.
    */
   async submitBatchOrder(
     marketPubkey: PublicKey,
-    orderCommitment: number[]
+    epoch: BN,
+    order: BatchOrderData,
+    payerCollateral: PublicKey
   ): Promise<string> {
+    const pdas = this.getVaultPDAs(marketPubkey);
+    const [batch] = this.getBatchPDA(marketPubkey, epoch);
+
     const tx = await this.program.methods
-      .submitBatchOrder(orderCommitment)
+      .submitBatchOrder(epoch, order)
       .accounts({
         market: marketPubkey,
-        user: this.wallet.publicKey,
+        config: this.getConfigPDA()[0],
+        batch,
+        orderQueue: this.getOrderQueuePDA(batch)[0],
+        collateralVault: pdas.collateralVault[0],
+        payerCollateral,
+        payer: this.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
@@ -236,11 +484,17 @@ export class PrivateMarketsClient {
   }
 
   /**
-   * Submit attestation for market resolution
+   * Submit a resolver's encrypted vote once the market has ended.
+   * `attestation` is the vote (1 for YES, 0 for NO) encrypted to the MXE
+   * under `encryptionPubkey` and `nonce`. The vote that reaches quorum
+   * queues the `resolve_market` computation at `computationOffset`.
    */
   async submitAttestation(
     marketPubkey: PublicKey,
-    encryptedAttestation: Buffer
+    computationOffset: BN,
+    encryptionPubkey: number[],
+    nonce: BN,
+    attestation: number[]
   ): Promise<string> {
     const [resolverPda] = PublicKey.findProgramAddressSync(
       [RESOLVER_SEED, marketPubkey.toBuffer(), this.wallet.publicKey.toBuffer()],
@@ -248,10 +502,13 @@ export class PrivateMarketsClient {
     );
 
     const tx = await this.program.methods
-      .submitAttestation(encryptedAttestation)
+      .submitAttestation(computationOffset, encryptionPubkey, nonce, attestation)
       .accounts({
+        payer: this.wallet.publicKey,
+        ...this.getArciumAccounts("resolve_market", computationOffset),
         market: marketPubkey,
         resolver: resolverPda,
+        votes: this.getVotesPDA(marketPubkey)[0],
         authority: this.wallet.publicKey,
       })
       .rpc();
@@ -259,6 +516,25 @@ export class PrivateMarketsClient {
     return tx;
   }
 
+  /**
+   * Queue the `resolve_market` computation again at `computationOffset`
+   * after a failed run returned the market to awaiting attestations.
+   * Anyone may call it once the stored votes reach quorum.
+   */
+  async retryResolution(marketPubkey: PublicKey, computationOffset: BN): Promise<string> {
+    const tx = await this.program.methods
+      .retryResolution(computationOffset)
+      .accounts({
+        payer: this.wallet.publicKey,
+        ...this.getArciumAccounts("resolve_market", computationOffset),
+        market: marketPubkey,
+        votes: this.getVotesPDA(marketPubkey)[0],
+      })
+      .rpc();
+
+    return tx;
+  }
+
   /**
    * Redeem winning tokens for collateral
    */
@@ -297,56 +573,18 @@ export class PrivateMarketsClient {
   }
 
   /**
-   * Get current price for a side (YES or NO)
-   * Uses CPMM formula: price = reserves_other / (reserves_yes + reserves_no)
+   * Get the last revealed price for a side (YES or NO)
+   * Pool reserves are encrypted; only the coarse YES price revealed at each
+   * batch clear is public. Returns null before the first clear.
    */
-  getCurrentPrice(market: MarketState, side: "YES" | "NO"): number {
-    const yesReserves = market.yesReserves.toNumber();
-    const noReserves = market.noReserves.toNumber();
-    const totalReserves = yesReserves + noReserves;
-
-    if (side === "YES") {
-      return noReserves / totalReserves;
-    } else {
-      return yesReserves / totalReserves;
+  getCurrentPrice(market: MarketState, side: "YES" | "NO"): number | null {
+    const publicPrice = market.publicPrice.toNumber();
+    if (publicPrice === 0) {
+      return null;
     }
-  }
-
-  /**
-   * Calculate expected outcome tokens for a trade
-   * Using CPMM: k = x * y, where k is constant
-   */
-  calculateExpectedTokens(
-    market: MarketState,
-    side: "YES" | "NO",
-    collateralAmount: number
-  ): { tokens: number; newPrice: number } {
-    const yesReserves = market.yesReserves.toNumber();
-    const noReserves = market.noReserves.toNumber();
-    const k = yesReserves * noReserves;
-
-    let newYesReserves: number;
-    let newNoReserves: number;
-    let tokens: number;
-
-    if (side === "YES") {
-      // Buying YES: add collateral to NO reserves, remove from YES reserves
-      newNoReserves = noReserves + collateralAmount;
-      newYesReserves = k / newNoReserves;
-      tokens = yesReserves - newYesReserves;
-    } else {
-      // Buying NO: add collateral to YES reserves, remove from NO reserves
-      newYesReserves = yesReserves + collateralAmount;
-      newNoReserves = k / newYesReserves;
-      tokens = noReserves - newNoReserves;
-    }
-
-    const newPrice =
-      side === "YES"
-        ? newNoReserves / (newYesReserves + newNoReserves)
-        : newYesReserves / (newYesReserves + newNoReserves);
 
-    return { tokens, newPrice };
+    const yesPrice = publicPrice / 1e6;
+    return side === "YES" ? yesPrice : 1 - yesPrice;
   }
 
   /**
@@ -362,9 +600,10 @@ export class PrivateMarketsClient {
 export function createClient(
   connection: Connection,
   program: Program,
-  wallet: any
+  wallet: any,
+  options: ClientOptions = {}
 ): PrivateMarketsClient {
-  return new PrivateMarketsClient(connection, program, wallet);
+  return new PrivateMarketsClient(connection, program, wallet, options);
 }
 
 export * from "@solana/web3.js";
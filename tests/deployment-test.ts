@@ -52,15 +52,16 @@ describe("Deployment Verification - Devnet", () => {
     console.log("✅ Collateral mint created:", collateralMint.toString());
 
     // Derive market PDA
+    const marketId = new anchor.BN(0);
     const [marketPda, marketBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("market"), marketAuthority.publicKey.toBuffer()],
+      [Buffer.from("market"), marketAuthority.publicKey.toBuffer(), marketId.toArrayLike(Buffer, "le", 8)],
       program.programId
     );
     console.log("Market PDA:", marketPda.toString());
 
     // Derive vault PDAs
     const [collateralVault] = PublicKey.findProgramAddressSync(
-      [Buffer.from("collateral_vault"), marketPda.toBuffer()],
+      [Buffer.from("vault"), marketPda.toBuffer()],
       program.programId
     );
 
@@ -69,24 +70,20 @@ describe("Deployment Verification - Devnet", () => {
       program.programId
     );
 
-    // Create outcome token mints
-    console.log("Creating outcome token mints...");
-    const yesMint = await createMint(
-      provider.connection,
-      marketAuthority,
-      marketAuthority.publicKey,
-      null,
-      6
+    const [yesMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("yes_mint"), marketPda.toBuffer()],
+      program.programId
     );
 
-    const noMint = await createMint(
-      provider.connection,
-      marketAuthority,
-      marketAuthority.publicKey,
-      null,
-      6
+    const [noMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("no_mint"), marketPda.toBuffer()],
+      program.programId
+    );
+
+    const [lpMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("lp_mint"), marketPda.toBuffer()],
+      program.programId
     );
-    console.log("✅ Outcome mints created");
 
     // Create market
     console.log("Creating market...");
@@ -95,11 +92,14 @@ describe("Deployment Verification - Devnet", () => {
     try {
       const tx = await program.methods
         .createMarket(
+          marketId,
           "Will BTC reach $100k by EOY?",
           new anchor.BN(currentTime + 86400), // end_time: 24 hours from now
           100, // fee_bps: 1%
           new anchor.BN(3600), // batch_interval: 1 hour
           3, // resolver_quorum: 3 resolvers needed
+          { cpmm: {} }, // pricing_curve
+          new anchor.BN(0), // lmsr_b: unused for CPMM
         )
         .accounts({
           market: marketPda,
@@ -107,8 +107,13 @@ describe("Deployment Verification - Devnet", () => {
           collateralMint: collateralMint,
           yesMint: yesMint,
           noMint: noMint,
+          lpMint: lpMint,
           collateralVault: collateralVault,
           feeVault: feeVault,
+          config: PublicKey.findProgramAddressSync(
+            [Buffer.from("config")],
+            program.programId
+          )[0],
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -11,6 +11,7 @@ import {
 import { assert } from "chai";
 import { randomBytes } from "crypto";
 import {
+  awaitComputationFinalization,
   getArciumAccountBaseSeed,
   getArciumProgAddress,
   getComputationAccAddress,
@@ -37,6 +38,93 @@ function deriveSignPda(): PublicKey {
   return pda;
 }
 
+// Derive market PDA for a creator-scoped market id
+function deriveMarketPda(authority: PublicKey, marketId: BN): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("market"), authority.toBuffer(), marketId.toArrayLike(Buffer, "le", 8)],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+// Derive the collateral vault, fee vault, outcome mints and LP mint for a market
+function deriveMarketAccounts(market: PublicKey) {
+  const derive = (seed: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from(seed), market.toBuffer()],
+      PROGRAM_ID
+    )[0];
+  return {
+    collateralVault: derive("vault"),
+    feeVault: derive("fee_vault"),
+    yesMint: derive("yes_mint"),
+    noMint: derive("no_mint"),
+    lpMint: derive("lp_mint"),
+  };
+}
+
+// Derive the encrypted reserves PDA for a market
+function deriveReservesPda(market: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("reserves"), market.toBuffer()],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+// Derive a trader's encrypted position PDA for a market
+function derivePositionPda(market: PublicKey, owner: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("position"), market.toBuffer(), owner.toBuffer()],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+// Derive the batch auction PDA for a market epoch
+function deriveBatchPda(market: PublicKey, epoch: BN): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("batch"), market.toBuffer(), epoch.toArrayLike(Buffer, "le", 8)],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+// Derive the encrypted order queue PDA for a batch
+function deriveVotesPda(market: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("votes"), market.toBuffer()],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+function deriveOrderQueuePda(batch: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("order_queue"), batch.toBuffer()],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+// Derive the singleton protocol config PDA
+function deriveConfigPda(): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+// Program data account holding the program's upgrade authority
+function deriveProgramDataPda(): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [PROGRAM_ID.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+  return pda;
+}
+
 // Helper for random bytes
 function randomBytes32() {
   return Array.from(randomBytes(32));
@@ -44,11 +132,62 @@ function randomBytes32() {
 
 const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
 
+type CompDefType =
+  | "private_trade"
+  | "lmsr_trade"
+  | "batch_clear"
+  | "resolve_market"
+  | "init_cfmm_state"
+  | "add_liquidity"
+  | "remove_liquidity"
+  | "init_position";
+
+// Arcium accounts shared by every queued computation
+function arciumQueueAccounts(compDefType: CompDefType, computationOffset: BN) {
+  const baseSeed = getArciumAccountBaseSeed("ComputationDefinitionAccount");
+  const compDefOffset = getCompDefAccOffset(compDefType);
+  return {
+    signPdaAccount: deriveSignPda(),
+    mxeAccount: getMXEAccAddress(PROGRAM_ID),
+    mempoolAccount: getMempoolAccAddress(PROGRAM_ID),
+    executingPool: getExecutingPoolAccAddress(PROGRAM_ID),
+    computationAccount: getComputationAccAddress(PROGRAM_ID, computationOffset),
+    compDefAccount: PublicKey.findProgramAddressSync(
+      [baseSeed, PROGRAM_ID.toBuffer(), compDefOffset],
+      ARCIUM_PROGRAM_ID
+    )[0],
+    clusterAccount: getClusterAccAddress(ARCIUM_CLUSTER_OFFSET),
+    poolAccount: getFeePoolAccAddress(),
+    clockAccount: getClockAccAddress(),
+    systemProgram: SystemProgram.programId,
+    arciumProgram: ARCIUM_PROGRAM_ID,
+  };
+}
+
+// Queue init_cfmm_state for a market and wait for the encrypted reserves
+async function initializeReserves(
+  provider: anchor.AnchorProvider,
+  program: Program<PrivateMarkets>,
+  market: PublicKey
+) {
+  const computationOffset = new BN(randomBytes(8), "le");
+  await program.methods
+    .initializeReserves(computationOffset, new BN(randomBytes(16), "le"))
+    .accounts({
+      payer: provider.wallet.publicKey,
+      ...arciumQueueAccounts("init_cfmm_state", computationOffset),
+      market,
+      reserves: deriveReservesPda(market),
+    })
+    .rpc();
+  await awaitComputationFinalization(provider, computationOffset, PROGRAM_ID, "confirmed");
+}
+
 // Ensure the computation definitions exist on the current cluster.
 async function ensureComputationDefinition(
   provider: anchor.AnchorProvider,
   program: Program<PrivateMarkets>,
-  compDefType: "private_trade" | "batch_clear" | "resolve_market"
+  compDefType: CompDefType
 ) {
   const baseSeed = getArciumAccountBaseSeed("ComputationDefinitionAccount");
   const compDefOffset = getCompDefAccOffset(compDefType);
@@ -93,12 +232,62 @@ describe("private-markets", () => {
   let feeVault: PublicKey;
   let yesMint: PublicKey;
   let noMint: PublicKey;
+  let lpMint: PublicKey;
+  let configPda: PublicKey;
+  let mpcAuthority: Keypair;
+  let splitUser: Keypair;
+  let splitUserCollateral: PublicKey;
+  let splitUserYes: PublicKey;
+  let splitUserNo: PublicKey;
+  // Short-window market whose first epoch ends while the rest of the suite runs
+  let epochMarketPda: PublicKey;
+  let epochMarketAccounts: ReturnType<typeof deriveMarketAccounts>;
+  let epochUser: Keypair;
+  let epochUserCollateral: PublicKey;
+
+  // Mirrors the SDK's getEpochAt, on the validator's clock
+  const epochAt = async () => {
+    const market = await program.account.market.fetch(epochMarketPda);
+    const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+    const elapsed = Math.max(0, now - market.batchStart.toNumber());
+    return new BN(Math.floor(elapsed / market.batchInterval.toNumber()));
+  };
+
+  const submitEpochOrder = (epoch: BN) => {
+    const batch = deriveBatchPda(epochMarketPda, epoch);
+    return program.methods
+      .submitBatchOrder(epoch, {
+        commitment: randomBytes32(),
+        amount: new BN(1e6),
+        encryptionPubkey: randomBytes32(),
+        nonce: new BN(randomBytes(16), "le"),
+        ciphertexts: [randomBytes32(), randomBytes32(), randomBytes32()],
+      })
+      .accounts({
+        market: epochMarketPda,
+        config: configPda,
+        batch,
+        orderQueue: deriveOrderQueuePda(batch),
+        collateralVault: epochMarketAccounts.collateralVault,
+        payerCollateral: epochUserCollateral,
+        payer: epochUser.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([epochUser])
+      .rpc();
+  };
 
   before(async () => {
     // Initialize computation definitions for the cluster
     await ensureComputationDefinition(provider as anchor.AnchorProvider, program, "private_trade");
+    await ensureComputationDefinition(provider as anchor.AnchorProvider, program, "lmsr_trade");
     await ensureComputationDefinition(provider as anchor.AnchorProvider, program, "batch_clear");
     await ensureComputationDefinition(provider as anchor.AnchorProvider, program, "resolve_market");
+    await ensureComputationDefinition(provider as anchor.AnchorProvider, program, "init_cfmm_state");
+    await ensureComputationDefinition(provider as anchor.AnchorProvider, program, "add_liquidity");
+    await ensureComputationDefinition(provider as anchor.AnchorProvider, program, "remove_liquidity");
+    await ensureComputationDefinition(provider as anchor.AnchorProvider, program, "init_position");
 
     // Create market authority
     marketAuthority = Keypair.generate();
@@ -120,6 +309,67 @@ describe("private-markets", () => {
     );
 
     console.log("Collateral mint:", collateralMint.toString());
+
+    // Initialize the protocol config (provider wallet is admin and treasury)
+    // and allow the test collateral mint
+    configPda = deriveConfigPda();
+    mpcAuthority = Keypair.generate();
+    const configInfo = await provider.connection.getAccountInfo(configPda);
+    if (!configInfo) {
+      // Only the upgrade authority may claim the config
+      const squatter = Keypair.generate();
+      const airdrop = await provider.connection.requestAirdrop(
+        squatter.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+      try {
+        await program.methods
+          .initializeConfig(squatter.publicKey, squatter.publicKey, 1000, [collateralMint])
+          .accounts({
+            config: configPda,
+            admin: squatter.publicKey,
+            program: PROGRAM_ID,
+            programData: deriveProgramDataPda(),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([squatter])
+          .rpc();
+        assert.fail("initializeConfig should require the upgrade authority");
+      } catch (err) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+
+      await program.methods
+        .initializeConfig(
+          provider.wallet.publicKey,
+          mpcAuthority.publicKey,
+          1000,
+          [collateralMint]
+        )
+        .accounts({
+          config: configPda,
+          admin: provider.wallet.publicKey,
+          program: PROGRAM_ID,
+          programData: deriveProgramDataPda(),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    } else {
+      await program.methods
+        .updateConfig(
+          provider.wallet.publicKey,
+          mpcAuthority.publicKey,
+          1000,
+          [collateralMint],
+          false
+        )
+        .accounts({
+          config: configPda,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+    }
   });
 
   it("Creates a prediction market", async () => {
@@ -130,57 +380,33 @@ describe("private-markets", () => {
     const resolverQuorum = 3;
 
     // Derive market PDA
-    [marketPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("market"), marketAuthority.publicKey.toBuffer()],
-      program.programId
-    );
-
-    // Create vault token accounts (owned by market PDA)
-    const collateralVaultAccount = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      marketAuthority,
-      collateralMint,
-      marketPda,
-      true // allowOwnerOffCurve for PDA
-    );
-    collateralVault = collateralVaultAccount.address;
-
-    // Fee vault owned by authority (for fee collection)
-    const feeVaultAccount = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      marketAuthority,
-      collateralMint,
-      marketAuthority.publicKey
-    );
-    feeVault = feeVaultAccount.address;
+    const marketId = new BN(0);
+    marketPda = deriveMarketPda(marketAuthority.publicKey, marketId);
 
-    // Create outcome token mints
-    yesMint = await createMint(
-      provider.connection,
-      marketAuthority,
-      marketPda, // mint authority is market PDA
-      null,
-      6
-    );
-
-    noMint = await createMint(
-      provider.connection,
-      marketAuthority,
-      marketPda,
-      null,
-      6
-    );
+    // Vaults and outcome mints are PDAs initialized by create_market
+    ({ collateralVault, feeVault, yesMint, noMint, lpMint } = deriveMarketAccounts(marketPda));
 
     // Create market
     const tx = await program.methods
-      .createMarket(question, endTime, feeBps, batchInterval, resolverQuorum)
+      .createMarket(
+        marketId,
+        question,
+        endTime,
+        feeBps,
+        batchInterval,
+        resolverQuorum,
+        { cpmm: {} },
+        new BN(0)
+      )
       .accounts({
         market: marketPda,
         collateralVault,
         feeVault,
         yesMint,
         noMint,
+        lpMint,
         collateralMint,
+        config: configPda,
         authority: marketAuthority.publicKey,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -203,6 +429,476 @@ describe("private-markets", () => {
     console.log("Market state verified");
   });
 
+  it("Initializes the encrypted pool reserves", async () => {
+    await initializeReserves(provider as anchor.AnchorProvider, program, marketPda);
+
+    const reserves = await program.account.encryptedReserves.fetch(deriveReservesPda(marketPda));
+    assert.isTrue(reserves.initialized);
+    assert.isTrue(reserves.market.equals(marketPda));
+
+    const marketAccount = await program.account.market.fetch(marketPda);
+    assert.notDeepEqual(marketAccount.cfmmStateCommitment, new Array(32).fill(0));
+    assert.equal(marketAccount.publicPrice.toNumber(), 0);
+  });
+
+  it("Creates a second market from the same authority", async () => {
+    const marketId = new BN(1);
+    const secondMarketPda = deriveMarketPda(marketAuthority.publicKey, marketId);
+    assert.notEqual(secondMarketPda.toBase58(), marketPda.toBase58());
+
+    const secondAccounts = deriveMarketAccounts(secondMarketPda);
+
+    await program.methods
+      .createMarket(
+        marketId,
+        "Will SOL flip ETH by EOY 2025?",
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400 * 30),
+        100,
+        new anchor.BN(3600),
+        3,
+        { cpmm: {} },
+        new BN(0)
+      )
+      .accounts({
+        market: secondMarketPda,
+        ...secondAccounts,
+        collateralMint,
+        config: configPda,
+        authority: marketAuthority.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([marketAuthority])
+      .rpc();
+
+    const secondMarket = await program.account.market.fetch(secondMarketPda);
+    assert.equal(secondMarket.marketId.toNumber(), 1);
+    assert.equal(
+      secondMarket.authority.toBase58(),
+      marketAuthority.publicKey.toBase58()
+    );
+  });
+
+  it("Creates an LMSR market with a liquidity parameter", async () => {
+    const marketId = new BN(3);
+    const lmsrMarketPda = deriveMarketPda(marketAuthority.publicKey, marketId);
+    const lmsrAccounts = deriveMarketAccounts(lmsrMarketPda);
+
+    // The creator funds the market maker's worst-case loss, b * ln 2
+    const creatorCollateral = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        marketAuthority,
+        collateralMint,
+        marketAuthority.publicKey
+      )
+    ).address;
+    await mintTo(
+      provider.connection,
+      marketAuthority,
+      collateralMint,
+      creatorCollateral,
+      marketAuthority,
+      100 * 1e6
+    );
+
+    await program.methods
+      .createMarket(
+        marketId,
+        "Will ETH reach $10k by EOY?",
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400 * 30),
+        100,
+        new anchor.BN(3600),
+        3,
+        { lmsr: {} },
+        new BN(100_000_000)
+      )
+      .accounts({
+        market: lmsrMarketPda,
+        ...lmsrAccounts,
+        collateralMint,
+        config: configPda,
+        authority: marketAuthority.publicKey,
+        creatorCollateral,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([marketAuthority])
+      .rpc();
+
+    const lmsrMarket = await program.account.market.fetch(lmsrMarketPda);
+    assert.deepEqual(lmsrMarket.pricingCurve, { lmsr: {} });
+    assert.equal(lmsrMarket.lmsrB.toNumber(), 100_000_000);
+
+    // ceil(100 * ln 2) at 6 decimals
+    const vault = await provider.connection.getTokenAccountBalance(lmsrAccounts.collateralVault);
+    assert.equal(vault.value.amount, "69314719");
+  });
+
+  it("Rejects a market whose vault does not match the PDA", async () => {
+    const marketId = new BN(2);
+    const badMarketPda = deriveMarketPda(marketAuthority.publicKey, marketId);
+    const badAccounts = deriveMarketAccounts(badMarketPda);
+
+    try {
+      await program.methods
+        .createMarket(
+          marketId,
+          "Mismatched vault",
+          new anchor.BN(Math.floor(Date.now() / 1000) + 86400 * 30),
+          100,
+          new anchor.BN(3600),
+          3,
+          { cpmm: {} },
+          new BN(0)
+        )
+        .accounts({
+          market: badMarketPda,
+          ...badAccounts,
+          collateralVault: Keypair.generate().publicKey,
+          collateralMint,
+          config: configPda,
+          authority: marketAuthority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([marketAuthority])
+        .rpc();
+      assert.fail("createMarket should reject a non-PDA collateral vault");
+    } catch (err) {
+      assert.include(err.toString(), "ConstraintSeeds");
+    }
+  });
+
+  it("Opens the first epoch of a short-window market", async () => {
+    // Created early so its first window has ended by the last test
+    const marketId = new BN(4);
+    epochMarketPda = deriveMarketPda(marketAuthority.publicKey, marketId);
+    epochMarketAccounts = deriveMarketAccounts(epochMarketPda);
+    await program.methods
+      .createMarket(
+        marketId,
+        "Will the epoch roll over?",
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400 * 30),
+        100,
+        new anchor.BN(300),
+        3,
+        { cpmm: {} },
+        new BN(0)
+      )
+      .accounts({
+        market: epochMarketPda,
+        ...epochMarketAccounts,
+        collateralMint,
+        config: configPda,
+        authority: marketAuthority.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([marketAuthority])
+      .rpc();
+
+    epochUser = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(
+      epochUser.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+    epochUserCollateral = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        epochUser,
+        collateralMint,
+        epochUser.publicKey
+      )
+    ).address;
+    await mintTo(
+      provider.connection,
+      marketAuthority,
+      collateralMint,
+      epochUserCollateral,
+      marketAuthority,
+      3 * 1e6
+    );
+
+    assert.equal((await epochAt()).toNumber(), 0);
+    await submitEpochOrder(new BN(0));
+  });
+
+  describe("protocol config", () => {
+    it("Rejects config updates from a non-admin", async () => {
+      const intruder = Keypair.generate();
+      try {
+        await program.methods
+          .updateConfig(
+            intruder.publicKey,
+            mpcAuthority.publicKey,
+            0,
+            [collateralMint],
+            true
+          )
+          .accounts({ config: configPda, admin: intruder.publicKey })
+          .signers([intruder])
+          .rpc();
+        assert.fail("updateConfig should reject a non-admin signer");
+      } catch (err) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+    });
+
+    it("Rejects MPC result instructions from an arbitrary signer", async () => {
+      const intruder = Keypair.generate();
+      const expectUnauthorized = async (call: Promise<string>, name: string) => {
+        try {
+          await call;
+          assert.fail(`${name} should reject an arbitrary signer`);
+        } catch (err) {
+          assert.include(err.toString(), "Unauthorized");
+        }
+      };
+
+      await expectUnauthorized(
+        program.methods
+          .applyBatchClear(randomBytes32(), new BN(500))
+          .accounts({
+            config: configPda,
+            arciumAuthority: intruder.publicKey,
+            market: marketPda,
+            batch: deriveBatchPda(marketPda, new BN(0)),
+          })
+          .signers([intruder])
+          .rpc(),
+        "applyBatchClear"
+      );
+
+      await expectUnauthorized(
+        program.methods
+          .updateCfmmState(new BN(0), [randomBytes32(), randomBytes32()])
+          .accounts({
+            config: configPda,
+            arciumAuthority: intruder.publicKey,
+            market: marketPda,
+            reserves: deriveReservesPda(marketPda),
+          })
+          .signers([intruder])
+          .rpc(),
+        "updateCfmmState"
+      );
+
+      await expectUnauthorized(
+        program.methods
+          .resolveMarket(1, Buffer.from([]))
+          .accounts({
+            config: configPda,
+            arciumAuthority: intruder.publicKey,
+            market: marketPda,
+          })
+          .signers([intruder])
+          .rpc(),
+        "resolveMarket"
+      );
+    });
+
+    it("Blocks market creation while paused", async () => {
+      await program.methods
+        .updateConfig(
+          provider.wallet.publicKey,
+          mpcAuthority.publicKey,
+          1000,
+          [collateralMint],
+          true
+        )
+        .accounts({ config: configPda, admin: provider.wallet.publicKey })
+        .rpc();
+
+      const marketId = new BN(50);
+      const pausedMarketPda = deriveMarketPda(marketAuthority.publicKey, marketId);
+      try {
+        await program.methods
+          .createMarket(
+            marketId,
+            "Paused market",
+            new anchor.BN(Math.floor(Date.now() / 1000) + 86400 * 30),
+            100,
+            new anchor.BN(3600),
+            3,
+            { cpmm: {} },
+            new BN(0)
+          )
+          .accounts({
+            market: pausedMarketPda,
+            ...deriveMarketAccounts(pausedMarketPda),
+            collateralMint,
+            config: configPda,
+            authority: marketAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .signers([marketAuthority])
+          .rpc();
+        assert.fail("createMarket should fail while the protocol is paused");
+      } catch (err) {
+        assert.include(err.toString(), "ProtocolPaused");
+      } finally {
+        await program.methods
+          .updateConfig(
+            provider.wallet.publicKey,
+            mpcAuthority.publicKey,
+            1000,
+            [collateralMint],
+            false
+          )
+          .accounts({ config: configPda, admin: provider.wallet.publicKey })
+          .rpc();
+      }
+    });
+
+    it("Transfers admin in two steps", async () => {
+      const newAdmin = Keypair.generate();
+
+      await program.methods
+        .transferAdmin(newAdmin.publicKey)
+        .accounts({ config: configPda, admin: provider.wallet.publicKey })
+        .rpc();
+
+      let config = await program.account.protocolConfig.fetch(configPda);
+      assert.equal(config.admin.toBase58(), provider.wallet.publicKey.toBase58());
+      assert.equal(config.pendingAdmin.toBase58(), newAdmin.publicKey.toBase58());
+
+      await program.methods
+        .acceptAdmin()
+        .accounts({ config: configPda, newAdmin: newAdmin.publicKey })
+        .signers([newAdmin])
+        .rpc();
+
+      config = await program.account.protocolConfig.fetch(configPda);
+      assert.equal(config.admin.toBase58(), newAdmin.publicKey.toBase58());
+      assert.isNull(config.pendingAdmin);
+
+      // Hand admin back so later tests can keep updating the config
+      await program.methods
+        .transferAdmin(provider.wallet.publicKey)
+        .accounts({ config: configPda, admin: newAdmin.publicKey })
+        .signers([newAdmin])
+        .rpc();
+      await program.methods
+        .acceptAdmin()
+        .accounts({ config: configPda, newAdmin: provider.wallet.publicKey })
+        .rpc();
+    });
+  });
+
+  describe("create_market validation", () => {
+    let nextMarketId = 100;
+
+    // Attempt to create a market with the given overrides and assert the error code
+    async function expectCreateMarketError(
+      overrides: {
+        question?: string;
+        endTime?: anchor.BN;
+        feeBps?: number;
+        batchInterval?: anchor.BN;
+        resolverQuorum?: number;
+        pricingCurve?: object;
+        lmsrB?: BN;
+      },
+      expectedError: string
+    ) {
+      const now = Math.floor(Date.now() / 1000);
+      const marketId = new BN(nextMarketId++);
+      const pda = deriveMarketPda(marketAuthority.publicKey, marketId);
+
+      try {
+        await program.methods
+          .createMarket(
+            marketId,
+            overrides.question ?? "Validation test",
+            overrides.endTime ?? new anchor.BN(now + 86400 * 30),
+            overrides.feeBps ?? 100,
+            overrides.batchInterval ?? new anchor.BN(3600),
+            overrides.resolverQuorum ?? 3,
+            overrides.pricingCurve ?? { cpmm: {} },
+            overrides.lmsrB ?? new BN(0)
+          )
+          .accounts({
+            market: pda,
+            ...deriveMarketAccounts(pda),
+            collateralMint,
+            config: configPda,
+            authority: marketAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .signers([marketAuthority])
+          .rpc();
+        assert.fail(`createMarket should fail with ${expectedError}`);
+      } catch (err) {
+        assert.include(err.toString(), expectedError);
+      }
+    }
+
+    it("Rejects a question longer than MAX_QUESTION_LEN", async () => {
+      await expectCreateMarketError({ question: "?".repeat(201) }, "QuestionTooLong");
+    });
+
+    it("Rejects a fee below MIN_FEE_BPS", async () => {
+      await expectCreateMarketError({ feeBps: 5 }, "InvalidFeeBps");
+    });
+
+    it("Rejects a fee above MAX_FEE_BPS", async () => {
+      await expectCreateMarketError({ feeBps: 1001 }, "InvalidFeeBps");
+    });
+
+    it("Rejects a batch interval below MIN_BATCH_INTERVAL", async () => {
+      await expectCreateMarketError(
+        { batchInterval: new anchor.BN(299) },
+        "InvalidBatchInterval"
+      );
+    });
+
+    it("Rejects a batch interval above MAX_BATCH_INTERVAL", async () => {
+      await expectCreateMarketError(
+        { batchInterval: new anchor.BN(86401) },
+        "InvalidBatchInterval"
+      );
+    });
+
+    it("Rejects an end time in the past", async () => {
+      await expectCreateMarketError(
+        { endTime: new anchor.BN(Math.floor(Date.now() / 1000) - 60) },
+        "InvalidEndTime"
+      );
+    });
+
+    it("Rejects an end time that does not fit one batch window", async () => {
+      await expectCreateMarketError(
+        { endTime: new anchor.BN(Math.floor(Date.now() / 1000) + 600) },
+        "InvalidEndTime"
+      );
+    });
+
+    it("Rejects a quorum below MIN_QUORUM", async () => {
+      await expectCreateMarketError({ resolverQuorum: 0 }, "InvalidQuorum");
+    });
+
+    it("Rejects a quorum above MAX_RESOLVERS", async () => {
+      await expectCreateMarketError({ resolverQuorum: 11 }, "InvalidQuorum");
+    });
+
+    it("Rejects an LMSR market with a liquidity parameter below MIN_LMSR_B", async () => {
+      await expectCreateMarketError(
+        { pricingCurve: { lmsr: {} }, lmsrB: new BN(999_999) },
+        "InvalidLiquidityParameter"
+      );
+    });
+  });
+
   it("Deposits collateral", async () => {
     const user = Keypair.generate();
     
@@ -221,38 +917,475 @@ describe("private-markets", () => {
       user.publicKey
     );
 
-    await mintTo(
-      provider.connection,
-      marketAuthority,
-      collateralMint,
-      userCollateral.address,
-      marketAuthority,
-      1000 * 1e6 // 1000 USDC
-    );
+    await mintTo(
+      provider.connection,
+      marketAuthority,
+      collateralMint,
+      userCollateral.address,
+      marketAuthority,
+      1000 * 1e6 // 1000 USDC
+    );
+
+    // Deposit collateral
+    const depositAmount = new anchor.BN(100 * 1e6); // 100 USDC
+    const tx = await program.methods
+      .depositCollateral(depositAmount)
+      .accounts({
+        market: marketPda,
+        collateralVault,
+        userCollateral: userCollateral.address,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    console.log("Collateral deposited:", tx);
+
+    // Verify vault balance
+    const vaultAccount = await provider.connection.getTokenAccountBalance(
+      collateralVault
+    );
+    assert.equal(vaultAccount.value.uiAmount, 100);
+
+    console.log("Vault balance verified:", vaultAccount.value.uiAmount);
+  });
+
+  it("Splits collateral into paired YES and NO tokens", async () => {
+    splitUser = Keypair.generate();
+    const user = splitUser;
+
+    const signature = await provider.connection.requestAirdrop(
+      user.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+
+    const userCollateral = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      user,
+      collateralMint,
+      user.publicKey
+    );
+    const userYes = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      user,
+      yesMint,
+      user.publicKey
+    );
+    const userNo = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      user,
+      noMint,
+      user.publicKey
+    );
+    splitUserCollateral = userCollateral.address;
+    splitUserYes = userYes.address;
+    splitUserNo = userNo.address;
+
+    await mintTo(
+      provider.connection,
+      marketAuthority,
+      collateralMint,
+      userCollateral.address,
+      marketAuthority,
+      50 * 1e6
+    );
+
+    const vaultBefore = await provider.connection.getTokenAccountBalance(
+      collateralVault
+    );
+
+    const splitAmount = new anchor.BN(25 * 1e6);
+    await program.methods
+      .splitCollateral(splitAmount)
+      .accounts({
+        market: marketPda,
+        config: configPda,
+        collateralVault,
+        yesMint,
+        noMint,
+        userCollateral: userCollateral.address,
+        userYesTokens: userYes.address,
+        userNoTokens: userNo.address,
+        user: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    const vaultAfter = await provider.connection.getTokenAccountBalance(
+      collateralVault
+    );
+    const yesBalance = await provider.connection.getTokenAccountBalance(
+      userYes.address
+    );
+    const noBalance = await provider.connection.getTokenAccountBalance(
+      userNo.address
+    );
+
+    assert.equal(vaultAfter.value.uiAmount - vaultBefore.value.uiAmount, 25);
+    assert.equal(yesBalance.value.uiAmount, 25);
+    assert.equal(noBalance.value.uiAmount, 25);
+  });
+
+  it("Blocks splitting collateral while paused", async () => {
+    const setPaused = (paused: boolean) =>
+      program.methods
+        .updateConfig(
+          provider.wallet.publicKey,
+          mpcAuthority.publicKey,
+          1000,
+          [collateralMint],
+          paused
+        )
+        .accounts({ config: configPda, admin: provider.wallet.publicKey })
+        .rpc();
+
+    await setPaused(true);
+    try {
+      await program.methods
+        .splitCollateral(new anchor.BN(1e6))
+        .accounts({
+          market: marketPda,
+          config: configPda,
+          collateralVault,
+          yesMint,
+          noMint,
+          userCollateral: splitUserCollateral,
+          userYesTokens: splitUserYes,
+          userNoTokens: splitUserNo,
+          user: splitUser.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([splitUser])
+        .rpc();
+      assert.fail("splitCollateral should fail while the protocol is paused");
+    } catch (err) {
+      assert.include(err.toString(), "ProtocolPaused");
+    } finally {
+      await setPaused(false);
+    }
+  });
+
+  it("Merges complete sets back into collateral", async () => {
+    const vaultBefore = await provider.connection.getTokenAccountBalance(
+      collateralVault
+    );
+
+    const mergeAmount = new anchor.BN(10 * 1e6);
+    await program.methods
+      .mergePositions(mergeAmount)
+      .accounts({
+        market: marketPda,
+        collateralVault,
+        yesMint,
+        noMint,
+        feeVault,
+        userCollateral: splitUserCollateral,
+        userYesTokens: splitUserYes,
+        userNoTokens: splitUserNo,
+        user: splitUser.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([splitUser])
+      .rpc();
+
+    const vaultAfter = await provider.connection.getTokenAccountBalance(
+      collateralVault
+    );
+    const yesBalance = await provider.connection.getTokenAccountBalance(
+      splitUserYes
+    );
+    const noBalance = await provider.connection.getTokenAccountBalance(
+      splitUserNo
+    );
+    const collateralBalance = await provider.connection.getTokenAccountBalance(
+      splitUserCollateral
+    );
+
+    assert.equal(vaultBefore.value.uiAmount - vaultAfter.value.uiAmount, 10);
+    assert.equal(yesBalance.value.uiAmount, 15);
+    assert.equal(noBalance.value.uiAmount, 15);
+    // 1% fee on the 10 released is kept in the fee vault
+    assert.equal(collateralBalance.value.uiAmount, 34.9);
+
+    const feeVaultBalance = await provider.connection.getTokenAccountBalance(
+      feeVault
+    );
+    assert.equal(feeVaultBalance.value.uiAmount, 0.1);
+    const marketAccount = await program.account.market.fetch(marketPda);
+    assert.equal(marketAccount.feesAccrued.toNumber(), 0.1 * 1e6);
+  });
+
+  it("Only lets the market authority withdraw fees", async () => {
+    const authorityCollateral = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      marketAuthority,
+      collateralMint,
+      marketAuthority.publicKey
+    );
+    const treasuryCollateral = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      marketAuthority,
+      collateralMint,
+      provider.wallet.publicKey
+    );
+
+    try {
+      await program.methods
+        .withdrawFees(new BN(0.1 * 1e6))
+        .accounts({
+          market: marketPda,
+          feeVault,
+          recipient: splitUserCollateral,
+          config: configPda,
+          treasuryTokenAccount: treasuryCollateral.address,
+          authority: splitUser.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([splitUser])
+        .rpc();
+      assert.fail("withdrawFees should reject a non-authority signer");
+    } catch (err) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+
+    await program.methods
+      .withdrawFees(new BN(0.1 * 1e6))
+      .accounts({
+        market: marketPda,
+        feeVault,
+        recipient: authorityCollateral.address,
+        config: configPda,
+        treasuryTokenAccount: treasuryCollateral.address,
+        authority: marketAuthority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([marketAuthority])
+      .rpc();
+
+    const feeVaultBalance = await provider.connection.getTokenAccountBalance(
+      feeVault
+    );
+    assert.equal(feeVaultBalance.value.uiAmount, 0);
+    const marketAccount = await program.account.market.fetch(marketPda);
+    assert.equal(marketAccount.feesAccrued.toNumber(), 0);
+
+    // 10% protocol share goes to the treasury
+    const treasuryBalance = await provider.connection.getTokenAccountBalance(
+      treasuryCollateral.address
+    );
+    assert.equal(treasuryBalance.value.uiAmount, 0.01);
+  });
+
+  describe("liquidity provision", () => {
+    const lpMarketId = new BN(5);
+    let lpMarketPda: PublicKey;
+    let lpAccounts: ReturnType<typeof deriveMarketAccounts>;
+    let liquidityProvider: Keypair;
+    let providerCollateral: PublicKey;
+    let providerYes: PublicKey;
+    let providerNo: PublicKey;
+    let providerLp: PublicKey;
+
+    const liquidityAccounts = (
+      compDefType: "add_liquidity" | "remove_liquidity",
+      computationOffset: BN
+    ) => ({
+      payer: liquidityProvider.publicKey,
+      ...arciumQueueAccounts(compDefType, computationOffset),
+      market: lpMarketPda,
+      reserves: deriveReservesPda(lpMarketPda),
+      collateralVault: lpAccounts.collateralVault,
+      feeVault: lpAccounts.feeVault,
+      yesMint: lpAccounts.yesMint,
+      noMint: lpAccounts.noMint,
+      lpMint: lpAccounts.lpMint,
+      userCollateral: providerCollateral,
+      userYesTokens: providerYes,
+      userNoTokens: providerNo,
+      userLpTokens: providerLp,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+
+    // Wait for the computation, then for its callback to release the
+    // reserves, so later reads see the settled balances
+    const finalize = async (computationOffset: BN) => {
+      await awaitComputationFinalization(
+        provider as anchor.AnchorProvider,
+        computationOffset,
+        PROGRAM_ID,
+        "confirmed"
+      );
+      const reservesPda = deriveReservesPda(lpMarketPda);
+      for (let attempt = 0; attempt < 30; attempt++) {
+        const reserves = await program.account.encryptedReserves.fetch(reservesPda, "confirmed");
+        if (!reserves.pending) {
+          return;
+        }
+        await sleep(1000);
+      }
+      assert.fail("callback did not release the reserves");
+    };
+
+    const fetchMarket = () => program.account.market.fetch(lpMarketPda, "confirmed");
+
+    const balance = async (account: PublicKey) =>
+      (await provider.connection.getTokenAccountBalance(account, "confirmed")).value.uiAmount;
+
+    before(async () => {
+      lpMarketPda = deriveMarketPda(marketAuthority.publicKey, lpMarketId);
+      lpAccounts = deriveMarketAccounts(lpMarketPda);
+
+      await program.methods
+        .createMarket(
+          lpMarketId,
+          "Liquidity provision test",
+          new anchor.BN(Math.floor(Date.now() / 1000) + 86400 * 30),
+          100,
+          new anchor.BN(3600),
+          3,
+          { cpmm: {} },
+          new BN(0)
+        )
+        .accounts({
+          market: lpMarketPda,
+          ...lpAccounts,
+          collateralMint,
+          config: configPda,
+          authority: marketAuthority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([marketAuthority])
+        .rpc();
+      await initializeReserves(provider as anchor.AnchorProvider, program, lpMarketPda);
+
+      liquidityProvider = Keypair.generate();
+      const signature = await provider.connection.requestAirdrop(
+        liquidityProvider.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(signature);
+
+      const ata = async (mint: PublicKey) =>
+        (
+          await getOrCreateAssociatedTokenAccount(
+            provider.connection,
+            liquidityProvider,
+            mint,
+            liquidityProvider.publicKey
+          )
+        ).address;
+      providerCollateral = await ata(collateralMint);
+      providerYes = await ata(lpAccounts.yesMint);
+      providerNo = await ata(lpAccounts.noMint);
+      providerLp = await ata(lpAccounts.lpMint);
+
+      await mintTo(
+        provider.connection,
+        marketAuthority,
+        collateralMint,
+        providerCollateral,
+        marketAuthority,
+        50 * 1e6
+      );
+    });
+
+    it("Seeds the pool with the first deposit", async () => {
+      const computationOffset = new BN(randomBytes(8), "le");
+      await program.methods
+        .addLiquidity(computationOffset, new BN(20 * 1e6))
+        .accounts({ ...liquidityAccounts("add_liquidity", computationOffset), config: configPda })
+        .signers([liquidityProvider])
+        .rpc();
+      await finalize(computationOffset);
+
+      const market = await fetchMarket();
+      assert.equal(market.totalLiquidity.toNumber(), 20 * 1e6);
+      assert.equal(await balance(providerLp), 20);
+      assert.equal(await balance(lpAccounts.collateralVault), 20);
+      assert.equal(await balance(providerCollateral), 30);
+    });
 
-    // Deposit collateral
-    const depositAmount = new anchor.BN(100 * 1e6); // 100 USDC
-    const tx = await program.methods
-      .depositCollateral(depositAmount)
-      .accounts({
-        market: marketPda,
-        collateralVault,
-        userCollateral: userCollateral.address,
-        user: user.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
-      })
-      .signers([user])
-      .rpc();
+    it("Shares trading fees with liquidity providers", async () => {
+      await program.methods
+        .splitCollateral(new BN(10 * 1e6))
+        .accounts({
+          market: lpMarketPda,
+          config: configPda,
+          collateralVault: lpAccounts.collateralVault,
+          yesMint: lpAccounts.yesMint,
+          noMint: lpAccounts.noMint,
+          userCollateral: providerCollateral,
+          userYesTokens: providerYes,
+          userNoTokens: providerNo,
+          user: liquidityProvider.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([liquidityProvider])
+        .rpc();
 
-    console.log("Collateral deposited:", tx);
+      await program.methods
+        .mergePositions(new BN(10 * 1e6))
+        .accounts({
+          market: lpMarketPda,
+          collateralVault: lpAccounts.collateralVault,
+          yesMint: lpAccounts.yesMint,
+          noMint: lpAccounts.noMint,
+          feeVault: lpAccounts.feeVault,
+          userCollateral: providerCollateral,
+          userYesTokens: providerYes,
+          userNoTokens: providerNo,
+          user: liquidityProvider.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([liquidityProvider])
+        .rpc();
 
-    // Verify vault balance
-    const vaultAccount = await provider.connection.getTokenAccountBalance(
-      collateralVault
-    );
-    assert.equal(vaultAccount.value.uiAmount, 100);
+      // The 0.1 fee is split evenly between the creator and the LPs
+      const market = await fetchMarket();
+      assert.equal(market.feesAccrued.toNumber(), 0.05 * 1e6);
+      assert.equal(market.lpFeesAccrued.toNumber(), 0.05 * 1e6);
+    });
 
-    console.log("Vault balance verified:", vaultAccount.value.uiAmount);
+    it("Returns a pro-rata share of reserves and LP fees on withdrawal", async () => {
+      const collateralBefore = await balance(providerCollateral);
+
+      const computationOffset = new BN(randomBytes(8), "le");
+      await program.methods
+        .removeLiquidity(computationOffset, new BN(10 * 1e6))
+        .accounts(liquidityAccounts("remove_liquidity", computationOffset))
+        .signers([liquidityProvider])
+        .rpc();
+      await finalize(computationOffset);
+
+      // Half of a balanced 20/20 pool comes back as 10 YES and 10 NO
+      const market = await fetchMarket();
+      assert.equal(market.totalLiquidity.toNumber(), 10 * 1e6);
+      assert.equal(market.lpFeesAccrued.toNumber(), 0.025 * 1e6);
+
+      assert.equal(await balance(providerLp), 10);
+      assert.equal(await balance(providerYes), 10);
+      assert.equal(await balance(providerNo), 10);
+      assert.closeTo((await balance(providerCollateral)) - collateralBefore, 0.025, 1e-9);
+    });
+
+    it("Rejects withdrawing more shares than are outstanding", async () => {
+      const computationOffset = new BN(randomBytes(8), "le");
+      try {
+        await program.methods
+          .removeLiquidity(computationOffset, new BN(11 * 1e6))
+          .accounts(liquidityAccounts("remove_liquidity", computationOffset))
+          .signers([liquidityProvider])
+          .rpc();
+        assert.fail("removeLiquidity should reject more shares than outstanding");
+      } catch (err) {
+        assert.include(err.toString(), "InsufficientLiquidity");
+      }
+    });
   });
 
   it("Submits a private trade order", async () => {
@@ -265,6 +1398,30 @@ describe("private-markets", () => {
     );
     await provider.connection.confirmTransaction(signature);
 
+    // Open the trader's encrypted position before their first trade
+    const positionPda = derivePositionPda(marketPda, user.publicKey);
+    const positionOffset = new BN(randomBytes(8), "le");
+    await program.methods
+      .openPosition(positionOffset, randomBytes32(), new BN(randomBytes(16), "le"))
+      .accounts({
+        payer: user.publicKey,
+        ...arciumQueueAccounts("init_position", positionOffset),
+        market: marketPda,
+        position: positionPda,
+      })
+      .signers([user])
+      .rpc();
+    await awaitComputationFinalization(
+      provider as anchor.AnchorProvider,
+      positionOffset,
+      PROGRAM_ID,
+      "confirmed"
+    );
+
+    const position = await program.account.userPosition.fetch(positionPda);
+    assert.isTrue(position.initialized);
+    assert.isTrue(position.authority.equals(user.publicKey));
+
     // Derive all Arcium accounts
     const mxeAccount = getMXEAccAddress(PROGRAM_ID);
     const signPda = deriveSignPda();
@@ -289,6 +1446,25 @@ describe("private-markets", () => {
     const nonceBn = new BN(randomBytes(16), "le");
     const clientPubkey = randomBytes32();
 
+    // Fund the collateral escrowed with the trade and the outcome token
+    // accounts the fill is minted to
+    const ata = async (mint: PublicKey) =>
+      (await getOrCreateAssociatedTokenAccount(provider.connection, user, mint, user.publicKey))
+        .address;
+    const userCollateral = await ata(collateralMint);
+    const userYes = await ata(yesMint);
+    const userNo = await ata(noMint);
+    await mintTo(
+      provider.connection,
+      marketAuthority,
+      collateralMint,
+      userCollateral,
+      marketAuthority,
+      10 * 1e6
+    );
+    const vaultBefore = (await provider.connection.getTokenAccountBalance(collateralVault)).value
+      .uiAmount;
+
     const tx = await program.methods
       .submitPrivateTrade(
         computationOffset,
@@ -296,7 +1472,8 @@ describe("private-markets", () => {
         ciphertextSide,
         ciphertextMaxPrice,
         nonceBn,
-        clientPubkey
+        clientPubkey,
+        new BN(5 * 1e6)
       )
       .accounts({
         payer: user.publicKey,
@@ -312,11 +1489,132 @@ describe("private-markets", () => {
         systemProgram: SystemProgram.programId,
         arciumProgram: getArciumProgAddress(),
         market: marketPda,
+        config: configPda,
+        reserves: deriveReservesPda(marketPda),
+        position: positionPda,
+        collateralVault,
+        feeVault,
+        yesMint,
+        noMint,
+        userCollateral,
+        userYesTokens: userYes,
+        userNoTokens: userNo,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([user])
       .rpc();
 
     console.log("Private trade submitted:", tx);
+
+    // The escrow sits in the vault until the callback mints shares or refunds it
+    const vaultAfter = (await provider.connection.getTokenAccountBalance(collateralVault)).value
+      .uiAmount;
+    assert.equal(vaultAfter - vaultBefore, 5);
+    assert.equal(
+      (await provider.connection.getTokenAccountBalance(userCollateral)).value.uiAmount,
+      5
+    );
+
+    // The pool and position stay locked until the callback lands, so a second
+    // trade cannot be priced against the same reserves
+    const reservesPda = deriveReservesPda(marketPda);
+    assert.isTrue((await program.account.encryptedReserves.fetch(reservesPda)).pending);
+    assert.isTrue((await program.account.userPosition.fetch(positionPda)).pending);
+
+    const retryOffset = new BN(randomBytes(8), "le");
+    try {
+      await program.methods
+        .submitPrivateTrade(
+          retryOffset,
+          ciphertextAmount,
+          ciphertextSide,
+          ciphertextMaxPrice,
+          nonceBn,
+          clientPubkey,
+          new BN(1e6)
+        )
+        .accounts({
+          payer: user.publicKey,
+          ...arciumQueueAccounts("private_trade", retryOffset),
+          market: marketPda,
+          config: configPda,
+          reserves: reservesPda,
+          position: positionPda,
+          collateralVault,
+          feeVault,
+          yesMint,
+          noMint,
+          userCollateral,
+          userYesTokens: userYes,
+          userNoTokens: userNo,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("submitPrivateTrade should wait for the pending computation");
+    } catch (err) {
+      assert.include(err.toString(), "ComputationPending");
+    }
+
+    await awaitComputationFinalization(
+      provider as anchor.AnchorProvider,
+      computationOffset,
+      PROGRAM_ID,
+      "confirmed"
+    );
+    assert.isFalse((await program.account.encryptedReserves.fetch(reservesPda)).pending);
+    assert.isFalse((await program.account.userPosition.fetch(positionPda)).pending);
+  });
+
+  it("Opens the first batch epoch and keeps it open until its window ends", async () => {
+    const batchPda = deriveBatchPda(marketPda, new BN(0));
+
+    await program.methods
+      .openBatch(new BN(0))
+      .accounts({
+        market: marketPda,
+        batch: batchPda,
+        orderQueue: deriveOrderQueuePda(batchPda),
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const batch = await program.account.batchState.fetch(batchPda);
+    assert.equal(batch.epoch.toNumber(), 0);
+    assert.deepEqual(batch.state, { open: {} });
+    assert.equal(batch.endTime.sub(batch.startTime).toNumber(), 3600);
+
+    const marketAccount = await program.account.market.fetch(marketPda);
+    assert.equal(marketAccount.nextBatchClear.toNumber(), batch.endTime.toNumber());
+
+    try {
+      await program.methods
+        .closeBatch()
+        .accounts({ market: marketPda, batch: batchPda })
+        .rpc();
+      assert.fail("closeBatch should reject a batch whose window is still open");
+    } catch (err) {
+      assert.include(err.toString(), "BatchWindowOpen");
+    }
+
+    // Later epochs open only once their window starts
+    const futureBatchPda = deriveBatchPda(marketPda, new BN(5));
+    try {
+      await program.methods
+        .openBatch(new BN(5))
+        .accounts({
+          market: marketPda,
+          batch: futureBatchPda,
+          orderQueue: deriveOrderQueuePda(futureBatchPda),
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("openBatch should reject an epoch whose window has not started");
+    } catch (err) {
+      assert.include(err.toString(), "WrongEpoch");
+    }
   });
 
   it("Submits a batch order", async () => {
@@ -329,123 +1627,250 @@ describe("private-markets", () => {
     );
     await provider.connection.confirmTransaction(signature);
 
-    // Derive all Arcium accounts
-    const mxeAccount = getMXEAccAddress(PROGRAM_ID);
-    const signPda = deriveSignPda();
-    const mempoolAccount = getMempoolAccAddress(PROGRAM_ID);
-    const executingPool = getExecutingPoolAccAddress(PROGRAM_ID);
-    const computationOffset = new BN(Date.now() % 10_000_000);
-    const computationAccount = getComputationAccAddress(PROGRAM_ID, computationOffset);
-    const baseSeed = getArciumAccountBaseSeed("ComputationDefinitionAccount");
-    const compDefOffset = getCompDefAccOffset("batch_clear");
-    const compDefAccount = PublicKey.findProgramAddressSync(
-      [baseSeed, PROGRAM_ID.toBuffer(), compDefOffset],
-      getArciumProgAddress()
-    )[0];
-    const clusterAccount = getClusterAccAddress(ARCIUM_CLUSTER_OFFSET);
-    const clockAccount = getClockAccAddress();
-    const poolAccount = getFeePoolAccAddress();
+    // Fund the collateral account the escrow and trading fee are charged from
+    const userCollateral = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      user,
+      collateralMint,
+      user.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      marketAuthority,
+      collateralMint,
+      userCollateral.address,
+      marketAuthority,
+      110 * 1e6
+    );
 
-    // Mock batch order data
-    const batchOrders = [{
+    const batchPda = deriveBatchPda(marketPda, new BN(0));
+    const orderQueuePda = deriveOrderQueuePda(batchPda);
+
+    // Mock encrypted order escrowing 100 collateral
+    const order = {
       commitment: randomBytes32(),
       amount: new BN(100 * 1e6),
-      isBuy: true,
-    }];
+      encryptionPubkey: randomBytes32(),
+      nonce: new BN(randomBytes(16), "le"),
+      ciphertexts: [randomBytes32(), randomBytes32(), randomBytes32()],
+    };
+
+    const feesBefore = (await program.account.market.fetch(marketPda)).feesAccrued;
+    const vaultBefore = (await provider.connection.getTokenAccountBalance(collateralVault)).value
+      .uiAmount;
 
     const tx = await program.methods
-      .submitBatchOrder(computationOffset, batchOrders)
+      .submitBatchOrder(new BN(0), order)
       .accounts({
+        market: marketPda,
+        config: configPda,
+        batch: batchPda,
+        orderQueue: orderQueuePda,
+        collateralVault,
+        payerCollateral: userCollateral.address,
         payer: user.publicKey,
-        signPdaAccount: signPda,
-        mxeAccount,
-        mempoolAccount,
-        executingPool,
-        computationAccount,
-        compDefAccount,
-        clusterAccount,
-        poolAccount,
-        clockAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
-        arciumProgram: getArciumProgAddress(),
-        market: marketPda,
       })
       .signers([user])
       .rpc();
 
     console.log("Batch order submitted:", tx);
 
-    // Verify batch order count
+    // The 1% fee on 100 notional is escrowed with the order but not charged
+    // until the fill is claimed
     const marketAccount = await program.account.market.fetch(marketPda);
     assert.equal(marketAccount.batchOrderCount, 1);
-  });
-
-  it("Only begins resolution after distinct resolvers reach quorum", async () => {
-    const resolutionAuthority = Keypair.generate();
-    const quorum = 2;
-    const endTime = new anchor.BN(Math.floor(Date.now() / 1000) + 8);
-
-    const [resolutionMarketPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("market"), resolutionAuthority.publicKey.toBuffer()],
-      program.programId
+    const batch = await program.account.batchState.fetch(batchPda);
+    assert.equal(batch.orderCount, 1);
+    assert.isTrue(marketAccount.feesAccrued.eq(feesBefore));
+    const vaultAfter = (await provider.connection.getTokenAccountBalance(collateralVault)).value
+      .uiAmount;
+    assert.equal(vaultAfter - vaultBefore, 101);
+
+    // The order waits in the queue, encrypted, until the batch is cranked
+    const orderQueue = await program.account.orderQueue.fetch(orderQueuePda);
+    assert.isTrue(orderQueue.orders[0].owner.equals(user.publicKey));
+    assert.deepEqual(orderQueue.orders[0].order.commitment, order.commitment);
+    assert.equal(
+      (await provider.connection.getTokenAccountBalance(userCollateral.address)).value.uiAmount,
+      9
     );
+  });
 
+  it("Cancels a queued batch order and refunds its escrow", async () => {
+    const user = Keypair.generate();
     const signature = await provider.connection.requestAirdrop(
-      resolutionAuthority.publicKey,
-      2 * LAMPORTS_PER_SOL
+      user.publicKey,
+      LAMPORTS_PER_SOL
     );
     await provider.connection.confirmTransaction(signature);
 
-    // Create vault token accounts
-    const resolutionCollateralVaultAccount = await getOrCreateAssociatedTokenAccount(
+    const userCollateral = await getOrCreateAssociatedTokenAccount(
       provider.connection,
-      resolutionAuthority,
+      user,
       collateralMint,
-      resolutionMarketPda,
-      true
+      user.publicKey
     );
-    const resolutionCollateralVault = resolutionCollateralVaultAccount.address;
-
-    const resolutionFeeVaultAccount = await getOrCreateAssociatedTokenAccount(
+    await mintTo(
       provider.connection,
-      resolutionAuthority,
+      marketAuthority,
       collateralMint,
-      resolutionAuthority.publicKey
+      userCollateral.address,
+      marketAuthority,
+      11 * 1e6
     );
-    const resolutionFeeVault = resolutionFeeVaultAccount.address;
 
-    // Create outcome token mints
-    const resolutionYesMint = await createMint(
-      provider.connection,
-      resolutionAuthority,
-      resolutionMarketPda,
-      null,
-      6
+    const batchPda = deriveBatchPda(marketPda, new BN(0));
+    const orderQueuePda = deriveOrderQueuePda(batchPda);
+    const rootBefore = (await program.account.batchState.fetch(batchPda)).orderRoot;
+
+    const order = {
+      commitment: randomBytes32(),
+      amount: new BN(10 * 1e6),
+      encryptionPubkey: randomBytes32(),
+      nonce: new BN(randomBytes(16), "le"),
+      ciphertexts: [randomBytes32(), randomBytes32(), randomBytes32()],
+    };
+
+    await program.methods
+      .submitBatchOrder(new BN(0), order)
+      .accounts({
+        market: marketPda,
+        config: configPda,
+        batch: batchPda,
+        orderQueue: orderQueuePda,
+        collateralVault,
+        payerCollateral: userCollateral.address,
+        payer: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+    assert.equal((await program.account.batchState.fetch(batchPda)).orderCount, 2);
+
+    await program.methods
+      .cancelBatchOrder(order.commitment)
+      .accounts({
+        market: marketPda,
+        batch: batchPda,
+        orderQueue: orderQueuePda,
+        collateralVault,
+        ownerCollateral: userCollateral.address,
+        owner: user.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    // Nothing was filled, so the escrow and its 1% fee both come back
+    const batch = await program.account.batchState.fetch(batchPda);
+    assert.equal(batch.orderCount, 1);
+    assert.deepEqual(batch.orderRoot, rootBefore);
+    const marketAccount = await program.account.market.fetch(marketPda);
+    assert.equal(marketAccount.batchOrderCount, 1);
+    const orderQueue = await program.account.orderQueue.fetch(orderQueuePda);
+    assert.isTrue(orderQueue.orders[1].owner.equals(PublicKey.default));
+    assert.equal(
+      (await provider.connection.getTokenAccountBalance(userCollateral.address)).value.uiAmount,
+      11
     );
+  });
 
-    const resolutionNoMint = await createMint(
+  it("Rejects claiming fills before the batch clears", async () => {
+    const batchPda = deriveBatchPda(marketPda, new BN(0));
+    const userCollateral = await getOrCreateAssociatedTokenAccount(
       provider.connection,
-      resolutionAuthority,
-      resolutionMarketPda,
-      null,
-      6
+      marketAuthority,
+      collateralMint,
+      provider.wallet.publicKey
+    );
+
+    try {
+      await program.methods
+        .claimBatchFill()
+        .accounts({
+          market: marketPda,
+          batch: batchPda,
+          orderQueue: deriveOrderQueuePda(batchPda),
+          collateralVault,
+          feeVault,
+          yesMint,
+          noMint,
+          ownerCollateral: userCollateral.address,
+          ownerYesTokens: userCollateral.address,
+          ownerNoTokens: userCollateral.address,
+          owner: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      assert.fail("claimBatchFill should wait for the batch to clear");
+    } catch (err) {
+      assert.include(err.toString(), "BatchNotCleared");
+    }
+  });
+
+  it("Rejects cranking a batch until it is closed", async () => {
+    const batchPda = deriveBatchPda(marketPda, new BN(0));
+    const computationOffset = new BN(randomBytes(8), "le");
+
+    try {
+      await program.methods
+        .crankBatchClear(computationOffset)
+        .accounts({
+          payer: provider.wallet.publicKey,
+          ...arciumQueueAccounts("batch_clear", computationOffset),
+          market: marketPda,
+          reserves: deriveReservesPda(marketPda),
+          batch: batchPda,
+          orderQueue: deriveOrderQueuePda(batchPda),
+        })
+        .rpc();
+      assert.fail("crankBatchClear should wait for the batch to be closed");
+    } catch (err) {
+      assert.include(err.toString(), "BatchNotClosed");
+    }
+  });
+
+  it("Only begins resolution after distinct resolvers reach quorum", async () => {
+    const resolutionAuthority = Keypair.generate();
+    const quorum = 2;
+    // Shortest market the program accepts: a single minimum-length batch window
+    const batchInterval = new anchor.BN(300);
+    const endTime = new anchor.BN(Math.floor(Date.now() / 1000) + 305);
+
+    const resolutionMarketId = new BN(0);
+    const resolutionMarketPda = deriveMarketPda(
+      resolutionAuthority.publicKey,
+      resolutionMarketId
+    );
+
+    const signature = await provider.connection.requestAirdrop(
+      resolutionAuthority.publicKey,
+      2 * LAMPORTS_PER_SOL
     );
+    await provider.connection.confirmTransaction(signature);
+
+    const resolutionAccounts = deriveMarketAccounts(resolutionMarketPda);
+    const resolutionCollateralVault = resolutionAccounts.collateralVault;
 
     await program.methods
       .createMarket(
+        resolutionMarketId,
         "Resolution quorum test",
         endTime,
         100,
-        new anchor.BN(3600),
-        quorum
+        batchInterval,
+        quorum,
+        { cpmm: {} },
+        new BN(0)
       )
       .accounts({
         market: resolutionMarketPda,
-        collateralVault: resolutionCollateralVault,
-        feeVault: resolutionFeeVault,
-        yesMint: resolutionYesMint,
-        noMint: resolutionNoMint,
+        ...resolutionAccounts,
         collateralMint,
+        config: configPda,
         authority: resolutionAuthority.publicKey,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -557,7 +1982,12 @@ describe("private-markets", () => {
     const attestation1 = randomBytes32();
 
     await program.methods
-      .submitAttestation(arciumAccounts1.computationOffset, attestation1)
+      .submitAttestation(
+        arciumAccounts1.computationOffset,
+        randomBytes32(),
+        new BN(randomBytes(16), "le"),
+        attestation1
+      )
       .accounts({
         payer: arciumAccounts1.payer,
         signPdaAccount: arciumAccounts1.signPdaAccount,
@@ -573,6 +2003,7 @@ describe("private-markets", () => {
         arciumProgram: arciumAccounts1.arciumProgram,
         market: arciumAccounts1.market,
         resolver: arciumAccounts1.resolver,
+        votes: deriveVotesPda(resolutionMarketPda),
       })
       .signers([firstResolver.keypair])
       .rpc();
@@ -585,30 +2016,41 @@ describe("private-markets", () => {
     );
     assert.equal(resolutionMarketAccount.attestationCount, 1);
 
-    // Second attestation (repeat by same resolver - should not increment)
+    // Second attestation (repeat by same resolver - rejected, count unchanged)
     const arciumAccounts2 = getArciumAccountsForResolver(firstResolver.keypair.publicKey, firstResolver.resolverPda);
     const attestation2 = randomBytes32();
 
-    await program.methods
-      .submitAttestation(arciumAccounts2.computationOffset, attestation2)
-      .accounts({
-        payer: arciumAccounts2.payer,
-        signPdaAccount: arciumAccounts2.signPdaAccount,
-        mxeAccount: arciumAccounts2.mxeAccount,
-        mempoolAccount: arciumAccounts2.mempoolAccount,
-        executingPool: arciumAccounts2.executingPool,
-        computationAccount: arciumAccounts2.computationAccount,
-        compDefAccount: arciumAccounts2.compDefAccount,
-        clusterAccount: arciumAccounts2.clusterAccount,
-        poolAccount: arciumAccounts2.poolAccount,
-        clockAccount: arciumAccounts2.clockAccount,
-        systemProgram: arciumAccounts2.systemProgram,
-        arciumProgram: arciumAccounts2.arciumProgram,
-        market: arciumAccounts2.market,
-        resolver: arciumAccounts2.resolver,
-      })
-      .signers([firstResolver.keypair])
-      .rpc();
+    try {
+      await program.methods
+        .submitAttestation(
+          arciumAccounts2.computationOffset,
+          randomBytes32(),
+          new BN(randomBytes(16), "le"),
+          attestation2
+        )
+        .accounts({
+          payer: arciumAccounts2.payer,
+          signPdaAccount: arciumAccounts2.signPdaAccount,
+          mxeAccount: arciumAccounts2.mxeAccount,
+          mempoolAccount: arciumAccounts2.mempoolAccount,
+          executingPool: arciumAccounts2.executingPool,
+          computationAccount: arciumAccounts2.computationAccount,
+          compDefAccount: arciumAccounts2.compDefAccount,
+          clusterAccount: arciumAccounts2.clusterAccount,
+          poolAccount: arciumAccounts2.poolAccount,
+          clockAccount: arciumAccounts2.clockAccount,
+          systemProgram: arciumAccounts2.systemProgram,
+          arciumProgram: arciumAccounts2.arciumProgram,
+          market: arciumAccounts2.market,
+          resolver: arciumAccounts2.resolver,
+          votes: deriveVotesPda(resolutionMarketPda),
+        })
+        .signers([firstResolver.keypair])
+        .rpc();
+      assert.fail("submitAttestation should reject a second vote from the same resolver");
+    } catch (err) {
+      assert.include(err.toString(), "AlreadyAttested");
+    }
 
     resolutionMarketAccount = await program.account.market.fetch(
       resolutionMarketPda
@@ -616,12 +2058,44 @@ describe("private-markets", () => {
     assert.isTrue("awaitingAttestation" in resolutionMarketAccount.resolutionState);
     assert.equal(resolutionMarketAccount.attestationCount, 1);
 
+    // Re-queueing the tally needs a quorum of stored votes
+    const retryAccounts = getArciumAccountsForResolver(provider.wallet.publicKey, firstResolver.resolverPda);
+    try {
+      await program.methods
+        .retryResolution(retryAccounts.computationOffset)
+        .accounts({
+          payer: retryAccounts.payer,
+          signPdaAccount: retryAccounts.signPdaAccount,
+          mxeAccount: retryAccounts.mxeAccount,
+          mempoolAccount: retryAccounts.mempoolAccount,
+          executingPool: retryAccounts.executingPool,
+          computationAccount: retryAccounts.computationAccount,
+          compDefAccount: retryAccounts.compDefAccount,
+          clusterAccount: retryAccounts.clusterAccount,
+          poolAccount: retryAccounts.poolAccount,
+          clockAccount: retryAccounts.clockAccount,
+          systemProgram: retryAccounts.systemProgram,
+          arciumProgram: retryAccounts.arciumProgram,
+          market: retryAccounts.market,
+          votes: deriveVotesPda(resolutionMarketPda),
+        })
+        .rpc();
+      assert.fail("retryResolution should wait for quorum");
+    } catch (err) {
+      assert.include(err.toString(), "QuorumNotReached");
+    }
+
     // Third attestation (by second resolver - should reach quorum)
     const arciumAccounts3 = getArciumAccountsForResolver(secondResolver.keypair.publicKey, secondResolver.resolverPda);
     const attestation3 = randomBytes32();
 
     await program.methods
-      .submitAttestation(arciumAccounts3.computationOffset, attestation3)
+      .submitAttestation(
+        arciumAccounts3.computationOffset,
+        randomBytes32(),
+        new BN(randomBytes(16), "le"),
+        attestation3
+      )
       .accounts({
         payer: arciumAccounts3.payer,
         signPdaAccount: arciumAccounts3.signPdaAccount,
@@ -637,6 +2111,7 @@ describe("private-markets", () => {
         arciumProgram: arciumAccounts3.arciumProgram,
         market: arciumAccounts3.market,
         resolver: arciumAccounts3.resolver,
+        votes: deriveVotesPda(resolutionMarketPda),
       })
       .signers([secondResolver.keypair])
       .rpc();
@@ -646,5 +2121,41 @@ describe("private-markets", () => {
     );
     assert.isTrue("computing" in resolutionMarketAccount.resolutionState);
     assert.equal(resolutionMarketAccount.attestationCount, quorum);
+
+    // One stake-weighted vote per resolver
+    const votes = await program.account.resolutionVotes.fetch(
+      deriveVotesPda(resolutionMarketPda)
+    );
+    assert.isTrue(votes.votes[0].resolver.equals(firstResolver.resolverPda));
+    assert.deepEqual(votes.votes[0].ciphertext, attestation1);
+    assert.isTrue(votes.votes[1].resolver.equals(secondResolver.resolverPda));
+    assert.equal(votes.votes[1].stake.toNumber(), stakeAmount.toNumber());
+  });
+
+  it("Rejects an order for an epoch whose window has ended", async () => {
+    // Normally already past by now; only waits out what the suite has not
+    const staleEpoch = new BN(0);
+    const market = await program.account.market.fetch(epochMarketPda);
+    const windowEnd = market.batchStart.add(market.batchInterval).toNumber();
+    while ((await epochAt()).eq(staleEpoch)) {
+      const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+      await sleep(Math.max(1, windowEnd - now) * 1000);
+    }
+
+    try {
+      await submitEpochOrder(staleEpoch);
+      assert.fail("submitBatchOrder should reject an epoch whose window has ended");
+    } catch (err) {
+      assert.include(err.toString(), "WrongEpoch");
+    }
+
+    // The client re-reads the epoch and resubmits
+    const currentEpoch = await epochAt();
+    assert.isTrue(currentEpoch.gt(staleEpoch));
+    await submitEpochOrder(currentEpoch);
+    const batch = await program.account.batchState.fetch(
+      deriveBatchPda(epochMarketPda, currentEpoch)
+    );
+    assert.equal(batch.orderCount, 1);
   });
 });